@@ -0,0 +1,856 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the API document generator.
+//
+// it lists the public functions and data of a module, i.e. the items which
+// are visible when the module is used as a shared module, with their
+// signatures, documents (the `///...` and `//!...` lines) and the full
+// names they are exported as.
+//
+// each submodule is a section of the document, and the re-exported
+// items (i.e. `pub import fn ...` and `pub import data ...`) link to
+// their original items if the original items are in the same module.
+
+use std::{collections::HashSet, path::Path};
+
+use anc_assembly::{
+    ast::{DataSection, ImportItemType, ImportNode, ModuleNode},
+    printer::{format_import_params, format_params, format_results},
+};
+use anc_isa::{DataSectionType, SELF_REFERENCE_MODULE_NAME};
+use anc_parser_asm::NAME_PATH_SEPARATOR;
+
+use crate::{
+    assembler::{get_export_full_name, resolve_relative_full_name},
+    manifest::{load_manifest, parse_submodule_sources, ModuleAssembleError, MANIFEST_FILE_NAME},
+    verifier::{resolve_import_nodes, DependencyImage},
+    AssembleErrorType, AssemblerError,
+};
+
+const DOCUMENT_TITLE: &str = "API Reference";
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ApiDocumentFormat {
+    Markdown,
+    Html,
+}
+
+/// a submodule to be documented.
+pub struct DocumentSubmodule<'a> {
+    pub submodule_full_name: &'a str,
+    pub module_node: &'a ModuleNode,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum ApiItemKind {
+    Function,
+    Data,
+}
+
+struct ApiItem {
+    kind: ApiItemKind,
+
+    // the full name that the item is exported as,
+    // e.g. "hello_world::utils::add".
+    full_name: String,
+
+    // e.g. "pub fn add(left:i32, right:i32) -> i32"
+    signature: String,
+    document: Option<String>,
+
+    // the full name of the original item of a re-exported item.
+    reexport_from: Option<String>,
+
+    // the signature (or the data type) of the re-exported item is omitted
+    // in the source and can not be resolved.
+    unresolved: bool,
+}
+
+struct ApiSubmodule {
+    full_name: String,
+    document: Option<String>,
+    functions: Vec<ApiItem>,
+    datas: Vec<ApiItem>,
+
+    // the glob re-exports, e.g. `pub import fn module::utils::*`
+    glob_reexports: Vec<(ApiItemKind, String)>,
+}
+
+/// generate the API document of the specified submodules.
+///
+/// the submodules are sorted by their full names in the document.
+///
+/// the omitted signatures (and data types) of the re-exported items are resolved
+/// by the images of the dependency modules, the ones which can not be resolved
+/// (i.e. the items of the current module, or the images are not provided) are
+/// marked as unresolved in the document.
+pub fn generate_api_document(
+    submodules: &[DocumentSubmodule],
+    dependency_images: &[DependencyImage],
+    format: ApiDocumentFormat,
+) -> Result<String, AssemblerError> {
+    let mut api_submodules = submodules
+        .iter()
+        .map(|submodule| {
+            collect_api_submodule(
+                submodule.submodule_full_name,
+                submodule.module_node,
+                dependency_images,
+            )
+        })
+        .collect::<Result<Vec<ApiSubmodule>, AssemblerError>>()?;
+
+    api_submodules.sort_by(|left, right| left.full_name.cmp(&right.full_name));
+
+    let text = match format {
+        ApiDocumentFormat::Markdown => format_markdown(&api_submodules),
+        ApiDocumentFormat::Html => format_html(&api_submodules),
+    };
+
+    Ok(text)
+}
+
+/// generate the API document of a module project, i.e. all source
+/// files in the "src" folder of the module.
+pub fn generate_api_document_by_manifest(
+    module_directory: &Path,
+    dependency_images: &[DependencyImage],
+    format: ApiDocumentFormat,
+) -> Result<String, ModuleAssembleError> {
+    let manifest_file_path = module_directory.join(MANIFEST_FILE_NAME);
+    let manifest = load_manifest(&manifest_file_path).map_err(ModuleAssembleError::Manifest)?;
+    let parsed_submodules = parse_submodule_sources(&manifest.name, module_directory)?;
+
+    let submodules: Vec<DocumentSubmodule> = parsed_submodules
+        .iter()
+        .map(|(submodule_full_name, _, module_node)| DocumentSubmodule {
+            submodule_full_name,
+            module_node,
+        })
+        .collect();
+
+    generate_api_document(&submodules, dependency_images, format).map_err(|error| {
+        ModuleAssembleError::Assembler {
+            file_path: manifest_file_path,
+            error,
+        }
+    })
+}
+
+fn collect_api_submodule(
+    submodule_full_name: &str,
+    module_node: &ModuleNode,
+    dependency_images: &[DependencyImage],
+) -> Result<ApiSubmodule, AssemblerError> {
+    let mut functions = vec![];
+    let mut datas = vec![];
+    let mut glob_reexports = vec![];
+    let mut reexport_datas = vec![];
+
+    for function_node in module_node.functions.iter().filter(|node| node.public) {
+        functions.push(ApiItem {
+            kind: ApiItemKind::Function,
+            full_name: get_export_full_name(submodule_full_name, &function_node.name),
+            signature: format!(
+                "pub fn {}{} -> {}",
+                function_node.name,
+                format_params(&function_node.params),
+                format_results(&function_node.results)
+            ),
+            document: function_node.document.clone(),
+            reexport_from: None,
+            unresolved: false,
+        });
+    }
+
+    // the re-exported functions and data are placed after the user-defined ones,
+    // which is the same as the order of the export entries and the re-export entries.
+    for import_node in &module_node.imports {
+        let resolved_node = match import_node {
+            ImportNode::Function(import_function_node)
+                if import_function_node.public && import_function_node.signature.is_none() =>
+            {
+                resolve_reexport_node(import_node, submodule_full_name, dependency_images)?
+            }
+            ImportNode::Data(import_data_node)
+                if import_data_node.public && import_data_node.data_type.is_none() =>
+            {
+                resolve_reexport_node(import_node, submodule_full_name, dependency_images)?
+            }
+            _ => None,
+        };
+
+        match resolved_node.as_ref().unwrap_or(import_node) {
+            ImportNode::Function(import_function_node) if import_function_node.public => {
+                let original_full_name =
+                    get_original_full_name(&import_function_node.full_name, submodule_full_name)?;
+
+                let identifier = match &import_function_node.alias_name {
+                    Some(alias_name) => alias_name.as_str(),
+                    None => get_identifier(&import_function_node.full_name),
+                };
+
+                let signature = match &import_function_node.signature {
+                    Some((params, results)) => format!(
+                        "pub fn {}{} -> {}",
+                        identifier,
+                        format_import_params(params),
+                        format_results(results)
+                    ),
+                    None => format!("pub fn {}", identifier),
+                };
+
+                functions.push(ApiItem {
+                    kind: ApiItemKind::Function,
+                    full_name: get_export_full_name(submodule_full_name, identifier),
+                    signature,
+                    document: import_function_node.document.clone(),
+                    reexport_from: Some(original_full_name),
+                    unresolved: import_function_node.signature.is_none(),
+                });
+            }
+            ImportNode::Data(import_data_node) if import_data_node.public => {
+                let original_full_name =
+                    get_original_full_name(&import_data_node.full_name, submodule_full_name)?;
+
+                let identifier = match &import_data_node.alias_name {
+                    Some(alias_name) => alias_name.as_str(),
+                    None => get_identifier(&import_data_node.full_name),
+                };
+
+                let section_keyword = match import_data_node.data_section_type {
+                    Some(DataSectionType::ReadOnly) => "readonly data",
+                    Some(DataSectionType::ReadWrite) | None => "data",
+                    Some(DataSectionType::Uninit) => "uninit data",
+                };
+
+                let signature = match &import_data_node.data_type {
+                    Some(data_type) => {
+                        format!("pub {} {}:{}", section_keyword, identifier, data_type)
+                    }
+                    None => format!("pub {} {}", section_keyword, identifier),
+                };
+
+                reexport_datas.push(ApiItem {
+                    kind: ApiItemKind::Data,
+                    full_name: get_export_full_name(submodule_full_name, identifier),
+                    signature,
+                    document: import_data_node.document.clone(),
+                    reexport_from: Some(original_full_name),
+                    unresolved: import_data_node.data_type.is_none(),
+                });
+            }
+            ImportNode::Glob(import_glob_node) if import_glob_node.public => {
+                let kind = match import_glob_node.item_type {
+                    ImportItemType::Function => ApiItemKind::Function,
+                    ImportItemType::Data => ApiItemKind::Data,
+                };
+
+                let module_path =
+                    get_original_full_name(&import_glob_node.module_path, submodule_full_name)?;
+                glob_reexports.push((kind, module_path));
+            }
+            _ => {
+                // not re-exported
+            }
+        }
+    }
+
+    for data_node in module_node.datas.iter().filter(|node| node.public) {
+        let signature = match &data_node.data_section {
+            DataSection::ReadOnly(pair) => {
+                format!("pub readonly data {}:{}", data_node.name, pair.data_type)
+            }
+            DataSection::ReadWrite(pair) => {
+                format!("pub data {}:{}", data_node.name, pair.data_type)
+            }
+            DataSection::Uninit(data_type) => {
+                format!("pub uninit data {}:{}", data_node.name, data_type)
+            }
+        };
+
+        datas.push(ApiItem {
+            kind: ApiItemKind::Data,
+            full_name: get_export_full_name(submodule_full_name, &data_node.name),
+            signature,
+            document: data_node.document.clone(),
+            reexport_from: None,
+            unresolved: false,
+        });
+    }
+
+    datas.append(&mut reexport_datas);
+
+    Ok(ApiSubmodule {
+        full_name: submodule_full_name.to_owned(),
+        document: module_node.document.clone(),
+        functions,
+        datas,
+        glob_reexports,
+    })
+}
+
+/// resolve the omitted signature (or data type) of a re-exported item
+/// by `verifier::resolve_import_nodes`.
+///
+/// returns `None` if the item is in the current module or the image
+/// of its module is not provided.
+fn resolve_reexport_node(
+    import_node: &ImportNode,
+    submodule_full_name: &str,
+    dependency_images: &[DependencyImage],
+) -> Result<Option<ImportNode>, AssemblerError> {
+    let mut module_node = ModuleNode {
+        document: None,
+        imports: vec![import_node.clone()],
+        externals: vec![],
+        datas: vec![],
+        functions: vec![],
+    };
+
+    match resolve_import_nodes(&mut module_node, submodule_full_name, dependency_images) {
+        Ok(_) => Ok(module_node.imports.pop()),
+        Err(error) if matches!(error.error_type, AssembleErrorType::UnresolvedImportItem(_)) => {
+            Ok(None)
+        }
+        Err(error) => Err(error),
+    }
+}
+
+/// the full name of the imported item in the document, i.e. the relative
+/// names and the special module name "module" are replaced with the
+/// name of the current module, e.g. in the submodule "hello_world::app":
+///
+/// - "self::foo" -> "hello_world::app::foo"
+/// - "module::utils::add" -> "hello_world::utils::add"
+/// - "std::memory::copy" -> "std::memory::copy"
+fn get_original_full_name(
+    full_name: &str,
+    submodule_full_name: &str,
+) -> Result<String, AssemblerError> {
+    let resolved_full_name = resolve_relative_full_name(full_name, submodule_full_name)?;
+    let (module_name, name_path) = resolved_full_name
+        .split_once(NAME_PATH_SEPARATOR)
+        .unwrap_or((&resolved_full_name, ""));
+
+    if module_name != SELF_REFERENCE_MODULE_NAME {
+        return Ok(resolved_full_name);
+    }
+
+    let (current_module_name, _) = submodule_full_name
+        .split_once(NAME_PATH_SEPARATOR)
+        .unwrap_or((submodule_full_name, ""));
+
+    if name_path.is_empty() {
+        Ok(current_module_name.to_owned())
+    } else {
+        Ok(format!(
+            "{}{}{}",
+            current_module_name, NAME_PATH_SEPARATOR, name_path
+        ))
+    }
+}
+
+fn get_identifier(full_name: &str) -> &str {
+    full_name
+        .rsplit_once(NAME_PATH_SEPARATOR)
+        .map(|(_, identifier)| identifier)
+        .unwrap_or(full_name)
+}
+
+/// the anchors are generated from the full names, the separator "::" is
+/// replaced with "-" since it is not friendly to some Markdown renderers,
+/// e.g. "hello_world::utils::add" -> "fn-hello_world-utils-add".
+fn get_item_anchor(kind: ApiItemKind, full_name: &str) -> String {
+    let prefix = match kind {
+        ApiItemKind::Function => "fn",
+        ApiItemKind::Data => "data",
+    };
+
+    format!("{}-{}", prefix, full_name.replace(NAME_PATH_SEPARATOR, "-"))
+}
+
+fn get_unresolved_note(kind: ApiItemKind) -> &'static str {
+    match kind {
+        ApiItemKind::Function => {
+            "The signature is omitted in the source and is not resolved, see the original function."
+        }
+        ApiItemKind::Data => {
+            "The type is omitted in the source and is not resolved, see the original data."
+        }
+    }
+}
+
+// the anchors of all the documented functions and data, the re-exported
+// items link to their original items only if the anchors exist.
+fn get_item_anchors(api_submodules: &[ApiSubmodule]) -> HashSet<String> {
+    api_submodules
+        .iter()
+        .flat_map(|submodule| submodule.functions.iter().chain(submodule.datas.iter()))
+        .map(|item| get_item_anchor(item.kind, &item.full_name))
+        .collect()
+}
+
+fn get_submodule_anchor(full_name: &str) -> String {
+    format!("mod-{}", full_name.replace(NAME_PATH_SEPARATOR, "-"))
+}
+
+fn format_markdown(api_submodules: &[ApiSubmodule]) -> String {
+    let submodule_names: HashSet<&str> = api_submodules
+        .iter()
+        .map(|submodule| submodule.full_name.as_str())
+        .collect();
+
+    let item_anchors = get_item_anchors(api_submodules);
+
+    let mut text = format!("# {}\n\n", DOCUMENT_TITLE);
+
+    // the index of submodules
+    for api_submodule in api_submodules {
+        text.push_str(&format!(
+            "- [`{}`](#{})\n",
+            api_submodule.full_name,
+            get_submodule_anchor(&api_submodule.full_name)
+        ));
+    }
+
+    for api_submodule in api_submodules {
+        text.push_str(&format!(
+            "\n## <a id=\"{}\"></a>`{}`\n",
+            get_submodule_anchor(&api_submodule.full_name),
+            api_submodule.full_name
+        ));
+
+        if let Some(document) = &api_submodule.document {
+            text.push_str(&format!("\n{}\n", document));
+        }
+
+        for (title, items) in [
+            ("Functions", &api_submodule.functions),
+            ("Data", &api_submodule.datas),
+        ] {
+            if items.is_empty() {
+                continue;
+            }
+
+            text.push_str(&format!("\n### {}\n", title));
+
+            for item in items {
+                text.push_str(&format!(
+                    "\n#### <a id=\"{}\"></a>`{}`\n\n```\n{}\n```\n",
+                    get_item_anchor(item.kind, &item.full_name),
+                    item.full_name,
+                    item.signature
+                ));
+
+                if let Some(document) = &item.document {
+                    text.push_str(&format!("\n{}\n", document));
+                }
+
+                if let Some(original_full_name) = &item.reexport_from {
+                    let original_anchor = get_item_anchor(item.kind, original_full_name);
+                    if item_anchors.contains(&original_anchor) {
+                        text.push_str(&format!(
+                            "\nRe-exported from [`{}`](#{}).\n",
+                            original_full_name, original_anchor
+                        ));
+                    } else {
+                        text.push_str(&format!("\nRe-exported from `{}`.\n", original_full_name));
+                    }
+                }
+
+                if item.unresolved {
+                    text.push_str(&format!("\n{}\n", get_unresolved_note(item.kind)));
+                }
+            }
+        }
+
+        if !api_submodule.glob_reexports.is_empty() {
+            text.push_str("\n### Re-exports\n\n");
+
+            for (kind, module_path) in &api_submodule.glob_reexports {
+                let item_type_name = match kind {
+                    ApiItemKind::Function => "functions",
+                    ApiItemKind::Data => "data",
+                };
+
+                if submodule_names.contains(module_path.as_str()) {
+                    text.push_str(&format!(
+                        "- All public {} of [`{}`](#{})\n",
+                        item_type_name,
+                        module_path,
+                        get_submodule_anchor(module_path)
+                    ));
+                } else {
+                    text.push_str(&format!(
+                        "- All public {} of `{}`\n",
+                        item_type_name, module_path
+                    ));
+                }
+            }
+        }
+    }
+
+    text
+}
+
+fn format_html(api_submodules: &[ApiSubmodule]) -> String {
+    let submodule_names: HashSet<&str> = api_submodules
+        .iter()
+        .map(|submodule| submodule.full_name.as_str())
+        .collect();
+
+    let item_anchors = get_item_anchors(api_submodules);
+
+    let mut text = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n</head>\n<body>\n<h1>{}</h1>\n",
+        DOCUMENT_TITLE, DOCUMENT_TITLE
+    );
+
+    // the index of submodules
+    text.push_str("<ul>\n");
+    for api_submodule in api_submodules {
+        text.push_str(&format!(
+            "<li><a href=\"#{}\"><code>{}</code></a></li>\n",
+            get_submodule_anchor(&api_submodule.full_name),
+            api_submodule.full_name
+        ));
+    }
+    text.push_str("</ul>\n");
+
+    for api_submodule in api_submodules {
+        text.push_str(&format!(
+            "<h2 id=\"{}\"><code>{}</code></h2>\n",
+            get_submodule_anchor(&api_submodule.full_name),
+            api_submodule.full_name
+        ));
+
+        if let Some(document) = &api_submodule.document {
+            text.push_str(&format_html_document(document));
+        }
+
+        for (title, items) in [
+            ("Functions", &api_submodule.functions),
+            ("Data", &api_submodule.datas),
+        ] {
+            if items.is_empty() {
+                continue;
+            }
+
+            text.push_str(&format!("<h3>{}</h3>\n", title));
+
+            for item in items {
+                text.push_str(&format!(
+                    "<h4 id=\"{}\"><code>{}</code></h4>\n<pre><code>{}</code></pre>\n",
+                    get_item_anchor(item.kind, &item.full_name),
+                    item.full_name,
+                    escape_html(&item.signature)
+                ));
+
+                if let Some(document) = &item.document {
+                    text.push_str(&format_html_document(document));
+                }
+
+                if let Some(original_full_name) = &item.reexport_from {
+                    let original_anchor = get_item_anchor(item.kind, original_full_name);
+                    if item_anchors.contains(&original_anchor) {
+                        text.push_str(&format!(
+                            "<p>Re-exported from <a href=\"#{}\"><code>{}</code></a>.</p>\n",
+                            original_anchor, original_full_name
+                        ));
+                    } else {
+                        text.push_str(&format!(
+                            "<p>Re-exported from <code>{}</code>.</p>\n",
+                            original_full_name
+                        ));
+                    }
+                }
+
+                if item.unresolved {
+                    text.push_str(&format!("<p>{}</p>\n", get_unresolved_note(item.kind)));
+                }
+            }
+        }
+
+        if !api_submodule.glob_reexports.is_empty() {
+            text.push_str("<h3>Re-exports</h3>\n<ul>\n");
+
+            for (kind, module_path) in &api_submodule.glob_reexports {
+                let item_type_name = match kind {
+                    ApiItemKind::Function => "functions",
+                    ApiItemKind::Data => "data",
+                };
+
+                if submodule_names.contains(module_path.as_str()) {
+                    text.push_str(&format!(
+                        "<li>All public {} of <a href=\"#{}\"><code>{}</code></a></li>\n",
+                        item_type_name,
+                        get_submodule_anchor(module_path),
+                        module_path
+                    ));
+                } else {
+                    text.push_str(&format!(
+                        "<li>All public {} of <code>{}</code></li>\n",
+                        item_type_name, module_path
+                    ));
+                }
+            }
+
+            text.push_str("</ul>\n");
+        }
+    }
+
+    text.push_str("</body>\n</html>\n");
+    text
+}
+
+/// the paragraphs of the document are separated by blank lines.
+fn format_html_document(document: &str) -> String {
+    document
+        .split("\n\n")
+        .map(|paragraph| paragraph.trim())
+        .filter(|paragraph| !paragraph.is_empty())
+        .map(|paragraph| format!("<p>{}</p>\n", escape_html(paragraph)))
+        .collect()
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use anc_image::entry_writer::write_object_file;
+    use anc_parser_asm::parser::parse_from_str;
+    use pretty_assertions::assert_eq;
+
+    use crate::{assembler::assemble_module_node, verifier::DependencyImage};
+
+    use super::{generate_api_document, ApiDocumentFormat, DocumentSubmodule};
+
+    #[test]
+    fn test_generate_api_document() {
+        let utils_node = parse_from_str(
+            r#"
+//! The utilities.
+
+/// Adds two numbers.
+pub fn add(left:i32, right:i32) -> i32
+    add_i32(local_load_i32_s(left), local_load_i32_s(right))
+
+fn private_helper() nop()
+
+/// The counter.
+pub data count:i32 = 0
+pub uninit data buf:byte[16]
+"#,
+        )
+        .unwrap();
+
+        let app_node = parse_from_str(
+            r#"
+/// Re-exported add.
+pub import fn module::utils::add(i32, i32) -> i32 as plus
+pub import fn std::memory::copy as mem_copy
+pub import fn module::utils::*
+pub import data module::utils::count type i32 as counter
+"#,
+        )
+        .unwrap();
+
+        let submodules = [
+            DocumentSubmodule {
+                submodule_full_name: "hello_world::utils",
+                module_node: &utils_node,
+            },
+            DocumentSubmodule {
+                submodule_full_name: "hello_world::app",
+                module_node: &app_node,
+            },
+        ];
+
+        assert_eq!(
+            generate_api_document(&submodules, &[], ApiDocumentFormat::Markdown).unwrap(),
+            "\
+# API Reference
+
+- [`hello_world::app`](#mod-hello_world-app)
+- [`hello_world::utils`](#mod-hello_world-utils)
+
+## <a id=\"mod-hello_world-app\"></a>`hello_world::app`
+
+### Functions
+
+#### <a id=\"fn-hello_world-app-plus\"></a>`hello_world::app::plus`
+
+```
+pub fn plus(i32, i32) -> i32
+```
+
+Re-exported add.
+
+Re-exported from [`hello_world::utils::add`](#fn-hello_world-utils-add).
+
+#### <a id=\"fn-hello_world-app-mem_copy\"></a>`hello_world::app::mem_copy`
+
+```
+pub fn mem_copy
+```
+
+Re-exported from `std::memory::copy`.
+
+The signature is omitted in the source and is not resolved, see the original function.
+
+### Data
+
+#### <a id=\"data-hello_world-app-counter\"></a>`hello_world::app::counter`
+
+```
+pub data counter:i32
+```
+
+Re-exported from [`hello_world::utils::count`](#data-hello_world-utils-count).
+
+### Re-exports
+
+- All public functions of [`hello_world::utils`](#mod-hello_world-utils)
+
+## <a id=\"mod-hello_world-utils\"></a>`hello_world::utils`
+
+The utilities.
+
+### Functions
+
+#### <a id=\"fn-hello_world-utils-add\"></a>`hello_world::utils::add`
+
+```
+pub fn add(left:i32, right:i32) -> i32
+```
+
+Adds two numbers.
+
+### Data
+
+#### <a id=\"data-hello_world-utils-count\"></a>`hello_world::utils::count`
+
+```
+pub data count:i32
+```
+
+The counter.
+
+#### <a id=\"data-hello_world-utils-buf\"></a>`hello_world::utils::buf`
+
+```
+pub uninit data buf:byte[16]
+```
+"
+        );
+
+        let html = generate_api_document(&submodules, &[], ApiDocumentFormat::Html).unwrap();
+        assert!(html.contains(
+            "<h4 id=\"fn-hello_world-utils-add\"><code>hello_world::utils::add</code></h4>\n\
+            <pre><code>pub fn add(left:i32, right:i32) -&gt; i32</code></pre>\n\
+            <p>Adds two numbers.</p>\n"
+        ));
+        assert!(html.contains(
+            "<p>Re-exported from <a href=\"#fn-hello_world-utils-add\"><code>hello_world::utils::add</code></a>.</p>\n"
+        ));
+    }
+
+    #[test]
+    fn test_generate_api_document_with_dependency_images() {
+        let memory_node = parse_from_str(
+            r#"
+pub fn copy(dst:i64, src:i64, len:i64) nop()
+pub readonly data page_size:i32 = 4096
+"#,
+        )
+        .unwrap();
+        let entry = assemble_module_node(&memory_node, "std::memory", &[], &[]).unwrap();
+        let mut binary: Vec<u8> = vec![];
+        write_object_file(&entry, false, &mut binary).unwrap();
+
+        let dependency_images = [DependencyImage {
+            module_name: "std",
+            image_binary: &binary,
+        }];
+
+        let app_node = parse_from_str(
+            r#"
+pub import fn std::memory::copy as mem_copy
+pub import data std::memory::page_size
+pub import fn module::utils::add
+"#,
+        )
+        .unwrap();
+
+        let submodules = [DocumentSubmodule {
+            submodule_full_name: "hello_world::app",
+            module_node: &app_node,
+        }];
+
+        // the items of the current module can not be resolved by the images.
+        assert_eq!(
+            generate_api_document(&submodules, &dependency_images, ApiDocumentFormat::Markdown)
+                .unwrap(),
+            "\
+# API Reference
+
+- [`hello_world::app`](#mod-hello_world-app)
+
+## <a id=\"mod-hello_world-app\"></a>`hello_world::app`
+
+### Functions
+
+#### <a id=\"fn-hello_world-app-mem_copy\"></a>`hello_world::app::mem_copy`
+
+```
+pub fn mem_copy(i64, i64, i64) -> ()
+```
+
+Re-exported from `std::memory::copy`.
+
+#### <a id=\"fn-hello_world-app-add\"></a>`hello_world::app::add`
+
+```
+pub fn add
+```
+
+Re-exported from `hello_world::utils::add`.
+
+The signature is omitted in the source and is not resolved, see the original function.
+
+### Data
+
+#### <a id=\"data-hello_world-app-page_size\"></a>`hello_world::app::page_size`
+
+```
+pub readonly data page_size:i32
+```
+
+Re-exported from `std::memory::page_size`.
+"
+        );
+
+        // the errors other than the missing images are reported.
+        let missing_node = parse_from_str("pub import fn std::memory::move").unwrap();
+        let submodules = [DocumentSubmodule {
+            submodule_full_name: "hello_world::app",
+            module_node: &missing_node,
+        }];
+        assert!(generate_api_document(
+            &submodules,
+            &dependency_images,
+            ApiDocumentFormat::Markdown
+        )
+        .is_err());
+    }
+}
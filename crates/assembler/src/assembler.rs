@@ -4,34 +4,57 @@
 // the Mozilla Public License version 2.0 and additional exceptions,
 // more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
 
-use std::fmt::Display;
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    path::{Path, PathBuf},
+};
 
-use anc_assembly::ast::{
-    ArgumentValue, BreakNode, DataNode, DataSection, DataTypeValuePair, DataValue, DeclareDataType,
-    ExpressionNode, ExternalNode, FixedDeclareDataType, FunctionNode, ImportNode, InstructionNode,
-    LiteralNumber, LocalVariable, ModuleNode, NamedArgument, NamedParameter,
+use anc_assembly::{
+    ast::{
+        ArgumentValue, BreakNode, DataNode, DataSection, DataTypeValuePair, DataValue,
+        DeclareDataType, DependencySource, ExpressionNode, ExternalNode, FixedDeclareDataType,
+        FunctionNode, ImportNode, IncludeFile, IncludeFileType, InstructionNode, LiteralNumber,
+        LocalVariable, ModuleNode, NamedArgument, NamedParameter,
+    },
+    location::Location,
 };
 use anc_image::{
     bytecode_writer::BytecodeWriter,
     entry::{
         ExportDataEntry, ExportFunctionEntry, ExternalFunctionEntry, ExternalLibraryEntry,
         FunctionEntry, ImageCommonEntry, ImportDataEntry, ImportFunctionEntry, ImportModuleEntry,
-        InitedDataEntry, LocalVariableEntry, LocalVariableListEntry, RelocateEntry,
-        RelocateListEntry, TypeEntry, UninitDataEntry,
+        InitedDataEntry, LocalVariableEntry, LocalVariableListEntry, ReexportDataEntry,
+        ReexportFunctionEntry, RelocateEntry, RelocateListEntry, TypeEntry, UninitDataEntry,
     },
     module_image::{ImageType, Visibility},
 };
 use anc_isa::{
-    opcode::Opcode, DataSectionType, EffectiveVersion, MemoryDataType, OperandDataType,
-    SELF_REFERENCE_MODULE_NAME,
+    opcode::Opcode, DataSectionType, DependencyCondition, DependencyLocal, DependencyRemote,
+    DependencyShare, EffectiveVersion, ExternalLibraryDependency, MemoryDataType, ModuleDependency,
+    OperandDataType, SELF_REFERENCE_MODULE_NAME,
 };
 use anc_parser_asm::NAME_PATH_SEPARATOR;
 
-use crate::{AssembleErrorType, AssemblerError};
+use crate::{AssembleErrorType, AssemblerError, DependencyOrigin, DuplicatedIdentifier};
 
 // the value of the stub for the instruction parameter 'next_inst_offset'
 const INSTRUCTION_STUB_VALUE: u32 = 0;
 
+// the prefix of the identifiers of the anonymous read-only data which
+// are converted from the inline data literals.
+// the char '$' is not allowed in the identifiers of the source code, so
+// these identifiers never conflict with the user-defined items.
+const INLINE_DATA_IDENTIFIER_PREFIX: &str = "$inline_data_";
+
+// the max length (in bytes) of an initialized data item, e.g. the data
+// expanded from repetitions `[0_i64; 1024]` or from `byte[4096] = fill(0_i8)`.
+const MAX_DATA_LENGTH: usize = 64 * 1024 * 1024;
+
+// the special names for the relative full names, e.g. "self::foo" and "super::bar".
+const SELF_SUBMODULE_NAME: &str = "self";
+const SUPER_SUBMODULE_NAME: &str = "super";
+
 /// Get the "module name" and "name path" from a "full name",
 /// note that the "name path" may be empty if the "full name"
 /// does not include that part.
@@ -63,6 +86,66 @@ fn get_namespace_and_identifier(name_path: &str) -> (&str, &str) {
         .unwrap_or(("", name_path))
 }
 
+/// Get the full name of an exported function or data, i.e.
+/// "submodule_full_name::identifier", e.g. "hello_world::utils::add".
+pub fn get_export_full_name(submodule_full_name: &str, identifier: &str) -> String {
+    format!(
+        "{}{}{}",
+        submodule_full_name, NAME_PATH_SEPARATOR, identifier
+    )
+}
+
+/// Convert the relative full name (i.e., starts with "self" or "super") to
+/// the full name which starts with the special name "module", e.g.
+/// in the submodule "hello_world::utils::foo":
+///
+/// - "self::bar" -> "module::utils::foo::bar"
+/// - "super::bar" -> "module::utils::bar"
+/// - "super::super::bar" -> "module::bar"
+/// - "super::baz::bar" -> "module::utils::baz::bar"
+///
+/// the other full names are returned as they are.
+pub fn resolve_relative_full_name(
+    full_name: &str,
+    submodule_full_name: &str,
+) -> Result<String, AssemblerError> {
+    let segments: Vec<&str> = full_name.split(NAME_PATH_SEPARATOR).collect();
+
+    if segments[0] != SELF_SUBMODULE_NAME && segments[0] != SUPER_SUBMODULE_NAME {
+        return Ok(full_name.to_owned());
+    }
+
+    // the names of the current submodule, excludes the module name.
+    let mut namespace: Vec<&str> = submodule_full_name
+        .split(NAME_PATH_SEPARATOR)
+        .skip(1)
+        .collect();
+
+    let mut rest = &segments[..];
+
+    if rest[0] == SELF_SUBMODULE_NAME {
+        rest = &rest[1..];
+    }
+
+    while rest.first() == Some(&SUPER_SUBMODULE_NAME) {
+        if namespace.pop().is_none() {
+            return Err(AssemblerError::new(
+                AssembleErrorType::RelativePathAboveModuleRoot {
+                    full_name: full_name.to_owned(),
+                    submodule_full_name: submodule_full_name.to_owned(),
+                },
+            ));
+        }
+        rest = &rest[1..];
+    }
+
+    let mut names = vec![SELF_REFERENCE_MODULE_NAME];
+    names.extend(namespace);
+    names.extend(rest);
+
+    Ok(names.join(NAME_PATH_SEPARATOR))
+}
+
 /// about library "full_name"
 /// -------------------------
 /// "full_name" = "library_name::identifier"
@@ -70,6 +153,93 @@ fn get_library_name_and_identifier(full_name: &str) -> (&str, &str) {
     full_name.split_once(NAME_PATH_SEPARATOR).unwrap()
 }
 
+#[derive(Debug, PartialEq, Default)]
+pub struct AssembleOptions {
+    /// merge the read-only data items which have identical content
+    /// and alignment, so that they share the same data public index.
+    pub deduplicate_read_only_data: bool,
+
+    /// the path of the source file, the file paths in `include_bytes(...)`
+    /// and `include_str(...)` are relative to the directory of this file.
+    /// if it is `None`, they are relative to the current working directory.
+    pub source_file_path: Option<PathBuf>,
+
+    /// the version of the module, in the format "major.minor.patch", e.g. "1.2.0".
+    /// if it is `None`, the version "0.0.0" is used.
+    pub module_version: Option<String>,
+}
+
+/// parse the version string "major.minor.patch", each part is a
+/// decimal number in the range of `u16`.
+pub fn parse_module_version(version: &str) -> Result<EffectiveVersion, AssemblerError> {
+    let parts: Vec<u16> = version
+        .split('.')
+        .map(|part| {
+            if part.is_empty() || !part.chars().all(|c| c.is_ascii_digit()) {
+                None
+            } else {
+                part.parse::<u16>().ok()
+            }
+        })
+        .collect::<Option<Vec<u16>>>()
+        .ok_or_else(|| {
+            AssemblerError::new(AssembleErrorType::IncorrectModuleVersion(
+                version.to_owned(),
+            ))
+        })?;
+
+    match parts[..] {
+        [major, minor, patch] => Ok(EffectiveVersion::new(major, minor, patch)),
+        _ => Err(AssemblerError::new(
+            AssembleErrorType::IncorrectModuleVersion(version.to_owned()),
+        )),
+    }
+}
+
+/// all submodules of a module should have the same module name and version.
+///
+/// it is used for the object files which are assembled separately (e.g. read
+/// from the disk before linking), the submodules assembled by the same manifest
+/// always have the same version.
+pub fn check_submodule_versions(
+    image_common_entries: &[&ImageCommonEntry],
+) -> Result<(), AssemblerError> {
+    if let Some(first) = image_common_entries.first() {
+        if let Some(entry) = image_common_entries
+            .iter()
+            .find(|entry| entry.name != first.name || entry.version != first.version)
+        {
+            return Err(AssemblerError::new(
+                AssembleErrorType::InconsistentModuleVersion(entry.name.to_owned()),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, PartialEq, Default)]
+pub struct AssembleReport {
+    /// the read-only data items which are merged into other items,
+    /// it is empty if the option `deduplicate_read_only_data` is not enabled.
+    pub deduplicated_data_items: Vec<DeduplicatedDataItem>,
+
+    /// the total length (in bytes) of the merged read-only data.
+    pub saved_bytes: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DeduplicatedDataItem {
+    /// the identifier of the data item which is merged.
+    pub identifier: String,
+
+    /// the identifier of the data item which holds the content.
+    pub target_identifier: String,
+
+    /// the length (in bytes) of the content.
+    pub length: usize,
+}
+
 /// parameter 'submodule_full_name' is the full name of a submodule.
 ///
 /// e.g.
@@ -95,8 +265,35 @@ pub fn assemble_module_node(
     config_import_module_entries: &[ImportModuleEntry],
     config_external_library_entries: &[ExternalLibraryEntry],
 ) -> Result<ImageCommonEntry, AssemblerError> {
+    let (image_common_entry, _) = assemble_module_node_with_options(
+        module_node,
+        submodule_full_name,
+        config_import_module_entries,
+        config_external_library_entries,
+        &AssembleOptions::default(),
+    )?;
+
+    Ok(image_common_entry)
+}
+
+/// the same as `assemble_module_node`, but with the assemble options,
+/// and returns the report of the optional passes as well.
+pub fn assemble_module_node_with_options(
+    module_node: &ModuleNode,
+    submodule_full_name: &str,
+    config_import_module_entries: &[ImportModuleEntry],
+    config_external_library_entries: &[ExternalLibraryEntry],
+    options: &AssembleOptions,
+) -> Result<(ImageCommonEntry, AssembleReport), AssemblerError> {
     let (module_name, _) = get_module_name_and_name_path(submodule_full_name);
 
+    let version = match &options.module_version {
+        Some(module_version) => parse_module_version(module_version)?,
+        None => EffectiveVersion::new(0, 0, 0),
+    };
+
+    check_duplicated_identifiers(module_node)?;
+
     let mut type_entries: Vec<TypeEntry> = vec![];
     let mut local_variable_list_entries: Vec<LocalVariableListEntry> = vec![];
 
@@ -117,6 +314,8 @@ pub fn assemble_module_node(
         external_library_entries,
         external_library_identifiers,
     } = assemble_dependencies(
+        &module_node.imports,
+        &module_node.externals,
         config_import_module_entries,
         config_external_library_entries,
     )?;
@@ -129,7 +328,7 @@ pub fn assemble_module_node(
         import_read_write_data_identifiers,
         import_uninit_data_identifiers,
     } = assemble_import_nodes(
-        module_name,
+        submodule_full_name,
         &import_module_identifiers,
         &module_node.imports,
         &mut type_entries,
@@ -147,6 +346,41 @@ pub fn assemble_module_node(
     let (export_function_entries, function_identifiers) =
         assemble_export_function_entries(&module_node.functions, submodule_full_name);
 
+    // the re-exported items (i.e. `pub import fn ...` and `pub import data ...`)
+    // are exported by the re-export entries, which refer to the import entries.
+    let (reexport_function_entries, reexport_data_entries) =
+        assemble_reexport_entries(&module_node.imports, submodule_full_name);
+
+    // the inline data literals (e.g. `host_addr_data("Hello")`) are converted
+    // into anonymous read-only data items, and they are placed after the
+    // user-defined read-only data items.
+    let inline_data_nodes = assemble_inline_data_nodes(&module_node.functions);
+
+    let source_directory = options
+        .source_file_path
+        .as_ref()
+        .and_then(|path| path.parent())
+        .map(|path| path.to_path_buf())
+        .unwrap_or_default();
+    let data_nodes: Vec<&DataNode> = module_node
+        .datas
+        .iter()
+        .chain(inline_data_nodes.iter())
+        .collect();
+
+    let inline_data_id_value_pairs: Vec<(String, DataValue)> = inline_data_nodes
+        .iter()
+        .map(|data_node| {
+            let DataSection::ReadOnly(data_type_value_pair) = &data_node.data_section else {
+                unreachable!()
+            };
+            (
+                data_node.name.to_owned(),
+                data_type_value_pair.value.clone(),
+            )
+        })
+        .collect();
+
     // the data public index is mixed the following items:
     // - imported read-only data items
     // - imported read-write data items
@@ -155,11 +389,51 @@ pub fn assemble_module_node(
     // - internal read-write data items
     // - internal uninitilized data items
     let AssembleResultForDataNameEntry {
-        data_name_entries: export_data_entries,
-        read_only_data_identifiers,
+        data_name_entries: mut export_data_entries,
+        mut read_only_data_identifiers,
         read_write_data_identifiers,
         uninit_data_identifiers,
-    } = assemble_export_data_entries(&module_node.datas, submodule_full_name);
+    } = assemble_export_data_entries(&data_nodes, submodule_full_name);
+
+    let AssembleResultForDataNodes {
+        mut read_only_data_entries,
+        read_write_data_entries,
+        uninit_data_entries,
+    } = assemble_data_nodes(&data_nodes, &source_directory)?;
+
+    let mut report = AssembleReport::default();
+
+    if options.deduplicate_read_only_data {
+        let read_only_data_public_flags: Vec<bool> = data_nodes
+            .iter()
+            .filter(|data_node| matches!(data_node.data_section, DataSection::ReadOnly(_)))
+            .map(|data_node| data_node.public)
+            .collect();
+
+        report.deduplicated_data_items = deduplicate_read_only_data(
+            &read_only_data_public_flags,
+            &mut read_only_data_identifiers,
+            &mut read_only_data_entries,
+            &mut export_data_entries,
+        );
+
+        report.saved_bytes = report
+            .deduplicated_data_items
+            .iter()
+            .map(|item| item.length)
+            .sum();
+    }
+
+    let data_alias_id_pairs: Vec<(String, String)> = report
+        .deduplicated_data_items
+        .iter()
+        .map(|item| {
+            (
+                item.identifier.to_owned(),
+                item.target_identifier.to_owned(),
+            )
+        })
+        .collect();
 
     let identifier_public_index_lookup_table =
         IdentifierPublicIndexLookupTable::build(IdentifierSource {
@@ -174,6 +448,9 @@ pub fn assemble_module_node(
             read_write_data_identifiers,
             uninit_data_identifiers,
             //
+            data_alias_id_pairs,
+            inline_data_id_value_pairs,
+            //
             external_function_identifiers,
         });
 
@@ -184,15 +461,9 @@ pub fn assemble_module_node(
         &identifier_public_index_lookup_table,
     )?;
 
-    let AssembleResultForDataNodes {
-        read_only_data_entries,
-        read_write_data_entries,
-        uninit_data_entries,
-    } = assemble_data_nodes(&module_node.datas)?;
-
     let module_entry = ImageCommonEntry {
         name: module_name.to_owned(),
-        version: EffectiveVersion::new(0, 0, 0),
+        version,
         image_type: ImageType::ObjectFile,
         //
         type_entries,
@@ -209,13 +480,180 @@ pub fn assemble_module_node(
         //
         export_function_entries,
         export_data_entries,
+        reexport_function_entries,
+        reexport_data_entries,
         relocate_list_entries,
         //
         external_library_entries,
         external_function_entries,
     };
 
-    Ok(module_entry)
+    Ok((module_entry, report))
+}
+
+// a function or data declaration, used for checking duplicated identifiers.
+pub(crate) struct Declaration {
+    pub identifier: String,
+
+    // for building error message, e.g. `import fn "math::add"`
+    pub description: String,
+
+    // the import and external declarations can be renamed by `as`.
+    pub aliasable: bool,
+
+    // the location of the identifier, i.e. the name or the alias.
+    pub location: Location,
+}
+
+/// the functions (includes imported and external functions) share a namespace,
+/// and the data items (includes imported and external data) share another namespace,
+/// the identifiers in the same namespace must be unique.
+fn check_duplicated_identifiers(module_node: &ModuleNode) -> Result<(), AssemblerError> {
+    let (function_declarations, data_declarations) = collect_declarations(module_node);
+    check_duplicated_declarations(&[&function_declarations, &data_declarations])
+}
+
+/// returns the function declarations and the data declarations.
+pub(crate) fn collect_declarations(
+    module_node: &ModuleNode,
+) -> (Vec<Declaration>, Vec<Declaration>) {
+    let mut function_declarations: Vec<Declaration> = vec![];
+    let mut data_declarations: Vec<Declaration> = vec![];
+
+    let get_import_identifier = |full_name: &str, alias_name: &Option<String>| {
+        let (_, name_path) = get_module_name_and_name_path(full_name);
+        let (_, identifier) = get_namespace_and_identifier(name_path);
+        alias_name.clone().unwrap_or_else(|| identifier.to_owned())
+    };
+
+    let get_external_identifier = |full_name: &str, alias_name: &Option<String>| {
+        let (_, identifier) = get_library_name_and_identifier(full_name);
+        alias_name.clone().unwrap_or_else(|| identifier.to_owned())
+    };
+
+    for import_node in &module_node.imports {
+        match import_node {
+            ImportNode::Function(import_function_node) => {
+                function_declarations.push(Declaration {
+                    identifier: get_import_identifier(
+                        &import_function_node.full_name,
+                        &import_function_node.alias_name,
+                    ),
+                    description: format!("import fn \"{}\"", import_function_node.full_name),
+                    aliasable: true,
+                    location: import_function_node.location,
+                });
+            }
+            ImportNode::Data(import_data_node) => {
+                data_declarations.push(Declaration {
+                    identifier: get_import_identifier(
+                        &import_data_node.full_name,
+                        &import_data_node.alias_name,
+                    ),
+                    description: format!("import data \"{}\"", import_data_node.full_name),
+                    aliasable: true,
+                    location: import_data_node.location,
+                });
+            }
+            ImportNode::Module(_) => {
+                // the import modules are not in the namespaces of functions and data.
+            }
+            ImportNode::Glob(_) => {
+                // the glob imports are checked when they are expanded.
+            }
+        }
+    }
+
+    for external_node in &module_node.externals {
+        match external_node {
+            ExternalNode::Function(external_function_node) => {
+                function_declarations.push(Declaration {
+                    identifier: get_external_identifier(
+                        &external_function_node.full_name,
+                        &external_function_node.alias_name,
+                    ),
+                    description: format!("external fn \"{}\"", external_function_node.full_name),
+                    aliasable: true,
+                    location: external_function_node.location,
+                });
+            }
+            ExternalNode::Data(external_data_node) => {
+                data_declarations.push(Declaration {
+                    identifier: get_external_identifier(
+                        &external_data_node.full_name,
+                        &external_data_node.alias_name,
+                    ),
+                    description: format!("external data \"{}\"", external_data_node.full_name),
+                    aliasable: true,
+                    location: external_data_node.location,
+                });
+            }
+            ExternalNode::Library(_) => {
+                // the external libraries are not in the namespaces of functions and data.
+            }
+        }
+    }
+
+    function_declarations.extend(
+        module_node
+            .functions
+            .iter()
+            .map(|function_node| Declaration {
+                identifier: function_node.name.to_owned(),
+                description: format!("fn \"{}\"", function_node.name),
+                aliasable: false,
+                location: function_node.location,
+            }),
+    );
+
+    data_declarations.extend(module_node.datas.iter().map(|data_node| Declaration {
+        identifier: data_node.name.to_owned(),
+        description: format!("data \"{}\"", data_node.name),
+        aliasable: false,
+        location: data_node.location,
+    }));
+
+    (function_declarations, data_declarations)
+}
+
+/// check each namespace (i.e. the list of declarations), and report
+/// all the duplicated identifiers together.
+pub(crate) fn check_duplicated_declarations(
+    namespaces: &[&[Declaration]],
+) -> Result<(), AssemblerError> {
+    let mut duplicated_identifiers: Vec<DuplicatedIdentifier> = vec![];
+
+    for declarations in namespaces {
+        for (idx, declaration) in declarations.iter().enumerate() {
+            if let Some(first_declaration) = declarations[..idx]
+                .iter()
+                .find(|item| item.identifier == declaration.identifier)
+            {
+                // prefer to suggest renaming the latter declaration.
+                let alias_suggestion = [declaration, first_declaration]
+                    .iter()
+                    .find(|item| item.aliasable)
+                    .map(|item| item.description.to_owned());
+
+                duplicated_identifiers.push(DuplicatedIdentifier {
+                    identifier: declaration.identifier.to_owned(),
+                    first_declaration: first_declaration.description.to_owned(),
+                    second_declaration: declaration.description.to_owned(),
+                    first_location: first_declaration.location,
+                    second_location: declaration.location,
+                    alias_suggestion,
+                });
+            }
+        }
+    }
+
+    if duplicated_identifiers.is_empty() {
+        Ok(())
+    } else {
+        Err(AssemblerError::new(
+            AssembleErrorType::DuplicatedIdentifiers(duplicated_identifiers),
+        ))
+    }
 }
 
 fn assemble_export_function_entries(
@@ -229,7 +667,7 @@ fn assemble_export_function_entries(
         // add function id
         function_identifiers.push(function_node.name.to_owned());
 
-        let full_name = format!("{}::{}", submodule_full_name, function_node.name);
+        let full_name = get_export_full_name(submodule_full_name, &function_node.name);
 
         // add function name entry
         let visibility = if function_node.public {
@@ -253,7 +691,7 @@ struct AssembleResultForDataNameEntry {
 }
 
 fn assemble_export_data_entries(
-    data_nodes: &[DataNode],
+    data_nodes: &[&DataNode],
     submodule_full_name: &str,
 ) -> AssembleResultForDataNameEntry {
     // the data name in `ExportDataSection` follow these order:
@@ -270,7 +708,7 @@ fn assemble_export_data_entries(
     let mut uninit_data_identifiers: Vec<String> = vec![];
 
     for data_node in data_nodes {
-        let full_name = format!("{}::{}", submodule_full_name, data_node.name);
+        let full_name = get_export_full_name(submodule_full_name, &data_node.name);
         let id = data_node.name.to_owned();
         let visibility = if data_node.public {
             Visibility::Public
@@ -333,6 +771,14 @@ struct IdentifierSource {
     read_write_data_identifiers: Vec<String>,
     uninit_data_identifiers: Vec<String>,
     //
+    // the read-only data items which are merged into other items by the
+    // deduplication pass, the pair is (identifier, target identifier).
+    data_alias_id_pairs: Vec<(String, String)>,
+    //
+    // the identifiers and values of the anonymous read-only data items,
+    // note that these identifiers are also included in the `read_only_data_identifiers`.
+    inline_data_id_value_pairs: Vec<(String, DataValue)>,
+    //
     external_function_identifiers: Vec<String>,
 }
 
@@ -347,6 +793,7 @@ struct IdentifierSource {
 struct IdentifierPublicIndexLookupTable {
     functions: Vec<NameIndexPair>,
     datas: Vec<NameIndexPair>,
+    inline_datas: Vec<ValueIndexPair>,
     external_functions: Vec<NameIndexPair>,
 }
 
@@ -380,6 +827,14 @@ struct NameIndexPair {
     public_index: usize,
 }
 
+struct ValueIndexPair {
+    // the value of the inline data literal
+    value: DataValue,
+
+    // the data public index of the anonymous read-only data item.
+    public_index: usize,
+}
+
 impl IdentifierPublicIndexLookupTable {
     pub fn build(identifier_source: IdentifierSource) -> Self {
         let mut functions: Vec<NameIndexPair> = vec![];
@@ -422,6 +877,34 @@ impl IdentifierPublicIndexLookupTable {
             }));
         }
 
+        // fill the aliases of data, they share the public index
+        // with the target data items.
+        for (id, target_id) in identifier_source.data_alias_id_pairs {
+            let public_index = datas
+                .iter()
+                .find(|pair| pair.id == target_id)
+                .map(|pair| pair.public_index)
+                .unwrap();
+            datas.push(NameIndexPair { id, public_index });
+        }
+
+        // fill inline data values
+        let inline_datas = identifier_source
+            .inline_data_id_value_pairs
+            .into_iter()
+            .map(|(id, value)| {
+                let public_index = datas
+                    .iter()
+                    .find(|pair| pair.id == id)
+                    .map(|pair| pair.public_index)
+                    .unwrap();
+                ValueIndexPair {
+                    value,
+                    public_index,
+                }
+            })
+            .collect();
+
         // full external function ids
         external_functions.extend(
             identifier_source
@@ -437,6 +920,7 @@ impl IdentifierPublicIndexLookupTable {
         Self {
             functions,
             datas,
+            inline_datas,
             external_functions,
         }
     }
@@ -459,6 +943,19 @@ impl IdentifierPublicIndexLookupTable {
         }
     }
 
+    pub fn get_inline_data_public_index(&self, value: &DataValue) -> Result<usize, AssemblerError> {
+        match self
+            .inline_datas
+            .iter()
+            .find(|entry| is_identical_inline_data_value(&entry.value, value))
+        {
+            Some(p) => Ok(p.public_index),
+            None => Err(AssemblerError::new(AssembleErrorType::DataNotFound(
+                format!("{:?}", value),
+            ))),
+        }
+    }
+
     pub fn get_external_function_index(&self, identifier: &str) -> Result<usize, AssemblerError> {
         match self
             .external_functions
@@ -473,6 +970,166 @@ impl IdentifierPublicIndexLookupTable {
     }
 }
 
+/// collect the inline data literals (i.e., the strings, hex byte data and lists
+/// which are passed as the argument of data loading instructions) from the
+/// function bodies, and convert them into anonymous read-only data nodes.
+///
+/// identical literals share the same data node.
+fn assemble_inline_data_nodes(function_nodes: &[FunctionNode]) -> Vec<DataNode> {
+    let mut data_values: Vec<(&DataValue, Location)> = vec![];
+
+    for function_node in function_nodes {
+        collect_inline_data_values(&function_node.body, &mut data_values);
+    }
+
+    data_values
+        .iter()
+        .enumerate()
+        .map(|(idx, (data_value, location))| DataNode {
+            document: None,
+            public: false,
+            name: format!("{}{}", INLINE_DATA_IDENTIFIER_PREFIX, idx),
+            data_section: DataSection::ReadOnly(DataTypeValuePair {
+                data_type: DeclareDataType::Bytes(Some(get_inline_data_align(data_value))),
+                value: (*data_value).clone(),
+            }),
+            location: *location,
+        })
+        .collect()
+}
+
+/// the location of the data value is the first instruction which uses it.
+fn collect_inline_data_values<'a>(
+    expression_node: &'a ExpressionNode,
+    data_values: &mut Vec<(&'a DataValue, Location)>,
+) {
+    match expression_node {
+        ExpressionNode::Group(expression_nodes) => {
+            for node in expression_nodes {
+                collect_inline_data_values(node, data_values);
+            }
+        }
+        ExpressionNode::Instruction(instruction_node) => {
+            // only the first argument of the data addressing instructions
+            // can be an inline data literal.
+            if is_data_addressing_instruction(&instruction_node.name) {
+                if let Some(ArgumentValue::LiteralData(data_value)) =
+                    instruction_node.positional_args.first()
+                {
+                    if !data_values
+                        .iter()
+                        .any(|(item, _)| is_identical_inline_data_value(item, data_value))
+                    {
+                        data_values.push((data_value, instruction_node.location));
+                    }
+                }
+            }
+
+            let argument_values = instruction_node.positional_args.iter().chain(
+                instruction_node
+                    .named_args
+                    .iter()
+                    .map(|named_arg| &named_arg.value),
+            );
+
+            for argument_value in argument_values {
+                if let ArgumentValue::Expression(node) = argument_value {
+                    collect_inline_data_values(node, data_values);
+                }
+            }
+        }
+        ExpressionNode::When(when_node) => {
+            collect_inline_data_values(&when_node.testing, data_values);
+            collect_inline_data_values(&when_node.consequence, data_values);
+        }
+        ExpressionNode::If(if_node) => {
+            collect_inline_data_values(&if_node.testing, data_values);
+            collect_inline_data_values(&if_node.consequence, data_values);
+            collect_inline_data_values(&if_node.alternative, data_values);
+        }
+        ExpressionNode::Block(block_node) => {
+            for param_value in &block_node.param_values {
+                collect_inline_data_values(&param_value.value, data_values);
+            }
+            collect_inline_data_values(&block_node.body, data_values);
+        }
+        ExpressionNode::Break(break_node) | ExpressionNode::Recur(break_node) => {
+            let (BreakNode::Break(nodes) | BreakNode::BreakFn(nodes)) = break_node;
+            for node in nodes {
+                collect_inline_data_values(node, data_values);
+            }
+        }
+    }
+}
+
+/// the instructions which address data by the data public index,
+/// the first argument of them is either the identifier of data
+/// or an inline data literal.
+fn is_data_addressing_instruction(inst_name: &str) -> bool {
+    matches!(
+        inst_name,
+        "data_load_i64"
+            | "data_load_i32_s"
+            | "data_load_i32_u"
+            | "data_load_i16_s"
+            | "data_load_i16_u"
+            | "data_load_i8_s"
+            | "data_load_i8_u"
+            | "data_load_f32"
+            | "data_load_f64"
+            | "host_addr_data"
+            | "data_load_extend_i64"
+            | "data_load_extend_i32_s"
+            | "data_load_extend_i32_u"
+            | "data_load_extend_i16_s"
+            | "data_load_extend_i16_u"
+            | "data_load_extend_i8_s"
+            | "data_load_extend_i8_u"
+            | "data_load_extend_f32"
+            | "data_load_extend_f64"
+            | "host_addr_data_extend"
+    )
+}
+
+/// the inline data literals are compared structurally rather than by the `==`
+/// of values, so that the floating-point numbers are compared bitwise, e.g.
+/// a `NaN` literal is identical to itself, and `0.0` differs from `-0.0`.
+///
+/// note that the included files (e.g. `[include_bytes("a.bin")]`) are compared
+/// by their arguments, the files are not read here.
+fn is_identical_inline_data_value(left: &DataValue, right: &DataValue) -> bool {
+    match (left, right) {
+        (DataValue::F32(l), DataValue::F32(r)) => l.to_bits() == r.to_bits(),
+        (DataValue::F64(l), DataValue::F64(r)) => l.to_bits() == r.to_bits(),
+        (DataValue::List(l), DataValue::List(r)) => {
+            l.len() == r.len()
+                && l.iter()
+                    .zip(r.iter())
+                    .all(|(l, r)| is_identical_inline_data_value(l, r))
+        }
+        (DataValue::Repetition(l, l_count), DataValue::Repetition(r, r_count)) => {
+            l_count == r_count && is_identical_inline_data_value(l, r)
+        }
+        (DataValue::Fill(l), DataValue::Fill(r)) => is_identical_inline_data_value(l, r),
+        _ => left == right,
+    }
+}
+
+/// the alignment of the inline data is the max size of the numbers in it,
+/// e.g. the alignment of `[11_i8, 13_i64]` is 8, and the alignment of
+/// strings and hex byte data is 1.
+fn get_inline_data_align(data_value: &DataValue) -> usize {
+    match data_value {
+        DataValue::I8(_) => 1,
+        DataValue::I16(_) => 2,
+        DataValue::I32(_) | DataValue::F32(_) | DataValue::Char(_) => 4,
+        DataValue::I64(_) | DataValue::F64(_) => 8,
+        DataValue::String(_) | DataValue::ByteData(_) | DataValue::IncludeFile(_) => 1,
+        DataValue::List(items) => items.iter().map(get_inline_data_align).max().unwrap_or(1),
+        DataValue::Repetition(v, _) | DataValue::Fill(v) => get_inline_data_align(v),
+    }
+}
+
 fn assemble_function_nodes(
     function_nodes: &[FunctionNode],
     type_entries: &mut Vec<TypeEntry>,
@@ -501,6 +1158,8 @@ fn assemble_function_nodes(
                 &function_node.locals,
             );
 
+        // the errors of the function itself (e.g. the incomplete control flow)
+        // are located at the function name.
         let (code, relocate_entries) = assemble_function_code(
             &function_node.name, // for building error message
             local_variable_names_include_params,
@@ -508,7 +1167,8 @@ fn assemble_function_nodes(
             identifier_public_index_lookup_table,
             type_entries,
             local_variable_list_entries,
-        )?;
+        )
+        .map_err(|error| error.with_location_if_absent(function_node.location))?;
 
         function_entries.push(FunctionEntry {
             type_index,
@@ -575,6 +1235,16 @@ fn find_or_create_local_variable_list_index(
         .map(|item| match item.data_type {
             FixedDeclareDataType::I64 => LocalVariableEntry::from_i64(),
             FixedDeclareDataType::I32 => LocalVariableEntry::from_i32(),
+            FixedDeclareDataType::I16 => LocalVariableEntry {
+                memory_data_type: MemoryDataType::Bytes,
+                length: 2,
+                align: 2,
+            },
+            FixedDeclareDataType::I8 => LocalVariableEntry {
+                memory_data_type: MemoryDataType::Bytes,
+                length: 1,
+                align: 1,
+            },
             FixedDeclareDataType::F64 => LocalVariableEntry::from_f64(),
             FixedDeclareDataType::F32 => LocalVariableEntry::from_f32(),
             FixedDeclareDataType::FixedBytes(length, align) => LocalVariableEntry {
@@ -606,18 +1276,19 @@ fn find_or_create_local_variable_list_index(
     }
 }
 
+/// returns the names and the locations of the parameters and local variables.
 fn build_local_variable_names_by_params_and_local_variables(
     named_params: &[NamedParameter],
     local_variables: &[LocalVariable],
-) -> Vec<String> {
+) -> Vec<(String, Location)> {
     let names_from_params = named_params
         .iter()
-        .map(|item| item.name.clone())
+        .map(|item| (item.name.clone(), item.location))
         .collect::<Vec<_>>();
 
     let names_from_local_variables = local_variables
         .iter()
-        .map(|item| item.name.clone())
+        .map(|item| (item.name.clone(), item.location))
         .collect::<Vec<_>>();
 
     let mut names = vec![];
@@ -629,7 +1300,7 @@ fn build_local_variable_names_by_params_and_local_variables(
 
 fn assemble_function_code(
     function_name: &str, // for building error message
-    local_variable_names_include_params: Vec<String>,
+    local_variable_names_include_params: Vec<(String, Location)>,
     expression_node: &ExpressionNode,
     identifier_public_index_lookup_table: &IdentifierPublicIndexLookupTable,
     type_entries: &mut Vec<TypeEntry>,
@@ -670,6 +1341,9 @@ fn assemble_function_code(
     let mut control_flow_stack = ControlFlowStack::new();
     let mut bytecode_writer = BytecodeWriter::new();
 
+    control_flow_stack
+        .check_local_variable_names(function_name, &local_variable_names_include_params)?;
+
     control_flow_stack.push_layer(
         0,
         ControlFlowKind::Function,
@@ -749,7 +1423,8 @@ fn emit_expression(
             relocate_entries,
             control_flow_stack,
             bytecode_writer,
-        )?,
+        )
+        .map_err(|error| locate_instruction_error(error, instruction_node))?,
         ExpressionNode::When(when_node) => {
             //  asm: `when testing [locals] consequence`
             // code:  block_nez (param local_variable_list_index:i32, next_inst_offset:i32)
@@ -787,6 +1462,8 @@ fn emit_expression(
                 address_of_block_nez,
             ));
 
+            control_flow_stack.check_local_variable_names(function_name, &local_variable_names)?;
+
             // push flow stack
             control_flow_stack.push_layer(
                 address_of_block_nez,
@@ -932,6 +1609,7 @@ fn emit_expression(
                 .map(|item| NamedParameter {
                     name: item.name.clone(),
                     data_type: item.data_type,
+                    location: item.location,
                 })
                 .collect::<Vec<NamedParameter>>();
 
@@ -966,6 +1644,8 @@ fn emit_expression(
                 &mut RelocateEntry::from_block_with_type_and_local_variables(address_of_block),
             );
 
+            control_flow_stack.check_local_variable_names(function_name, &local_variable_names)?;
+
             // push flow stack
             control_flow_stack.push_layer(
                 address_of_block,
@@ -1096,6 +1776,35 @@ fn emit_expression(
     Ok(())
 }
 
+/// the errors of the missing identifiers (e.g. `foo` in `call(foo)`) are
+/// located at the identifier argument, and the other errors are located at
+/// the instruction name.
+///
+/// the errors of the nested instructions (i.e. the arguments) are already located.
+fn locate_instruction_error(
+    error: AssemblerError,
+    instruction_node: &InstructionNode,
+) -> AssemblerError {
+    let identifier_location = match instruction_node.positional_args.first() {
+        Some(ArgumentValue::Identifier(_, location)) => Some(*location),
+        _ => None,
+    };
+
+    let location = match (&error.error_type, identifier_location) {
+        (
+            AssembleErrorType::FunctionNotFound(_)
+            | AssembleErrorType::DataNotFound(_)
+            | AssembleErrorType::ExternalFunctionNotFound(_)
+            | AssembleErrorType::ExternalDataNotFound(_)
+            | AssembleErrorType::LocalVariableNotFound { .. },
+            Some(location),
+        ) => location,
+        _ => instruction_node.location,
+    };
+
+    error.with_location_if_absent(location)
+}
+
 #[allow(clippy::too_many_arguments)]
 fn emit_instruction(
     function_name: &str, // for building error message
@@ -1262,10 +1971,10 @@ fn emit_instruction(
         /* host */
         "host_addr_data"=> {
             //  asm: (identifier, offset=literal_i16)
+            //       (literal_data, offset=literal_i16)
             // code: (param offset_bytes:i16 data_public_index:i32)
 
-            let identifier = read_argument_value_as_identifer(function_name,inst_name, &args[0])?;
-            let data_public_index = identifier_public_index_lookup_table.get_data_public_index(identifier)?;
+            let data_public_index = read_argument_value_as_data_public_index(function_name, inst_name, &args[0], identifier_public_index_lookup_table)?;
 
             let offset = match get_named_argument_value(named_args, "offset") {
                 Some(v) => read_argument_value_as_i16(function_name,inst_name, v)?,
@@ -1319,10 +2028,10 @@ fn emit_instruction(
         /* host */
         "host_addr_data_extend" => {
             //  asm: (identifier, offset:i64)
+            //       (literal_data, offset:i64)
             // code: (param data_public_index:i32) (operand offset_bytes:i64)
 
-            let identifier = read_argument_value_as_identifer(function_name,inst_name, &args[0])?;
-            let data_public_index = identifier_public_index_lookup_table.get_data_public_index(identifier)?;
+            let data_public_index = read_argument_value_as_data_public_index(function_name, inst_name, &args[0], identifier_public_index_lookup_table)?;
 
             let opcode = Opcode::from_name(inst_name);
 
@@ -1784,8 +2493,9 @@ struct ControlFlowItem {
     // current layer, the "break" item wouldn't be here.
     break_items: Vec<BreakItem>,
 
-    // used to find the index of local variables by name
-    local_variable_names_include_params: Vec<String>,
+    // used to find the index of local variables by name,
+    // the locations are used for reporting the duplicated names.
+    local_variable_names_include_params: Vec<(String, Location)>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -1864,7 +2574,7 @@ impl ControlFlowStack {
         &mut self,
         address: usize,
         control_flow_kind: ControlFlowKind,
-        local_variable_names_include_params: Vec<String>,
+        local_variable_names_include_params: Vec<(String, Location)>,
     ) {
         let control_flow_item = ControlFlowItem {
             address,
@@ -1875,6 +2585,41 @@ impl ControlFlowStack {
         self.control_flow_items.push(control_flow_item);
     }
 
+    /// check the local variable names (include the parameters) of the new layer,
+    /// they should not be duplicated with each other, nor with the names in
+    /// the outer layers.
+    ///
+    /// call this function before pushing a new layer.
+    pub fn check_local_variable_names(
+        &self,
+        function_name: &str, // for building the error message only
+        local_variable_names_include_params: &[(String, Location)],
+    ) -> Result<(), AssemblerError> {
+        for (idx, (name, location)) in local_variable_names_include_params.iter().enumerate() {
+            // the names in the outer layers are declared before the new layer.
+            let first_location = self
+                .control_flow_items
+                .iter()
+                .flat_map(|item| item.local_variable_names_include_params.iter())
+                .chain(local_variable_names_include_params[..idx].iter())
+                .find(|(item_name, _)| item_name == name)
+                .map(|(_, item_location)| *item_location);
+
+            if let Some(first_location) = first_location {
+                return Err(
+                    AssemblerError::new(AssembleErrorType::DuplicatedLocalVariable {
+                        variable_name: name.to_owned(),
+                        function_name: function_name.to_owned(),
+                        first_location,
+                    })
+                    .with_location(*location),
+                );
+            }
+        }
+
+        Ok(())
+    }
+
     /// call this function when encounting instruction 'break' and 'break_alt'.
     ///
     /// - 'break_alt' is equivalent to 'break 0, next_inst_offset'.
@@ -2013,7 +2758,7 @@ impl ControlFlowStack {
             if let Some(variable_index) = control_flow_item
                 .local_variable_names_include_params
                 .iter()
-                .position(|name| name == local_variable_name)
+                .position(|(name, _)| name == local_variable_name)
             {
                 let reversed_index = self.control_flow_items.len() - level_index - 1;
 
@@ -2050,7 +2795,8 @@ struct AssembleResultForDataNodes {
 }
 
 fn assemble_data_nodes(
-    data_nodes: &[DataNode],
+    data_nodes: &[&DataNode],
+    source_directory: &Path,
 ) -> Result<AssembleResultForDataNodes, AssemblerError> {
     let mut read_only_data_entries: Vec<InitedDataEntry> = vec![];
     let mut read_write_data_entries: Vec<InitedDataEntry> = vec![];
@@ -2060,16 +2806,24 @@ fn assemble_data_nodes(
         let data_name = &data_node.name;
         match &data_node.data_section {
             DataSection::ReadOnly(data_type_value_pair) => {
-                read_only_data_entries.push(conver_data_type_value_pair_to_inited_data_entry(
-                    data_name,
-                    data_type_value_pair,
-                )?);
+                read_only_data_entries.push(
+                    conver_data_type_value_pair_to_inited_data_entry(
+                        data_name,
+                        data_type_value_pair,
+                        source_directory,
+                    )
+                    .map_err(|error| error.with_location(data_node.location))?,
+                );
             }
             DataSection::ReadWrite(data_type_value_pair) => {
-                read_write_data_entries.push(conver_data_type_value_pair_to_inited_data_entry(
-                    data_name,
-                    data_type_value_pair,
-                )?);
+                read_write_data_entries.push(
+                    conver_data_type_value_pair_to_inited_data_entry(
+                        data_name,
+                        data_type_value_pair,
+                        source_directory,
+                    )
+                    .map_err(|error| error.with_location(data_node.location))?,
+                );
             }
             DataSection::Uninit(fixed_declare_data_type) => uninit_data_entries.push(
                 convert_fixed_declare_data_type_to_uninit_data_entry(fixed_declare_data_type),
@@ -2084,6 +2838,55 @@ fn assemble_data_nodes(
     })
 }
 
+/// merge the identical read-only data items (i.e., the items with the same
+/// data type, content and alignment) into the first one, the identifiers of
+/// the merged items become the aliases of the first item.
+///
+/// the public items are never merged since other modules may import them by name.
+fn deduplicate_read_only_data(
+    read_only_data_public_flags: &[bool],
+    read_only_data_identifiers: &mut Vec<String>,
+    read_only_data_entries: &mut Vec<InitedDataEntry>,
+    export_data_entries: &mut Vec<ExportDataEntry>,
+) -> Vec<DeduplicatedDataItem> {
+    let mut deduplicated_data_items: Vec<DeduplicatedDataItem> = vec![];
+
+    // the index of the read-only items (in the original order) to be removed.
+    let mut removed_indices: Vec<usize> = vec![];
+
+    for idx in 0..read_only_data_entries.len() {
+        if read_only_data_public_flags[idx] {
+            continue;
+        }
+
+        let opt_target_idx = (0..idx).find(|prev_idx| {
+            !removed_indices.contains(prev_idx)
+                && read_only_data_entries[*prev_idx] == read_only_data_entries[idx]
+        });
+
+        if let Some(target_idx) = opt_target_idx {
+            deduplicated_data_items.push(DeduplicatedDataItem {
+                identifier: read_only_data_identifiers[idx].to_owned(),
+                target_identifier: read_only_data_identifiers[target_idx].to_owned(),
+                length: read_only_data_entries[idx].length as usize,
+            });
+            removed_indices.push(idx);
+        }
+    }
+
+    // remove items in the reverse order to keep the indices valid.
+    //
+    // note that the read-only data are the first part of
+    // the export data entries, so they share the same index.
+    for idx in removed_indices.iter().rev() {
+        read_only_data_identifiers.remove(*idx);
+        read_only_data_entries.remove(*idx);
+        export_data_entries.remove(*idx);
+    }
+
+    deduplicated_data_items
+}
+
 struct AssembleResultForDependencies {
     import_module_entries: Vec<ImportModuleEntry>,
     import_module_identifiers: Vec<String>,
@@ -2091,23 +2894,118 @@ struct AssembleResultForDependencies {
     external_library_identifiers: Vec<String>,
 }
 
+/// the import modules and external libraries come from the configuration
+/// (i.e., the parameters `config_import_module_entries` and `config_external_library_entries`)
+/// and the declarations in the source file (i.e., `import module ...` and `external library ...`).
+///
+/// the declarations in the source file are appended after the configuration entries,
+/// a declaration which has the same name as a previous entry (in the configuration or
+/// in the source file) must have the same dependency, otherwise an error which records
+/// the origins of both declarations is returned.
 fn assemble_dependencies(
+    import_nodes: &[ImportNode],
+    external_nodes: &[ExternalNode],
     config_import_module_entries: &[ImportModuleEntry],
     config_external_library_entries: &[ExternalLibraryEntry],
 ) -> Result<AssembleResultForDependencies, AssemblerError> {
-    let mut import_module_identifiers: Vec<String> = config_import_module_entries
+    let mut import_module_entries = config_import_module_entries.to_vec();
+    let mut external_library_entries = config_external_library_entries.to_vec();
+
+    for import_node in import_nodes {
+        if let ImportNode::Module(import_module_node) = import_node {
+            let module_dependency = convert_dependency_source_to_module_dependency(
+                &import_module_node.name,
+                &import_module_node.source,
+            )
+            .map_err(|error| error.with_location(import_module_node.location))?;
+
+            match import_module_entries
+                .iter()
+                .position(|entry| entry.name == import_module_node.name)
+            {
+                Some(idx)
+                    if import_module_entries[idx].module_dependency.as_ref()
+                        == &module_dependency =>
+                {
+                    // the same module is already declared.
+                }
+                Some(idx) => {
+                    return Err(
+                        AssemblerError::new(AssembleErrorType::ConflictingImportModule {
+                            module_name: import_module_node.name.to_owned(),
+                            first_origin: get_dependency_origin(
+                                idx,
+                                config_import_module_entries.len(),
+                            ),
+                            second_origin: DependencyOrigin::SourceFile,
+                        })
+                        .with_location(import_module_node.location),
+                    );
+                }
+                None => {
+                    import_module_entries.push(ImportModuleEntry {
+                        name: import_module_node.name.to_owned(),
+                        module_dependency: Box::new(module_dependency),
+                    });
+                }
+            }
+        }
+    }
+
+    for external_node in external_nodes {
+        if let ExternalNode::Library(external_library_node) = external_node {
+            let external_library_dependency =
+                convert_dependency_source_to_external_library_dependency(
+                    &external_library_node.name,
+                    &external_library_node.source,
+                )
+                .map_err(|error| error.with_location(external_library_node.location))?;
+
+            match external_library_entries
+                .iter()
+                .position(|entry| entry.name == external_library_node.name)
+            {
+                Some(idx)
+                    if external_library_entries[idx]
+                        .external_library_dependency
+                        .as_ref()
+                        == &external_library_dependency =>
+                {
+                    // the same library is already declared.
+                }
+                Some(idx) => {
+                    return Err(AssemblerError::new(
+                        AssembleErrorType::ConflictingExternalLibrary {
+                            library_name: external_library_node.name.to_owned(),
+                            first_origin: get_dependency_origin(
+                                idx,
+                                config_external_library_entries.len(),
+                            ),
+                            second_origin: DependencyOrigin::SourceFile,
+                        },
+                    )
+                    .with_location(external_library_node.location));
+                }
+                None => {
+                    external_library_entries.push(ExternalLibraryEntry::new(
+                        external_library_node.name.to_owned(),
+                        Box::new(external_library_dependency),
+                    ));
+                }
+            }
+        }
+    }
+
+    let mut import_module_identifiers: Vec<String> = import_module_entries
         .iter()
         .map(|item| item.name.to_owned())
         .collect();
 
-    let external_library_identifiers: Vec<String> = config_external_library_entries
+    let external_library_identifiers: Vec<String> = external_library_entries
         .iter()
         .map(|item| item.name.to_owned())
         .collect();
 
-    let mut import_module_entries = config_import_module_entries.to_vec();
-    let external_library_entries = config_external_library_entries.to_vec();
-
     // insert the self-referent module to index 0.
     // this module is used for importing other functions and data
     // in the same module.
@@ -2123,6 +3021,96 @@ fn assemble_dependencies(
     })
 }
 
+/// the configuration entries are the first part of the dependency entries,
+/// the declarations in the source file are appended after them.
+fn get_dependency_origin(idx: usize, config_entries_count: usize) -> DependencyOrigin {
+    if idx < config_entries_count {
+        DependencyOrigin::Configuration
+    } else {
+        DependencyOrigin::SourceFile
+    }
+}
+
+/// convert the source of an import module (declared in the source
+/// file or in the module manifest) to the module dependency.
+pub(crate) fn convert_dependency_source_to_module_dependency(
+    module_name: &str,
+    source: &DependencySource,
+) -> Result<ModuleDependency, AssemblerError> {
+    let module_dependency = match source {
+        DependencySource::Version(version) => ModuleDependency::Share(Box::new(DependencyShare {
+            version: version.to_owned(),
+            condition: DependencyCondition::True,
+            parameters: HashMap::default(),
+        })),
+        DependencySource::Local(path) => ModuleDependency::Local(Box::new(DependencyLocal {
+            path: path.to_owned(),
+            condition: DependencyCondition::True,
+            parameters: HashMap::default(),
+        })),
+        DependencySource::Remote(remote) => ModuleDependency::Remote(Box::new(DependencyRemote {
+            url: remote.url.to_owned(),
+            reversion: remote.reversion.to_owned(),
+            condition: DependencyCondition::True,
+            parameters: HashMap::default(),
+        })),
+        DependencySource::Runtime => ModuleDependency::Runtime,
+        DependencySource::System(_) => {
+            // the parser does not accept 'system' for the import module,
+            // but it may be written in the manifest, or the node may be
+            // constructed by other tools.
+            return Err(AssemblerError::new(
+                AssembleErrorType::UnsupportedDependencySource(module_name.to_owned()),
+            ));
+        }
+    };
+
+    Ok(module_dependency)
+}
+
+/// convert the source of an external library (declared in the source
+/// file or in the module manifest) to the external library dependency.
+pub(crate) fn convert_dependency_source_to_external_library_dependency(
+    library_name: &str,
+    source: &DependencySource,
+) -> Result<ExternalLibraryDependency, AssemblerError> {
+    let external_library_dependency = match source {
+        DependencySource::Version(version) => {
+            ExternalLibraryDependency::Share(Box::new(DependencyShare {
+                version: version.to_owned(),
+                condition: DependencyCondition::True,
+                parameters: HashMap::default(),
+            }))
+        }
+        DependencySource::Local(path) => {
+            ExternalLibraryDependency::Local(Box::new(DependencyLocal {
+                path: path.to_owned(),
+                condition: DependencyCondition::True,
+                parameters: HashMap::default(),
+            }))
+        }
+        DependencySource::Remote(remote) => {
+            ExternalLibraryDependency::Remote(Box::new(DependencyRemote {
+                url: remote.url.to_owned(),
+                reversion: remote.reversion.to_owned(),
+                condition: DependencyCondition::True,
+                parameters: HashMap::default(),
+            }))
+        }
+        DependencySource::System(file_name) => {
+            ExternalLibraryDependency::System(file_name.to_owned())
+        }
+        DependencySource::Runtime => {
+            // the same as the 'system' for the import module.
+            return Err(AssemblerError::new(
+                AssembleErrorType::UnsupportedDependencySource(library_name.to_owned()),
+            ));
+        }
+    };
+
+    Ok(external_library_dependency)
+}
+
 struct AssembleResultForImportNodes {
     import_function_entries: Vec<ImportFunctionEntry>,
     import_function_identifiers: Vec<String>,
@@ -2133,11 +3121,13 @@ struct AssembleResultForImportNodes {
 }
 
 fn assemble_import_nodes(
-    module_name: &str,
+    submodule_full_name: &str,
     import_module_identifiers: &[String],
     import_nodes: &[ImportNode],
     type_entries: &mut Vec<TypeEntry>,
 ) -> Result<AssembleResultForImportNodes, AssemblerError> {
+    let (module_name, _) = get_module_name_and_name_path(submodule_full_name);
+
     let mut import_function_entries: Vec<ImportFunctionEntry> = vec![];
     let mut import_function_identifiers: Vec<String> = vec![];
 
@@ -2167,8 +3157,23 @@ fn assemble_import_nodes(
     for import_node in import_nodes {
         match import_node {
             ImportNode::Function(import_function_node) => {
-                let (declare_module_name, name_path) =
-                    get_module_name_and_name_path(&import_function_node.full_name);
+                // the omitted signature should be resolved by `verifier::resolve_import_nodes`
+                // before assembling.
+                let Some((params, results)) = &import_function_node.signature else {
+                    return Err(AssemblerError::new(AssembleErrorType::UnresolvedImportItem(
+                        import_function_node.full_name.to_owned(),
+                    ))
+                    .with_location(import_function_node.location));
+                };
+
+                // convert "self::..." and "super::..." to "module::...".
+                let full_name = resolve_relative_full_name(
+                    &import_function_node.full_name,
+                    submodule_full_name,
+                )
+                .map_err(|error| error.with_location(import_function_node.location))?;
+
+                let (declare_module_name, name_path) = get_module_name_and_name_path(&full_name);
                 let (_, function_name) = get_namespace_and_identifier(name_path);
 
                 // contains "from ..."
@@ -2186,7 +3191,8 @@ fn assemble_import_nodes(
                 };
 
                 let import_module_index =
-                    get_module_index_by_name(import_module_identifiers, canonical_module_name)?;
+                    get_module_index_by_name(import_module_identifiers, canonical_module_name)
+                        .map_err(|error| error.with_location(import_function_node.location))?;
 
                 // use the alias name if it presents.
                 let identifier = if let Some(alias_name) = &import_function_node.alias_name {
@@ -2199,11 +3205,8 @@ fn assemble_import_nodes(
                 import_function_identifiers.push(identifier);
 
                 // get type index
-                let type_index = find_or_create_import_function_type_index(
-                    type_entries,
-                    &import_function_node.params,
-                    &import_function_node.results,
-                );
+                let type_index =
+                    find_or_create_import_function_type_index(type_entries, params, results);
 
                 // the module name in the "full name" is not necessarily the same as
                 // the module (project) name. so the value of the "from" statement is ignored,
@@ -2211,7 +3214,7 @@ fn assemble_import_nodes(
                 let canonical_full_name = if declare_module_name == SELF_REFERENCE_MODULE_NAME {
                     format!("{}::{}", module_name, name_path)
                 } else {
-                    import_function_node.full_name.to_owned()
+                    full_name.to_owned()
                 };
 
                 // add import function entry
@@ -2220,8 +3223,22 @@ fn assemble_import_nodes(
                 import_function_entries.push(import_function_entry);
             }
             ImportNode::Data(import_data_node) => {
-                let (declare_module_name, name_path) =
-                    get_module_name_and_name_path(&import_data_node.full_name);
+                let (Some(data_section_type), Some(data_type)) = (
+                    import_data_node.data_section_type,
+                    import_data_node.data_type,
+                ) else {
+                    return Err(AssemblerError::new(AssembleErrorType::UnresolvedImportItem(
+                        import_data_node.full_name.to_owned(),
+                    ))
+                    .with_location(import_data_node.location));
+                };
+
+                // convert "self::..." and "super::..." to "module::...".
+                let full_name =
+                    resolve_relative_full_name(&import_data_node.full_name, submodule_full_name)
+                        .map_err(|error| error.with_location(import_data_node.location))?;
+
+                let (declare_module_name, name_path) = get_module_name_and_name_path(&full_name);
                 let (_, data_name) = get_namespace_and_identifier(name_path);
 
                 // contains "from ..."
@@ -2239,7 +3256,8 @@ fn assemble_import_nodes(
                 };
 
                 let import_module_index =
-                    get_module_index_by_name(import_module_identifiers, canonical_module_name)?;
+                    get_module_index_by_name(import_module_identifiers, canonical_module_name)
+                        .map_err(|error| error.with_location(import_data_node.location))?;
 
                 // use the alias name if it presents.
                 let identifier = if let Some(alias_name) = &import_data_node.alias_name {
@@ -2254,19 +3272,19 @@ fn assemble_import_nodes(
                 let canonical_full_name = if declare_module_name == SELF_REFERENCE_MODULE_NAME {
                     format!("{}::{}", module_name, name_path)
                 } else {
-                    import_data_node.full_name.to_owned()
+                    full_name.to_owned()
                 };
 
                 // import data entry
                 let import_data_entry = ImportDataEntry::new(
                     canonical_full_name,
                     import_module_index,
-                    import_data_node.data_section_type,
-                    import_data_node.data_type,
+                    data_section_type,
+                    data_type,
                 );
 
                 // add data id
-                match import_data_node.data_section_type {
+                match data_section_type {
                     DataSectionType::ReadOnly => {
                         import_read_only_data_entries.push(import_data_entry);
                         import_read_only_data_identifiers.push(identifier);
@@ -2281,6 +3299,20 @@ fn assemble_import_nodes(
                     }
                 };
             }
+            ImportNode::Module(_) => {
+                // the import modules are handled by `assemble_dependencies`.
+            }
+            ImportNode::Glob(import_glob_node) => {
+                // the glob imports should be expanded by `verifier::expand_glob_import_nodes`
+                // before assembling.
+                return Err(
+                    AssemblerError::new(AssembleErrorType::UnexpandedGlobImport(format!(
+                        "{}::*",
+                        import_glob_node.module_path
+                    )))
+                    .with_location(import_glob_node.location),
+                );
+            }
         }
     }
 
@@ -2302,6 +3334,105 @@ fn assemble_import_nodes(
     Ok(result)
 }
 
+/// the re-exported functions and data (i.e. `pub import fn ...` and `pub import data ...`)
+/// are exported by the re-export entries, the exported name is the full name of
+/// the current submodule joined with the alias name or the original identifier, e.g.
+/// `pub import fn foo::add(i32, i32) -> i32` in the submodule "hello_world::app"
+/// is exported as "hello_world::app::add".
+///
+/// each re-export entry refers to the import entry of the item, i.e. the import function
+/// index and the import data index, the latter follows the order of the import data
+/// entries (read-only, read-write and uninit).
+///
+/// note that the import nodes should have been assembled by `assemble_import_nodes`,
+/// so the sections of the imported data are resolved.
+fn assemble_reexport_entries(
+    import_nodes: &[ImportNode],
+    submodule_full_name: &str,
+) -> (Vec<ReexportFunctionEntry>, Vec<ReexportDataEntry>) {
+    let mut reexport_function_entries: Vec<ReexportFunctionEntry> = vec![];
+    let mut import_function_index = 0;
+
+    // (export full name, section index, index within the section)
+    let mut reexport_data_items: Vec<(String, usize, usize)> = vec![];
+    let mut section_item_counts = [0usize; 3];
+
+    let get_identifier = |full_name: &str, alias_name: &Option<String>| match alias_name {
+        Some(alias_name) => alias_name.to_owned(),
+        None => {
+            let (_, name_path) = get_module_name_and_name_path(full_name);
+            let (_, identifier) = get_namespace_and_identifier(name_path);
+            identifier.to_owned()
+        }
+    };
+
+    for import_node in import_nodes {
+        match import_node {
+            ImportNode::Function(import_function_node) => {
+                if import_function_node.public {
+                    let identifier = get_identifier(
+                        &import_function_node.full_name,
+                        &import_function_node.alias_name,
+                    );
+                    reexport_function_entries.push(ReexportFunctionEntry::new(
+                        get_export_full_name(submodule_full_name, &identifier),
+                        Visibility::Public,
+                        import_function_index,
+                    ));
+                }
+
+                import_function_index += 1;
+            }
+            ImportNode::Data(import_data_node) => {
+                let section_index = match import_data_node
+                    .data_section_type
+                    .expect("the section of the imported data should be resolved")
+                {
+                    DataSectionType::ReadOnly => 0,
+                    DataSectionType::ReadWrite => 1,
+                    DataSectionType::Uninit => 2,
+                };
+
+                if import_data_node.public {
+                    let identifier =
+                        get_identifier(&import_data_node.full_name, &import_data_node.alias_name);
+                    reexport_data_items.push((
+                        get_export_full_name(submodule_full_name, &identifier),
+                        section_index,
+                        section_item_counts[section_index],
+                    ));
+                }
+
+                section_item_counts[section_index] += 1;
+            }
+            ImportNode::Module(_) | ImportNode::Glob(_) => {
+                // not an item
+            }
+        }
+    }
+
+    // the import data entries are sorted by section (read-only, read-write and uninit).
+    let [read_only_data_count, read_write_data_count, _] = section_item_counts;
+    let section_offsets = [
+        0,
+        read_only_data_count,
+        read_only_data_count + read_write_data_count,
+    ];
+
+    let reexport_data_entries: Vec<ReexportDataEntry> = reexport_data_items
+        .into_iter()
+        .map(|(full_name, section_index, idx)| {
+            ReexportDataEntry::new(
+                full_name,
+                Visibility::Public,
+                section_offsets[section_index] + idx,
+            )
+        })
+        .collect();
+
+    (reexport_function_entries, reexport_data_entries)
+}
+
 struct AssembleResultForExternalNode {
     external_function_entries: Vec<ExternalFunctionEntry>,
     external_function_identifiers: Vec<String>,
@@ -2335,7 +3466,8 @@ fn assemble_external_nodes(
                 let (library_name, function_name) =
                     get_library_name_and_identifier(&external_function_node.full_name);
                 let external_library_index =
-                    get_library_index_by_name(external_library_identifiers, library_name)?;
+                    get_library_index_by_name(external_library_identifiers, library_name)
+                        .map_err(|error| error.with_location(external_function_node.location))?;
 
                 // use the alias name if it presents.
                 let identifier = if let Some(alias_name) = &external_function_node.alias_name {
@@ -2373,6 +3505,9 @@ fn assemble_external_nodes(
                 // todo
                 unimplemented!()
             }
+            ExternalNode::Library(_) => {
+                // the external libraries are handled by `assemble_dependencies`.
+            }
         }
     }
 
@@ -2382,12 +3517,111 @@ fn assemble_external_nodes(
     })
 }
 
+/// the integer literal (e.g. `1`, `-1`) is `i32` by default, it is accepted
+/// if the value is in the range of `i16` (either signed or unsigned).
+fn read_data_value_as_i16(data_name: &str, data_value: &DataValue) -> Result<u16, AssemblerError> {
+    let incorrect_type_error = |actual: &str| {
+        AssemblerError::new(AssembleErrorType::IncorrectDataValueType {
+            expected: "i16".to_owned(),
+            actual: actual.to_owned(),
+            data_name: data_name.to_owned(),
+        })
+    };
+
+    match data_value {
+        DataValue::I8(v) => Ok(*v as u16),
+        DataValue::I16(v) => Ok(*v),
+        DataValue::I64(v) => {
+            if (i16::MIN as i64..=u16::MAX as i64).contains(&(*v as i64)) {
+                Ok(*v as u16)
+            } else {
+                Err(incorrect_type_error("i64"))
+            }
+        }
+        DataValue::I32(v) => {
+            if (i16::MIN as i32..=u16::MAX as i32).contains(&(*v as i32)) {
+                Ok(*v as u16)
+            } else {
+                Err(incorrect_type_error("i32"))
+            }
+        }
+        DataValue::Char(v) => {
+            if (*v as u32) <= u16::MAX as u32 {
+                Ok(*v as u16)
+            } else {
+                Err(incorrect_type_error("char"))
+            }
+        }
+        DataValue::F64(_) => Err(incorrect_type_error("f64")),
+        DataValue::F32(_) => Err(incorrect_type_error("f32")),
+        DataValue::String(_) => Err(incorrect_type_error("string")),
+        DataValue::ByteData(_) => Err(incorrect_type_error("byte[]")),
+        DataValue::List(_) => Err(incorrect_type_error("list")),
+        DataValue::Repetition(_, _) => Err(incorrect_type_error("list")),
+        DataValue::Fill(_) => Err(incorrect_type_error("fill")),
+        DataValue::IncludeFile(_) => Err(incorrect_type_error("file")),
+    }
+}
+
+/// the integer literal (e.g. `1`, `-1`) is `i32` by default, it is accepted
+/// if the value is in the range of `i8` (either signed or unsigned).
+fn read_data_value_as_i8(data_name: &str, data_value: &DataValue) -> Result<u8, AssemblerError> {
+    let incorrect_type_error = |actual: &str| {
+        AssemblerError::new(AssembleErrorType::IncorrectDataValueType {
+            expected: "i8".to_owned(),
+            actual: actual.to_owned(),
+            data_name: data_name.to_owned(),
+        })
+    };
+
+    match data_value {
+        DataValue::I8(v) => Ok(*v),
+        DataValue::I16(v) => {
+            if (i8::MIN as i16..=u8::MAX as i16).contains(&(*v as i16)) {
+                Ok(*v as u8)
+            } else {
+                Err(incorrect_type_error("i16"))
+            }
+        }
+        DataValue::I64(v) => {
+            if (i8::MIN as i64..=u8::MAX as i64).contains(&(*v as i64)) {
+                Ok(*v as u8)
+            } else {
+                Err(incorrect_type_error("i64"))
+            }
+        }
+        DataValue::I32(v) => {
+            if (i8::MIN as i32..=u8::MAX as i32).contains(&(*v as i32)) {
+                Ok(*v as u8)
+            } else {
+                Err(incorrect_type_error("i32"))
+            }
+        }
+        DataValue::Char(v) => {
+            if (*v as u32) <= u8::MAX as u32 {
+                Ok(*v as u8)
+            } else {
+                Err(incorrect_type_error("char"))
+            }
+        }
+        DataValue::F64(_) => Err(incorrect_type_error("f64")),
+        DataValue::F32(_) => Err(incorrect_type_error("f32")),
+        DataValue::String(_) => Err(incorrect_type_error("string")),
+        DataValue::ByteData(_) => Err(incorrect_type_error("byte[]")),
+        DataValue::List(_) => Err(incorrect_type_error("list")),
+        DataValue::Repetition(_, _) => Err(incorrect_type_error("list")),
+        DataValue::Fill(_) => Err(incorrect_type_error("fill")),
+        DataValue::IncludeFile(_) => Err(incorrect_type_error("file")),
+    }
+}
+
 fn read_data_value_as_i32(data_name: &str, data_value: &DataValue) -> Result<u32, AssemblerError> {
     match data_value {
         DataValue::I8(v) => Ok(*v as u32),
         DataValue::I16(v) => Ok(*v as u32),
         DataValue::I64(v) => Ok(*v as u32),
         DataValue::I32(v) => Ok(*v),
+        DataValue::Char(v) => Ok(*v as u32),
         DataValue::F64(_) => Err(AssemblerError::new(
             AssembleErrorType::IncorrectDataValueType {
                 expected: "i32".to_owned(),
@@ -2423,6 +3657,27 @@ fn read_data_value_as_i32(data_name: &str, data_value: &DataValue) -> Result<u32
                 data_name: data_name.to_owned(),
             },
         )),
+        DataValue::Repetition(_, _) => Err(AssemblerError::new(
+            AssembleErrorType::IncorrectDataValueType {
+                expected: "i32".to_owned(),
+                actual: "list".to_owned(),
+                data_name: data_name.to_owned(),
+            },
+        )),
+        DataValue::Fill(_) => Err(AssemblerError::new(
+            AssembleErrorType::IncorrectDataValueType {
+                expected: "i32".to_owned(),
+                actual: "fill".to_owned(),
+                data_name: data_name.to_owned(),
+            },
+        )),
+        DataValue::IncludeFile(_) => Err(AssemblerError::new(
+            AssembleErrorType::IncorrectDataValueType {
+                expected: "i32".to_owned(),
+                actual: "file".to_owned(),
+                data_name: data_name.to_owned(),
+            },
+        )),
     }
 }
 
@@ -2432,6 +3687,7 @@ fn read_data_value_as_i64(data_name: &str, data_value: &DataValue) -> Result<u64
         DataValue::I16(v) => Ok(*v as u64),
         DataValue::I64(v) => Ok(*v),
         DataValue::I32(v) => Ok(*v as u64),
+        DataValue::Char(v) => Ok(*v as u64),
         DataValue::F64(_) => Err(AssemblerError::new(
             AssembleErrorType::IncorrectDataValueType {
                 expected: "i64".to_owned(),
@@ -2467,6 +3723,27 @@ fn read_data_value_as_i64(data_name: &str, data_value: &DataValue) -> Result<u64
                 data_name: data_name.to_owned(),
             },
         )),
+        DataValue::Repetition(_, _) => Err(AssemblerError::new(
+            AssembleErrorType::IncorrectDataValueType {
+                expected: "i64".to_owned(),
+                actual: "list".to_owned(),
+                data_name: data_name.to_owned(),
+            },
+        )),
+        DataValue::Fill(_) => Err(AssemblerError::new(
+            AssembleErrorType::IncorrectDataValueType {
+                expected: "i64".to_owned(),
+                actual: "fill".to_owned(),
+                data_name: data_name.to_owned(),
+            },
+        )),
+        DataValue::IncludeFile(_) => Err(AssemblerError::new(
+            AssembleErrorType::IncorrectDataValueType {
+                expected: "i64".to_owned(),
+                actual: "file".to_owned(),
+                data_name: data_name.to_owned(),
+            },
+        )),
     }
 }
 
@@ -2478,6 +3755,13 @@ fn read_data_value_as_f32(data_name: &str, data_value: &DataValue) -> Result<f32
         DataValue::I32(v) => Ok(*v as f32),
         DataValue::F64(v) => Ok(*v as f32),
         DataValue::F32(v) => Ok(*v),
+        DataValue::Char(_) => Err(AssemblerError::new(
+            AssembleErrorType::IncorrectDataValueType {
+                expected: "f32".to_owned(),
+                actual: "char".to_owned(),
+                data_name: data_name.to_owned(),
+            },
+        )),
         DataValue::String(_) => Err(AssemblerError::new(
             AssembleErrorType::IncorrectDataValueType {
                 expected: "f32".to_owned(),
@@ -2499,6 +3783,27 @@ fn read_data_value_as_f32(data_name: &str, data_value: &DataValue) -> Result<f32
                 data_name: data_name.to_owned(),
             },
         )),
+        DataValue::Repetition(_, _) => Err(AssemblerError::new(
+            AssembleErrorType::IncorrectDataValueType {
+                expected: "f32".to_owned(),
+                actual: "list".to_owned(),
+                data_name: data_name.to_owned(),
+            },
+        )),
+        DataValue::Fill(_) => Err(AssemblerError::new(
+            AssembleErrorType::IncorrectDataValueType {
+                expected: "f32".to_owned(),
+                actual: "fill".to_owned(),
+                data_name: data_name.to_owned(),
+            },
+        )),
+        DataValue::IncludeFile(_) => Err(AssemblerError::new(
+            AssembleErrorType::IncorrectDataValueType {
+                expected: "f32".to_owned(),
+                actual: "file".to_owned(),
+                data_name: data_name.to_owned(),
+            },
+        )),
     }
 }
 
@@ -2510,6 +3815,13 @@ fn read_data_value_as_f64(data_name: &str, data_value: &DataValue) -> Result<f64
         DataValue::I32(v) => Ok(*v as f64),
         DataValue::F64(v) => Ok(*v),
         DataValue::F32(v) => Ok(*v as f64),
+        DataValue::Char(_) => Err(AssemblerError::new(
+            AssembleErrorType::IncorrectDataValueType {
+                expected: "f64".to_owned(),
+                actual: "char".to_owned(),
+                data_name: data_name.to_owned(),
+            },
+        )),
         DataValue::String(_) => Err(AssemblerError::new(
             AssembleErrorType::IncorrectDataValueType {
                 expected: "f64".to_owned(),
@@ -2531,40 +3843,174 @@ fn read_data_value_as_f64(data_name: &str, data_value: &DataValue) -> Result<f64
                 data_name: data_name.to_owned(),
             },
         )),
+        DataValue::Repetition(_, _) => Err(AssemblerError::new(
+            AssembleErrorType::IncorrectDataValueType {
+                expected: "f64".to_owned(),
+                actual: "list".to_owned(),
+                data_name: data_name.to_owned(),
+            },
+        )),
+        DataValue::Fill(_) => Err(AssemblerError::new(
+            AssembleErrorType::IncorrectDataValueType {
+                expected: "f64".to_owned(),
+                actual: "fill".to_owned(),
+                data_name: data_name.to_owned(),
+            },
+        )),
+        DataValue::IncludeFile(_) => Err(AssemblerError::new(
+            AssembleErrorType::IncorrectDataValueType {
+                expected: "f64".to_owned(),
+                actual: "file".to_owned(),
+                data_name: data_name.to_owned(),
+            },
+        )),
     }
 }
 
-fn read_data_value_as_bytes(data_value: &DataValue) -> Vec<u8> {
-    let bytes = match data_value {
+fn read_data_value_as_bytes(
+    data_name: &str,
+    data_value: &DataValue,
+    source_directory: &Path,
+) -> Result<Vec<u8>, AssemblerError> {
+    let mut bytes: Vec<u8> = vec![];
+    append_data_value_bytes(data_name, data_value, source_directory, &mut bytes)?;
+    Ok(bytes)
+}
+
+/// append the bytes of the data value to `bytes`, the total length is checked
+/// before the bytes are allocated, so a huge repetition (e.g. `[0_i64; 0x7fffffff]`)
+/// or a long list of repetitions fails without allocating the memory.
+fn append_data_value_bytes(
+    data_name: &str,
+    data_value: &DataValue,
+    source_directory: &Path,
+    bytes: &mut Vec<u8>,
+) -> Result<(), AssemblerError> {
+    let value_bytes = match data_value {
         DataValue::I8(v) => v.to_le_bytes().to_vec(),
         DataValue::I16(v) => v.to_le_bytes().to_vec(),
         DataValue::I64(v) => v.to_le_bytes().to_vec(),
         DataValue::I32(v) => v.to_le_bytes().to_vec(),
         DataValue::F64(v) => v.to_le_bytes().to_vec(),
         DataValue::F32(v) => v.to_le_bytes().to_vec(),
+        DataValue::Char(v) => (*v as u32).to_le_bytes().to_vec(),
         DataValue::String(v) => v.as_bytes().to_vec(),
         DataValue::ByteData(v) => v.to_owned(),
         DataValue::List(v) => {
-            let mut bytes: Vec<u8> = vec![];
             for item in v {
-                let mut b = read_data_value_as_bytes(item);
-                bytes.append(&mut b);
+                append_data_value_bytes(data_name, item, source_directory, bytes)?;
+            }
+            return Ok(());
+        }
+        DataValue::Repetition(v, count) => {
+            let element_bytes = read_data_value_as_bytes(data_name, v, source_directory)?;
+            check_data_length(
+                data_name,
+                bytes.len(),
+                element_bytes.len().checked_mul(*count),
+            )?;
+
+            for _ in 0..*count {
+                bytes.extend_from_slice(&element_bytes);
             }
-            bytes
+            return Ok(());
+        }
+        // the content of the fill value is repeated when it is
+        // converted to the fixed-length byte array, here only
+        // returns the content of one unit.
+        DataValue::Fill(v) => {
+            return append_data_value_bytes(data_name, v, source_directory, bytes);
+        }
+        DataValue::IncludeFile(include_file) => {
+            read_include_file(data_name, include_file, source_directory)?
         }
     };
 
-    bytes
+    check_data_length(data_name, bytes.len(), Some(value_bytes.len()))?;
+    bytes.extend(value_bytes);
+    Ok(())
 }
 
-fn read_argument_value_as_i16(
-    function_name: &str,
-    inst_name: &str,
-    v: &ArgumentValue,
-) -> Result<u16, AssemblerError> {
-    match v {
-        ArgumentValue::Identifier(_) => Err(AssemblerError::new(
-            AssembleErrorType::IncorrectInstructionParameterType {
+/// check the length of the data after appending `additional_length` bytes,
+/// the `None` means the additional length overflows.
+fn check_data_length(
+    data_name: &str,
+    current_length: usize,
+    additional_length: Option<usize>,
+) -> Result<(), AssemblerError> {
+    match additional_length.and_then(|length| current_length.checked_add(length)) {
+        Some(length) if length <= MAX_DATA_LENGTH => Ok(()),
+        opt_length => Err(AssemblerError::new(AssembleErrorType::DataTooLarge {
+            length: opt_length.unwrap_or(usize::MAX),
+            max_length: MAX_DATA_LENGTH,
+            data_name: data_name.to_owned(),
+        })),
+    }
+}
+
+fn read_include_file(
+    data_name: &str,
+    include_file: &IncludeFile,
+    source_directory: &Path,
+) -> Result<Vec<u8>, AssemblerError> {
+    let file_path = source_directory.join(&include_file.file_path);
+
+    let content = std::fs::read(&file_path).map_err(|error| {
+        let error_type = if error.kind() == std::io::ErrorKind::NotFound {
+            AssembleErrorType::IncludeFileNotFound {
+                file_path: include_file.file_path.to_owned(),
+                data_name: data_name.to_owned(),
+            }
+        } else {
+            AssembleErrorType::IncludeFileReadError {
+                file_path: include_file.file_path.to_owned(),
+                message: error.to_string(),
+                data_name: data_name.to_owned(),
+            }
+        };
+        AssemblerError::new(error_type)
+    })?;
+
+    let file_length = content.len();
+    let offset = include_file.offset.unwrap_or(0);
+    let length = include_file
+        .length
+        .unwrap_or(file_length.saturating_sub(offset));
+
+    if offset + length > file_length {
+        return Err(AssemblerError::new(
+            AssembleErrorType::IncludeFileOutOfRange {
+                file_path: include_file.file_path.to_owned(),
+                offset,
+                length,
+                file_length,
+                data_name: data_name.to_owned(),
+            },
+        ));
+    }
+
+    let bytes = content[offset..(offset + length)].to_vec();
+
+    if include_file.file_type == IncludeFileType::Text && std::str::from_utf8(&bytes).is_err() {
+        return Err(AssemblerError::new(
+            AssembleErrorType::IncorrectIncludeFileEncoding {
+                file_path: include_file.file_path.to_owned(),
+                data_name: data_name.to_owned(),
+            },
+        ));
+    }
+
+    Ok(bytes)
+}
+
+fn read_argument_value_as_i16(
+    function_name: &str,
+    inst_name: &str,
+    v: &ArgumentValue,
+) -> Result<u16, AssemblerError> {
+    match v {
+        ArgumentValue::Identifier(..) => Err(AssemblerError::new(
+            AssembleErrorType::IncorrectInstructionParameterType {
                 expected: "i16".to_owned(),
                 actual: "identifier".to_owned(),
                 instruction_name: inst_name.to_owned(),
@@ -2576,6 +4022,17 @@ fn read_argument_value_as_i16(
             LiteralNumber::I16(v) => Ok(*v),
             LiteralNumber::I32(v) => Ok(*v as u16),
             LiteralNumber::I64(v) => Ok(*v as u16),
+            // the char is accepted if its code point is in the range of `i16`
+            // (either signed or unsigned), the same as the char of data value.
+            LiteralNumber::Char(v) if (*v as u32) <= u16::MAX as u32 => Ok(*v as u16),
+            LiteralNumber::Char(_) => Err(AssemblerError::new(
+                AssembleErrorType::IncorrectInstructionParameterType {
+                    expected: "i16".to_owned(),
+                    actual: "char".to_owned(),
+                    instruction_name: inst_name.to_owned(),
+                    function_name: function_name.to_owned(),
+                },
+            )),
             LiteralNumber::F32(_) | LiteralNumber::F64(_) => Err(AssemblerError::new(
                 AssembleErrorType::IncorrectInstructionParameterType {
                     expected: "i16".to_owned(),
@@ -2585,6 +4042,14 @@ fn read_argument_value_as_i16(
                 },
             )),
         },
+        ArgumentValue::LiteralData(_) => Err(AssemblerError::new(
+            AssembleErrorType::IncorrectInstructionParameterType {
+                expected: "i16".to_owned(),
+                actual: "literal data".to_owned(),
+                instruction_name: inst_name.to_owned(),
+                function_name: function_name.to_owned(),
+            },
+        )),
         ArgumentValue::Expression(_) => Err(AssemblerError::new(
             AssembleErrorType::IncorrectInstructionParameterType {
                 expected: "i16".to_owned(),
@@ -2602,7 +4067,7 @@ fn read_argument_value_as_i32(
     v: &ArgumentValue,
 ) -> Result<u32, AssemblerError> {
     match v {
-        ArgumentValue::Identifier(_) => Err(AssemblerError::new(
+        ArgumentValue::Identifier(..) => Err(AssemblerError::new(
             AssembleErrorType::IncorrectInstructionParameterType {
                 expected: "i32".to_owned(),
                 actual: "identifier".to_owned(),
@@ -2615,6 +4080,7 @@ fn read_argument_value_as_i32(
             LiteralNumber::I16(v) => Ok(*v as u32),
             LiteralNumber::I32(v) => Ok(*v),
             LiteralNumber::I64(v) => Ok(*v as u32),
+            LiteralNumber::Char(v) => Ok(*v as u32),
             LiteralNumber::F32(_) | LiteralNumber::F64(_) => Err(AssemblerError::new(
                 AssembleErrorType::IncorrectInstructionParameterType {
                     expected: "i32".to_owned(),
@@ -2624,6 +4090,14 @@ fn read_argument_value_as_i32(
                 },
             )),
         },
+        ArgumentValue::LiteralData(_) => Err(AssemblerError::new(
+            AssembleErrorType::IncorrectInstructionParameterType {
+                expected: "i32".to_owned(),
+                actual: "literal data".to_owned(),
+                instruction_name: inst_name.to_owned(),
+                function_name: function_name.to_owned(),
+            },
+        )),
         ArgumentValue::Expression(_) => Err(AssemblerError::new(
             AssembleErrorType::IncorrectInstructionParameterType {
                 expected: "i32".to_owned(),
@@ -2641,7 +4115,7 @@ fn read_argument_value_as_i64(
     v: &ArgumentValue,
 ) -> Result<u64, AssemblerError> {
     match v {
-        ArgumentValue::Identifier(_) => Err(AssemblerError::new(
+        ArgumentValue::Identifier(..) => Err(AssemblerError::new(
             AssembleErrorType::IncorrectInstructionParameterType {
                 expected: "i64".to_owned(),
                 actual: "identifier".to_owned(),
@@ -2654,6 +4128,7 @@ fn read_argument_value_as_i64(
             LiteralNumber::I16(v) => Ok(*v as u64),
             LiteralNumber::I32(v) => Ok(*v as u64),
             LiteralNumber::I64(v) => Ok(*v),
+            LiteralNumber::Char(v) => Ok(*v as u64),
             LiteralNumber::F32(_) | LiteralNumber::F64(_) => Err(AssemblerError::new(
                 AssembleErrorType::IncorrectInstructionParameterType {
                     expected: "i64".to_owned(),
@@ -2663,6 +4138,14 @@ fn read_argument_value_as_i64(
                 },
             )),
         },
+        ArgumentValue::LiteralData(_) => Err(AssemblerError::new(
+            AssembleErrorType::IncorrectInstructionParameterType {
+                expected: "i64".to_owned(),
+                actual: "literal data".to_owned(),
+                instruction_name: inst_name.to_owned(),
+                function_name: function_name.to_owned(),
+            },
+        )),
         ArgumentValue::Expression(_) => Err(AssemblerError::new(
             AssembleErrorType::IncorrectInstructionParameterType {
                 expected: "i64".to_owned(),
@@ -2680,7 +4163,7 @@ fn read_argument_value_as_f32(
     v: &ArgumentValue,
 ) -> Result<f32, AssemblerError> {
     match v {
-        ArgumentValue::Identifier(_) => Err(AssemblerError::new(
+        ArgumentValue::Identifier(..) => Err(AssemblerError::new(
             AssembleErrorType::IncorrectInstructionParameterType {
                 expected: "f32".to_owned(),
                 actual: "identifier".to_owned(),
@@ -2695,7 +4178,23 @@ fn read_argument_value_as_f32(
             LiteralNumber::I64(v) => Ok(*v as f32),
             LiteralNumber::F32(v) => Ok(*v),
             LiteralNumber::F64(v) => Ok(*v as f32),
+            LiteralNumber::Char(_) => Err(AssemblerError::new(
+                AssembleErrorType::IncorrectInstructionParameterType {
+                    expected: "f32".to_owned(),
+                    actual: "char".to_owned(),
+                    instruction_name: inst_name.to_owned(),
+                    function_name: function_name.to_owned(),
+                },
+            )),
         },
+        ArgumentValue::LiteralData(_) => Err(AssemblerError::new(
+            AssembleErrorType::IncorrectInstructionParameterType {
+                expected: "f32".to_owned(),
+                actual: "literal data".to_owned(),
+                instruction_name: inst_name.to_owned(),
+                function_name: function_name.to_owned(),
+            },
+        )),
         ArgumentValue::Expression(_) => Err(AssemblerError::new(
             AssembleErrorType::IncorrectInstructionParameterType {
                 expected: "f32".to_owned(),
@@ -2713,7 +4212,7 @@ fn read_argument_value_as_f64(
     v: &ArgumentValue,
 ) -> Result<f64, AssemblerError> {
     match v {
-        ArgumentValue::Identifier(_) => Err(AssemblerError::new(
+        ArgumentValue::Identifier(..) => Err(AssemblerError::new(
             AssembleErrorType::IncorrectInstructionParameterType {
                 expected: "f64".to_owned(),
                 actual: "identifier".to_owned(),
@@ -2728,7 +4227,23 @@ fn read_argument_value_as_f64(
             LiteralNumber::I64(v) => Ok(*v as f64),
             LiteralNumber::F32(v) => Ok(*v as f64),
             LiteralNumber::F64(v) => Ok(*v),
+            LiteralNumber::Char(_) => Err(AssemblerError::new(
+                AssembleErrorType::IncorrectInstructionParameterType {
+                    expected: "f64".to_owned(),
+                    actual: "char".to_owned(),
+                    instruction_name: inst_name.to_owned(),
+                    function_name: function_name.to_owned(),
+                },
+            )),
         },
+        ArgumentValue::LiteralData(_) => Err(AssemblerError::new(
+            AssembleErrorType::IncorrectInstructionParameterType {
+                expected: "f64".to_owned(),
+                actual: "literal data".to_owned(),
+                instruction_name: inst_name.to_owned(),
+                function_name: function_name.to_owned(),
+            },
+        )),
         ArgumentValue::Expression(_) => Err(AssemblerError::new(
             AssembleErrorType::IncorrectInstructionParameterType {
                 expected: "f64".to_owned(),
@@ -2746,7 +4261,7 @@ fn read_argument_value_as_expression<'a>(
     v: &'a ArgumentValue,
 ) -> Result<&'a ExpressionNode, AssemblerError> {
     match v {
-        ArgumentValue::Identifier(_) => Err(AssemblerError::new(
+        ArgumentValue::Identifier(..) => Err(AssemblerError::new(
             AssembleErrorType::IncorrectInstructionParameterType {
                 expected: "expression".to_owned(),
                 actual: "identifier".to_owned(),
@@ -2762,6 +4277,14 @@ fn read_argument_value_as_expression<'a>(
                 function_name: function_name.to_owned(),
             },
         )),
+        ArgumentValue::LiteralData(_) => Err(AssemblerError::new(
+            AssembleErrorType::IncorrectInstructionParameterType {
+                expected: "expression".to_owned(),
+                actual: "literal data".to_owned(),
+                instruction_name: inst_name.to_owned(),
+                function_name: function_name.to_owned(),
+            },
+        )),
         ArgumentValue::Expression(exp) => Ok(exp.as_ref()),
     }
 }
@@ -2772,7 +4295,7 @@ fn read_argument_value_as_identifer<'a>(
     v: &'a ArgumentValue,
 ) -> Result<&'a String, AssemblerError> {
     match v {
-        ArgumentValue::Identifier(id) => Ok(id),
+        ArgumentValue::Identifier(id, _) => Ok(id),
         ArgumentValue::LiteralNumber(_) => Err(AssemblerError::new(
             AssembleErrorType::IncorrectInstructionParameterType {
                 expected: "identifier".to_owned(),
@@ -2781,6 +4304,14 @@ fn read_argument_value_as_identifer<'a>(
                 function_name: function_name.to_owned(),
             },
         )),
+        ArgumentValue::LiteralData(_) => Err(AssemblerError::new(
+            AssembleErrorType::IncorrectInstructionParameterType {
+                expected: "identifier".to_owned(),
+                actual: "literal data".to_owned(),
+                instruction_name: inst_name.to_owned(),
+                function_name: function_name.to_owned(),
+            },
+        )),
         ArgumentValue::Expression(_) => Err(AssemblerError::new(
             AssembleErrorType::IncorrectInstructionParameterType {
                 expected: "identifier".to_owned(),
@@ -2792,6 +4323,25 @@ fn read_argument_value_as_identifer<'a>(
     }
 }
 
+/// read the first argument of the data loading instructions, the argument
+/// can be either the identifier of data or an inline data literal.
+fn read_argument_value_as_data_public_index(
+    function_name: &str,
+    inst_name: &str,
+    v: &ArgumentValue,
+    identifier_public_index_lookup_table: &IdentifierPublicIndexLookupTable,
+) -> Result<usize, AssemblerError> {
+    match v {
+        ArgumentValue::LiteralData(data_value) => {
+            identifier_public_index_lookup_table.get_inline_data_public_index(data_value)
+        }
+        _ => {
+            let identifier = read_argument_value_as_identifer(function_name, inst_name, v)?;
+            identifier_public_index_lookup_table.get_data_public_index(identifier)
+        }
+    }
+}
+
 fn get_named_argument_value<'a>(
     named_args: &'a [NamedArgument],
     name: &str,
@@ -2805,6 +4355,7 @@ fn get_named_argument_value<'a>(
 fn conver_data_type_value_pair_to_inited_data_entry(
     data_name: &str, // for building error message only
     data_type_value_pair: &DataTypeValuePair,
+    source_directory: &Path,
 ) -> Result<InitedDataEntry, AssemblerError> {
     let entry = match data_type_value_pair.data_type {
         DeclareDataType::I64 => InitedDataEntry::from_i64(read_data_value_as_i64(
@@ -2815,6 +4366,19 @@ fn conver_data_type_value_pair_to_inited_data_entry(
             data_name,
             &data_type_value_pair.value,
         )?),
+        DeclareDataType::I16 => InitedDataEntry::from_bytes(
+            read_data_value_as_i16(data_name, &data_type_value_pair.value)?
+                .to_le_bytes()
+                .to_vec(),
+            2,
+        ),
+        DeclareDataType::I8 => InitedDataEntry::from_bytes(
+            vec![read_data_value_as_i8(
+                data_name,
+                &data_type_value_pair.value,
+            )?],
+            1,
+        ),
         DeclareDataType::F64 => InitedDataEntry::from_f64(read_data_value_as_f64(
             data_name,
             &data_type_value_pair.value,
@@ -2824,12 +4388,23 @@ fn conver_data_type_value_pair_to_inited_data_entry(
             &data_type_value_pair.value,
         )?),
         DeclareDataType::Bytes(opt_align) => InitedDataEntry::from_bytes(
-            read_data_value_as_bytes(&data_type_value_pair.value),
+            read_data_value_as_bytes(data_name, &data_type_value_pair.value, source_directory)?,
             opt_align.unwrap_or(1) as u16,
         ),
         DeclareDataType::FixedBytes(length, opt_align) => {
-            let mut bytes = read_data_value_as_bytes(&data_type_value_pair.value);
-            bytes.resize(length, 0);
+            // the content is padded (or filled) to the declared length.
+            check_data_length(data_name, 0, Some(length))?;
+
+            let mut bytes =
+                read_data_value_as_bytes(data_name, &data_type_value_pair.value, source_directory)?;
+
+            if matches!(data_type_value_pair.value, DataValue::Fill(_)) && !bytes.is_empty() {
+                // repeat the content of fill value until the byte array is filled.
+                bytes = bytes.iter().copied().cycle().take(length).collect();
+            } else {
+                bytes.resize(length, 0);
+            }
+
             InitedDataEntry::from_bytes(bytes, opt_align.unwrap_or(1) as u16)
         }
     };
@@ -2842,6 +4417,8 @@ fn convert_fixed_declare_data_type_to_uninit_data_entry(
     match fixed_declare_data_type {
         FixedDeclareDataType::I64 => UninitDataEntry::from_i64(),
         FixedDeclareDataType::I32 => UninitDataEntry::from_i32(),
+        FixedDeclareDataType::I16 => UninitDataEntry::from_bytes(2, 2),
+        FixedDeclareDataType::I8 => UninitDataEntry::from_bytes(1, 1),
         FixedDeclareDataType::F64 => UninitDataEntry::from_f64(),
         FixedDeclareDataType::F32 => UninitDataEntry::from_f32(),
         FixedDeclareDataType::FixedBytes(length, opt_align) => {
@@ -2855,24 +4432,31 @@ mod tests {
 
     use std::collections::HashMap;
 
+    use anc_assembly::ast::{ArgumentValue, DataValue, ExpressionNode};
     use anc_image::{
         bytecode_reader::format_bytecode_as_text,
         entry::{
-            ExportDataEntry, ExportFunctionEntry, ExternalLibraryEntry, FunctionEntry,
-            ImageCommonEntry, ImportDataEntry, ImportFunctionEntry, ImportModuleEntry,
-            InitedDataEntry, LocalVariableEntry, LocalVariableListEntry, RelocateEntry,
-            RelocateListEntry, TypeEntry, UninitDataEntry,
+            ExportDataEntry, ExportFunctionEntry, ExternalFunctionEntry, ExternalLibraryEntry,
+            FunctionEntry, ImageCommonEntry, ImportDataEntry, ImportFunctionEntry,
+            ImportModuleEntry, InitedDataEntry, LocalVariableEntry, LocalVariableListEntry,
+            ReexportDataEntry, ReexportFunctionEntry, RelocateEntry, RelocateListEntry, TypeEntry,
+            UninitDataEntry,
         },
         module_image::{RelocateType, Visibility},
     };
     use anc_isa::{
-        DataSectionType, DependencyCondition, DependencyLocal, ExternalLibraryDependency,
-        MemoryDataType, ModuleDependency, OperandDataType,
+        DataSectionType, DependencyCondition, DependencyLocal, DependencyShare, EffectiveVersion,
+        ExternalLibraryDependency, MemoryDataType, ModuleDependency, OperandDataType,
     };
     use anc_parser_asm::parser::parse_from_str;
     use pretty_assertions::assert_eq;
 
-    use super::assemble_module_node;
+    use crate::{AssembleErrorType, AssemblerError, DependencyOrigin};
+
+    use super::{
+        assemble_module_node, assemble_module_node_with_options, check_submodule_versions,
+        parse_module_version, resolve_relative_full_name, AssembleOptions, DeduplicatedDataItem,
+    };
 
     fn assemble(source_code: &str) -> ImageCommonEntry {
         assemble_with_imports_and_externals(source_code, &[], &[])
@@ -3141,6 +4725,514 @@ pub data obj:byte[align=8] = [
         );
     }
 
+    #[test]
+    fn test_assemble_data_statement_with_repetition_and_fill() {
+        let entry = assemble(
+            r#"
+readonly data zeros:byte[] = [0_i32; 4]
+readonly data table:byte[] = [[0x11_i8, 0x13_i8]; 3]
+readonly data matrix:byte[] = [[0xff_i8; 2]; 2]
+data buf:byte[6] = fill(0xcc_i8)
+data pattern:byte[5, align=4] = fill([0x01_i8, 0x02_i8])
+"#,
+        );
+
+        assert_eq!(
+            entry.read_only_data_entries,
+            vec![
+                InitedDataEntry::from_bytes(vec![0; 16], 1),
+                InitedDataEntry::from_bytes(vec![0x11, 0x13, 0x11, 0x13, 0x11, 0x13], 1),
+                InitedDataEntry::from_bytes(vec![0xff; 4], 1),
+            ]
+        );
+
+        assert_eq!(
+            entry.read_write_data_entries,
+            vec![
+                InitedDataEntry::from_bytes(vec![0xcc; 6], 1),
+                InitedDataEntry::from_bytes(vec![0x01, 0x02, 0x01, 0x02, 0x01], 4),
+            ]
+        );
+
+        // the length of the data exceeds the limit
+        for source_code in [
+            "readonly data foo:byte[] = [0_i64; 0x7fffffff]",
+            // each repetition is under the limit, but the total length exceeds it.
+            "readonly data foo:byte[] = [[0_i64; 0x500000], [0_i64; 0x500000]]",
+            "data foo:byte[0x7fffffff] = fill(0xcc_i8)",
+            "data foo:byte[0x7fffffff] = [0_i8]",
+        ] {
+            let module_node = parse_from_str(source_code).unwrap();
+            assert!(matches!(
+                assemble_module_node(&module_node, "mymodule", &[], &[]),
+                Err(AssemblerError {
+                    error_type: AssembleErrorType::DataTooLarge { .. },
+                    ..
+                })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_assemble_data_statement_with_include_file() {
+        let source_directory = std::env::temp_dir().join("anc_assembler_test_include_file");
+        let assets_directory = source_directory.join("assets");
+        std::fs::create_dir_all(&assets_directory).unwrap();
+        std::fs::write(assets_directory.join("table.bin"), [0x11, 0x13, 0x17, 0x19]).unwrap();
+        std::fs::write(source_directory.join("message.txt"), "Hello, World!").unwrap();
+
+        let assemble_with_source_file = |source_code: &str| {
+            let module_node = parse_from_str(source_code).unwrap();
+            assemble_module_node_with_options(
+                &module_node,
+                "mymodule",
+                &[],
+                &[],
+                &AssembleOptions {
+                    source_file_path: Some(source_directory.join("lib.ancasm")),
+                    ..AssembleOptions::default()
+                },
+            )
+            .map(|(entry, _)| entry)
+        };
+
+        let entry = assemble_with_source_file(
+            r#"
+readonly data table:byte[] = include_bytes("assets/table.bin")
+readonly data part:byte[align=2] = include_bytes("assets/table.bin", offset=1, length=2)
+readonly data msg:byte[] = include_str("message.txt", offset=7)
+data buf:byte[8] = [include_str("message.txt", length=5), 0_i8]
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            entry.read_only_data_entries,
+            vec![
+                InitedDataEntry::from_bytes(vec![0x11, 0x13, 0x17, 0x19], 1),
+                InitedDataEntry::from_bytes(vec![0x13, 0x17], 2),
+                InitedDataEntry::from_bytes(b"World!".to_vec(), 1),
+            ]
+        );
+
+        assert_eq!(
+            entry.read_write_data_entries,
+            vec![InitedDataEntry::from_bytes(b"Hello\0\0\0".to_vec(), 1)]
+        );
+
+        // file not found
+        assert!(matches!(
+            assemble_with_source_file(r#"data foo:byte[] = include_bytes("missing.bin")"#),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::IncludeFileNotFound { .. },
+                ..
+            })
+        ));
+
+        // the path is a directory
+        assert!(matches!(
+            assemble_with_source_file(r#"data foo:byte[] = include_bytes("assets")"#),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::IncludeFileReadError { .. },
+                ..
+            })
+        ));
+
+        // out of range
+        assert!(matches!(
+            assemble_with_source_file(
+                r#"data foo:byte[] = include_bytes("assets/table.bin", offset=2, length=4)"#
+            ),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::IncludeFileOutOfRange { .. },
+                ..
+            })
+        ));
+
+        // invalid UTF-8 text
+        std::fs::write(source_directory.join("invalid.txt"), [0xff, 0xfe]).unwrap();
+        assert!(matches!(
+            assemble_with_source_file(r#"data foo:byte[] = include_str("invalid.txt")"#),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::IncorrectIncludeFileEncoding { .. },
+                ..
+            })
+        ));
+
+        // the files of the inline data literals are relative to the source file as well,
+        // and the literals which include different files are not merged.
+        let entry = assemble_with_source_file(
+            r#"
+fn foo() {
+    host_addr_data([include_bytes("assets/table.bin")])
+    host_addr_data([include_str("message.txt", length=5)])
+    host_addr_data([include_bytes("assets/table.bin")])
+}
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            entry.read_only_data_entries,
+            vec![
+                InitedDataEntry::from_bytes(vec![0x11, 0x13, 0x17, 0x19], 1),
+                InitedDataEntry::from_bytes(b"Hello".to_vec(), 1),
+            ]
+        );
+
+        assert!(matches!(
+            assemble_with_source_file(
+                r#"
+fn foo() {
+    host_addr_data([include_bytes("missing0.bin")])
+    host_addr_data([include_bytes("missing1.bin")])
+}
+"#
+            ),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::IncludeFileNotFound { .. },
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_assemble_data_statement_with_i16_and_i8() {
+        let entry = assemble(
+            r#"
+readonly data flag:i8 = 1
+data count:i16 = -2
+data mask:i8 = 0xff
+uninit data port:i16
+uninit data bit:i8
+
+fn foo() [a:i16, b:i8] nop()
+"#,
+        );
+
+        assert_eq!(
+            entry.read_only_data_entries,
+            vec![InitedDataEntry::from_bytes(vec![1], 1)]
+        );
+
+        assert_eq!(
+            entry.read_write_data_entries,
+            vec![
+                InitedDataEntry::from_bytes(vec![0xfe, 0xff], 2),
+                InitedDataEntry::from_bytes(vec![0xff], 1),
+            ]
+        );
+
+        assert_eq!(
+            entry.uninit_data_entries,
+            vec![
+                UninitDataEntry::from_bytes(2, 2),
+                UninitDataEntry::from_bytes(1, 1),
+            ]
+        );
+
+        assert_eq!(
+            entry.local_variable_list_entries[1],
+            LocalVariableListEntry::new(vec![
+                LocalVariableEntry {
+                    memory_data_type: MemoryDataType::Bytes,
+                    length: 2,
+                    align: 2
+                },
+                LocalVariableEntry {
+                    memory_data_type: MemoryDataType::Bytes,
+                    length: 1,
+                    align: 1
+                },
+            ])
+        );
+
+        // out of range
+        let module_node = parse_from_str("data foo:i8 = 256").unwrap();
+        assert!(matches!(
+            assemble_module_node(&module_node, "mymodule", &[], &[]),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::IncorrectDataValueType { .. },
+                ..
+            })
+        ));
+
+        // incorrect value type
+        let module_node = parse_from_str("data foo:i16 = 1.0").unwrap();
+        assert!(matches!(
+            assemble_module_node(&module_node, "mymodule", &[], &[]),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::IncorrectDataValueType { .. },
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_assemble_inline_data_literal() {
+        let entry = assemble(
+            r#"
+readonly data msg:byte[] = "foo"
+data count:i32 = 11
+
+fn foo() {
+    host_addr_data("Hello")
+    data_load_i64([11_i64, 13_i64], offset=8)
+}
+
+fn bar() {
+    host_addr_data("Hello")
+    host_addr_data_extend(h"11 13", imm_i64(0x1))
+}
+"#,
+        );
+
+        // the inline data literals are appended to the read-only data entries,
+        // and the identical literals are merged.
+        assert_eq!(
+            entry.read_only_data_entries,
+            vec![
+                InitedDataEntry::from_bytes(b"foo".to_vec(), 1),
+                InitedDataEntry::from_bytes(b"Hello".to_vec(), 1),
+                InitedDataEntry::from_bytes(
+                    vec![11, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0],
+                    8
+                ),
+                InitedDataEntry::from_bytes(vec![0x11, 0x13], 1),
+            ]
+        );
+
+        assert_eq!(
+            entry.read_write_data_entries,
+            vec![InitedDataEntry::from_i32(11)]
+        );
+
+        assert_eq!(
+            &entry.export_data_entries,
+            &[
+                ExportDataEntry::new(
+                    "mymodule::msg".to_owned(),
+                    Visibility::Private,
+                    DataSectionType::ReadOnly
+                ),
+                ExportDataEntry::new(
+                    "mymodule::$inline_data_0".to_owned(),
+                    Visibility::Private,
+                    DataSectionType::ReadOnly
+                ),
+                ExportDataEntry::new(
+                    "mymodule::$inline_data_1".to_owned(),
+                    Visibility::Private,
+                    DataSectionType::ReadOnly
+                ),
+                ExportDataEntry::new(
+                    "mymodule::$inline_data_2".to_owned(),
+                    Visibility::Private,
+                    DataSectionType::ReadOnly
+                ),
+                ExportDataEntry::new(
+                    "mymodule::count".to_owned(),
+                    Visibility::Private,
+                    DataSectionType::ReadWrite
+                ),
+            ]
+        );
+
+        assert_eq!(
+            format_bytecode_as_text(&entry.function_entries[0].code),
+            "\
+0x0000  43 04 00 00  01 00 00 00    host_addr_data    off:0x00  idx:1
+0x0008  c0 01 08 00  02 00 00 00    data_load_i64     off:0x08  idx:2
+0x0010  c0 03                       end"
+        );
+
+        assert_eq!(
+            format_bytecode_as_text(&entry.function_entries[1].code),
+            "\
+0x0000  43 04 00 00  01 00 00 00    host_addr_data    off:0x00  idx:1
+0x0008  41 01 00 00  01 00 00 00    imm_i64           low:0x00000001  high:0x00000000
+        00 00 00 00
+0x0014  44 04 00 00  03 00 00 00    host_addr_data_extend  idx:3
+0x001c  c0 03                       end"
+        );
+
+        assert_eq!(
+            entry.relocate_list_entries,
+            vec![
+                RelocateListEntry::new(vec![
+                    RelocateEntry::new(4, RelocateType::DataPublicIndex),
+                    RelocateEntry::new(0xc, RelocateType::DataPublicIndex),
+                ]),
+                RelocateListEntry::new(vec![
+                    RelocateEntry::new(4, RelocateType::DataPublicIndex),
+                    RelocateEntry::new(0x18, RelocateType::DataPublicIndex),
+                ]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_assemble_inline_data_literal_with_float() {
+        // the floating-point numbers are compared bitwise, `0.0` and `-0.0` are different.
+        let entry = assemble(
+            r#"
+fn foo() {
+    data_load_f32([0.0_f32])
+    data_load_f32([-0.0_f32])
+    data_load_f32([0.0_f32])
+}
+"#,
+        );
+
+        assert_eq!(
+            entry.read_only_data_entries,
+            vec![
+                InitedDataEntry::from_bytes(0.0_f32.to_le_bytes().to_vec(), 4),
+                InitedDataEntry::from_bytes((-0.0_f32).to_le_bytes().to_vec(), 4),
+            ]
+        );
+
+        assert_eq!(
+            format_bytecode_as_text(&entry.function_entries[0].code),
+            "\
+0x0000  c0 01 00 00  00 00 00 00    data_load_f32     off:0x00  idx:0
+0x0008  c0 01 00 00  01 00 00 00    data_load_f32     off:0x00  idx:1
+0x0010  c0 01 00 00  00 00 00 00    data_load_f32     off:0x00  idx:0
+0x0018  c0 03                       end"
+        );
+
+        // a NaN literal matches itself.
+        let mut module_node = parse_from_str("fn foo() data_load_f64([0.0_f64])").unwrap();
+        let ExpressionNode::Instruction(instruction_node) = &mut *module_node.functions[0].body
+        else {
+            unreachable!()
+        };
+        instruction_node.positional_args[0] =
+            ArgumentValue::LiteralData(DataValue::List(vec![DataValue::F64(f64::NAN)]));
+
+        let entry = assemble_module_node(&module_node, "mymodule", &[], &[]).unwrap();
+        assert_eq!(
+            entry.read_only_data_entries,
+            vec![InitedDataEntry::from_bytes(
+                f64::NAN.to_le_bytes().to_vec(),
+                8
+            )]
+        );
+    }
+
+    #[test]
+    fn test_assemble_deduplicate_read_only_data() {
+        let source_code = r#"
+readonly data msg0:byte[] = "Hello"
+readonly data msg1:byte[] = "Hello"
+pub readonly data msg2:byte[] = "Hello"
+readonly data msg3:byte[align=4] = "Hello"
+readonly data num0:i32 = 11
+readonly data num1:i32 = 11
+data msg4:byte[] = "Hello"
+
+fn foo() {
+    host_addr_data(msg1)
+    host_addr_data(num1)
+    host_addr_data("Hello")
+}
+"#;
+
+        let module_node = parse_from_str(source_code).unwrap();
+        let (entry, report) = assemble_module_node_with_options(
+            &module_node,
+            "mymodule",
+            &[],
+            &[],
+            &AssembleOptions {
+                deduplicate_read_only_data: true,
+                ..AssembleOptions::default()
+            },
+        )
+        .unwrap();
+
+        // - "msg1" and the inline data are merged into "msg0",
+        // - "msg2" is public, so it is kept,
+        // - "msg3" has different alignment,
+        // - "num1" is merged into "num0",
+        // - "msg4" is read-write data.
+        assert_eq!(
+            report.deduplicated_data_items,
+            vec![
+                DeduplicatedDataItem {
+                    identifier: "msg1".to_owned(),
+                    target_identifier: "msg0".to_owned(),
+                    length: 5
+                },
+                DeduplicatedDataItem {
+                    identifier: "num1".to_owned(),
+                    target_identifier: "num0".to_owned(),
+                    length: 4
+                },
+                DeduplicatedDataItem {
+                    identifier: "$inline_data_0".to_owned(),
+                    target_identifier: "msg0".to_owned(),
+                    length: 5
+                },
+            ]
+        );
+        assert_eq!(report.saved_bytes, 14);
+
+        assert_eq!(
+            entry.read_only_data_entries,
+            vec![
+                InitedDataEntry::from_bytes(b"Hello".to_vec(), 1),
+                InitedDataEntry::from_bytes(b"Hello".to_vec(), 1),
+                InitedDataEntry::from_bytes(b"Hello".to_vec(), 4),
+                InitedDataEntry::from_i32(11),
+            ]
+        );
+
+        assert_eq!(
+            &entry.export_data_entries,
+            &[
+                ExportDataEntry::new(
+                    "mymodule::msg0".to_owned(),
+                    Visibility::Private,
+                    DataSectionType::ReadOnly
+                ),
+                ExportDataEntry::new(
+                    "mymodule::msg2".to_owned(),
+                    Visibility::Public,
+                    DataSectionType::ReadOnly
+                ),
+                ExportDataEntry::new(
+                    "mymodule::msg3".to_owned(),
+                    Visibility::Private,
+                    DataSectionType::ReadOnly
+                ),
+                ExportDataEntry::new(
+                    "mymodule::num0".to_owned(),
+                    Visibility::Private,
+                    DataSectionType::ReadOnly
+                ),
+                ExportDataEntry::new(
+                    "mymodule::msg4".to_owned(),
+                    Visibility::Private,
+                    DataSectionType::ReadWrite
+                ),
+            ]
+        );
+
+        assert_eq!(
+            format_bytecode_as_text(&entry.function_entries[0].code),
+            "\
+0x0000  43 04 00 00  00 00 00 00    host_addr_data    off:0x00  idx:0
+0x0008  43 04 00 00  03 00 00 00    host_addr_data    off:0x00  idx:3
+0x0010  43 04 00 00  00 00 00 00    host_addr_data    off:0x00  idx:0
+0x0018  c0 03                       end"
+        );
+
+        // the deduplication is disabled by default
+        let entry = assemble(source_code);
+        assert_eq!(entry.read_only_data_entries.len(), 7);
+    }
+
     #[test]
     fn test_assemble_function_statement() {
         let entry = assemble(
@@ -3217,15 +5309,83 @@ pub data obj:byte[align=8] = [
             &FunctionEntry::new(2, 1, vec![0, 1, 192, 3])
         );
 
-        // function name paths
+        // function name paths
+        assert_eq!(
+            &entry.export_function_entries,
+            &vec![
+                ExportFunctionEntry::new("mymodule::add".to_owned(), Visibility::Private),
+                ExportFunctionEntry::new("mymodule::fib".to_owned(), Visibility::Private),
+                ExportFunctionEntry::new("mymodule::inc".to_owned(), Visibility::Public),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_assemble_char_literal() {
+        assert_eq!(
+            bytecode(
+                r#"
+        fn foo() {
+            imm_i32('A')
+            imm_i32('文')
+            nop()
+        }
+        "#
+            ),
+            "\
+0x0000  40 01 00 00  41 00 00 00    imm_i32           0x00000041
+0x0008  40 01 00 00  87 65 00 00    imm_i32           0x00006587
+0x0010  00 01                       nop
+0x0012  c0 03                       end"
+        );
+
+        let entry = assemble(
+            r#"
+data a:i32 = 'A'
+data b:i8 = '\n'
+data c:byte[] = ['H', 'i']
+"#,
+        );
+
         assert_eq!(
-            &entry.export_function_entries,
-            &vec![
-                ExportFunctionEntry::new("mymodule::add".to_owned(), Visibility::Private),
-                ExportFunctionEntry::new("mymodule::fib".to_owned(), Visibility::Private),
-                ExportFunctionEntry::new("mymodule::inc".to_owned(), Visibility::Public),
+            entry.read_write_data_entries,
+            vec![
+                InitedDataEntry::from_i32(0x41),
+                InitedDataEntry::from_bytes(vec![0x0a], 1),
+                InitedDataEntry::from_bytes(vec![0x48, 0, 0, 0, 0x69, 0, 0, 0], 1),
             ]
         );
+
+        // out of range
+        let module_node = parse_from_str("data foo:i8 = '文'").unwrap();
+        assert!(matches!(
+            assemble_module_node(&module_node, "mymodule", &[], &[]),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::IncorrectDataValueType { .. },
+                ..
+            })
+        ));
+
+        // the code point of the char argument is out of the range of i16
+        let module_node =
+            parse_from_str("data d:i32 = 0\nfn foo() data_load_i32_u(d, offset='😀')").unwrap();
+        assert!(matches!(
+            assemble_module_node(&module_node, "mymodule", &[], &[]),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::IncorrectInstructionParameterType { .. },
+                ..
+            })
+        ));
+
+        // incorrect argument type
+        let module_node = parse_from_str("fn foo() imm_f32('A')").unwrap();
+        assert!(matches!(
+            assemble_module_node(&module_node, "mymodule", &[], &[]),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::IncorrectInstructionParameterType { .. },
+                ..
+            })
+        ));
     }
 
     #[test]
@@ -4193,4 +6353,575 @@ fn bar() {
             ]
         );
     }
+
+    #[test]
+    fn test_assemble_duplicated_identifiers() {
+        let assemble_and_get_duplicated_identifiers = |source_code: &str| {
+            let module_node = parse_from_str(source_code).unwrap();
+            match assemble_module_node(&module_node, "mymodule", &[], &[]) {
+                Err(AssemblerError {
+                    error_type: AssembleErrorType::DuplicatedIdentifiers(duplicated_identifiers),
+                    ..
+                }) => duplicated_identifiers,
+                _ => panic!("Expect duplicated identifiers."),
+            }
+        };
+
+        // function vs import function
+        let duplicated_identifiers = assemble_and_get_duplicated_identifiers(
+            r#"
+import fn math::add(i32,i32)->i32
+fn add() {}
+"#,
+        );
+        assert_eq!(duplicated_identifiers.len(), 1);
+        assert_eq!(duplicated_identifiers[0].identifier, "add");
+        assert_eq!(
+            duplicated_identifiers[0].first_declaration,
+            "import fn \"math::add\""
+        );
+        assert_eq!(duplicated_identifiers[0].second_declaration, "fn \"add\"");
+        assert_eq!(
+            duplicated_identifiers[0].alias_suggestion.as_deref(),
+            Some("import fn \"math::add\"")
+        );
+
+        // two imports collapse to the same identifier
+        let duplicated_identifiers = assemble_and_get_duplicated_identifiers(
+            r#"
+import fn math::add(i32,i32)->i32
+import fn math::int::add(i64,i64)->i64
+"#,
+        );
+        assert_eq!(duplicated_identifiers[0].identifier, "add");
+        assert_eq!(
+            duplicated_identifiers[0].alias_suggestion.as_deref(),
+            Some("import fn \"math::int::add\"")
+        );
+
+        // external function vs function
+        let duplicated_identifiers = assemble_and_get_duplicated_identifiers(
+            r#"
+external fn libabc::abs(i32)->i32
+fn abs() {}
+"#,
+        );
+        assert_eq!(duplicated_identifiers[0].identifier, "abs");
+        assert_eq!(
+            duplicated_identifiers[0].alias_suggestion.as_deref(),
+            Some("external fn \"libabc::abs\"")
+        );
+
+        // two data items
+        let duplicated_identifiers = assemble_and_get_duplicated_identifiers(
+            r#"
+data foo:i32 = 11
+readonly data foo:i64 = 13
+"#,
+        );
+        assert_eq!(duplicated_identifiers[0].identifier, "foo");
+        assert_eq!(duplicated_identifiers[0].alias_suggestion, None);
+
+        // import data vs data
+        let duplicated_identifiers = assemble_and_get_duplicated_identifiers(
+            r#"
+import data mymodule::foo type i32
+data foo:i32 = 11
+"#,
+        );
+        assert_eq!(duplicated_identifiers[0].identifier, "foo");
+        assert!(duplicated_identifiers[0].alias_suggestion.is_some());
+
+        // all duplicates in both namespaces are reported
+        let duplicated_identifiers = assemble_and_get_duplicated_identifiers(
+            r#"
+import fn math::add(i32,i32)->i32
+data foo:i32 = 11
+data foo:i32 = 13
+fn add() {}
+fn sub() {}
+fn sub() {}
+fn add() {}
+"#,
+        );
+        assert_eq!(
+            duplicated_identifiers
+                .iter()
+                .map(|item| (
+                    item.identifier.as_str(),
+                    item.first_declaration.as_str(),
+                    item.second_declaration.as_str()
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                ("add", "import fn \"math::add\"", "fn \"add\""),
+                ("sub", "fn \"sub\"", "fn \"sub\""),
+                ("add", "import fn \"math::add\"", "fn \"add\""),
+                ("foo", "data \"foo\"", "data \"foo\""),
+            ]
+        );
+
+        // functions and data are in different namespaces,
+        // and the alias resolves the conflict.
+        let module_node = parse_from_str(
+            r#"
+import fn math::add(i32,i32)->i32 as math_add
+data add:i32 = 11
+fn add() {}
+"#,
+        )
+        .unwrap();
+
+        assert!(!matches!(
+            assemble_module_node(&module_node, "mymodule", &[], &[]),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::DuplicatedIdentifiers(_),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_assemble_duplicated_local_variables() {
+        let assemble_and_get_error_type = |source_code: &str| {
+            let module_node = parse_from_str(source_code).unwrap();
+            assemble_module_node(&module_node, "mymodule", &[], &[])
+                .unwrap_err()
+                .error_type
+        };
+
+        // local variable vs parameter
+        assert!(matches!(
+            assemble_and_get_error_type(
+                r#"
+fn foo(left:i32) [left:i32] {
+    nop()
+}
+"#
+            ),
+            AssembleErrorType::DuplicatedLocalVariable {
+                variable_name,
+                function_name,
+                ..
+            } if variable_name == "left" && function_name == "foo"
+        ));
+
+        // block parameter vs outer parameter
+        assert!(matches!(
+            assemble_and_get_error_type(
+                r#"
+fn foo(left:i32) {
+    block(left:i32=imm_i32(0x11)) {
+        nop()
+    }
+}
+"#
+            ),
+            AssembleErrorType::DuplicatedLocalVariable {
+                variable_name,
+                ..
+            } if variable_name == "left"
+        ));
+
+        // 'when' local variable vs outer block local variable
+        assert!(matches!(
+            assemble_and_get_error_type(
+                r#"
+fn foo() {
+    block() [temp:i32] {
+        when [temp:i32]
+            eqz_i32(imm_i32(1))
+            nop()
+    }
+}
+"#
+            ),
+            AssembleErrorType::DuplicatedLocalVariable {
+                variable_name,
+                ..
+            } if variable_name == "temp"
+        ));
+
+        // sibling blocks are allowed to have the same local variable names.
+        let module_node = parse_from_str(
+            r#"
+fn foo() {
+    block() [temp:i32] {
+        nop()
+    }
+    block() [temp:i32] {
+        nop()
+    }
+}
+"#,
+        )
+        .unwrap();
+        assert!(assemble_module_node(&module_node, "mymodule", &[], &[]).is_ok());
+    }
+
+    #[test]
+    fn test_assemble_import_module_and_external_library_declarations() {
+        let config_mod = ImportModuleEntry {
+            name: "std".to_owned(),
+            module_dependency: Box::new(ModuleDependency::Runtime),
+        };
+
+        let config_lib = ExternalLibraryEntry::new(
+            "libc".to_owned(),
+            Box::new(ExternalLibraryDependency::System("libc.so.6".to_owned())),
+        );
+
+        let entry = assemble_with_imports_and_externals(
+            r#"
+import module mymod from "modules/mymod"
+import module http_client version "1.2.0"
+import fn mymod::foo()
+import fn http_client::get(i64) -> i64
+import fn std::math::abs(i32) -> i32
+
+external library libfoo from "lib/libfoo.so"
+external library libc system "libc.so.6"    // the same as the configuration
+external fn libfoo::add(i32, i32) -> i32
+external fn libc::getuid() -> i32
+"#,
+            &[config_mod.clone()],
+            &[config_lib.clone()],
+        );
+
+        assert_eq!(
+            entry.import_module_entries,
+            vec![
+                ImportModuleEntry::self_reference_entry(),
+                config_mod,
+                ImportModuleEntry {
+                    name: "mymod".to_owned(),
+                    module_dependency: Box::new(ModuleDependency::Local(Box::new(
+                        DependencyLocal {
+                            path: "modules/mymod".to_owned(),
+                            condition: DependencyCondition::True,
+                            parameters: HashMap::default(),
+                        }
+                    ))),
+                },
+                ImportModuleEntry {
+                    name: "http_client".to_owned(),
+                    module_dependency: Box::new(ModuleDependency::Share(Box::new(
+                        DependencyShare {
+                            version: "1.2.0".to_owned(),
+                            condition: DependencyCondition::True,
+                            parameters: HashMap::default(),
+                        }
+                    ))),
+                },
+            ]
+        );
+
+        assert_eq!(
+            entry.external_library_entries,
+            vec![
+                config_lib,
+                ExternalLibraryEntry::new(
+                    "libfoo".to_owned(),
+                    Box::new(ExternalLibraryDependency::Local(Box::new(
+                        DependencyLocal {
+                            path: "lib/libfoo.so".to_owned(),
+                            condition: DependencyCondition::True,
+                            parameters: HashMap::default(),
+                        }
+                    )))
+                ),
+            ]
+        );
+
+        assert_eq!(
+            entry.import_function_entries,
+            vec![
+                ImportFunctionEntry::new("mymod::foo".to_owned(), 2, 0),
+                ImportFunctionEntry::new("http_client::get".to_owned(), 3, 1),
+                ImportFunctionEntry::new("std::math::abs".to_owned(), 1, 2),
+            ]
+        );
+
+        assert_eq!(
+            entry.external_function_entries,
+            vec![
+                ExternalFunctionEntry::new("add".to_owned(), 1, 3),
+                ExternalFunctionEntry::new("getuid".to_owned(), 0, 4),
+            ]
+        );
+
+        // conflict with the configuration
+        let module_node = parse_from_str(r#"external library libc from "lib/libc.so""#).unwrap();
+        assert!(matches!(
+            assemble_module_node(
+                &module_node,
+                "mymodule",
+                &[],
+                &[ExternalLibraryEntry::new(
+                    "libc".to_owned(),
+                    Box::new(ExternalLibraryDependency::System("libc.so.6".to_owned())),
+                )]
+            ),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::ConflictingExternalLibrary {
+                    library_name,
+                    first_origin: DependencyOrigin::Configuration,
+                    second_origin: DependencyOrigin::SourceFile,
+                },
+                ..
+            }) if library_name == "libc"
+        ));
+
+        // conflict within the source file
+        let module_node = parse_from_str(
+            r#"
+import module mymod from "modules/mymod"
+import module mymod from "modules/other"
+"#,
+        )
+        .unwrap();
+
+        let error = assemble_module_node(&module_node, "mymodule", &[], &[]).unwrap_err();
+        assert!(matches!(
+            &error.error_type,
+            AssembleErrorType::ConflictingImportModule {
+                module_name,
+                first_origin: DependencyOrigin::SourceFile,
+                second_origin: DependencyOrigin::SourceFile,
+            } if module_name == "mymod"
+        ));
+        assert_eq!(
+            error.to_string(),
+            "The import module \"mymod\" is declared more than once in the source file with different dependencies."
+        );
+    }
+
+    #[test]
+    fn test_assemble_module_version() {
+        assert_eq!(
+            parse_module_version("1.2.3").unwrap(),
+            EffectiveVersion::new(1, 2, 3)
+        );
+        assert_eq!(
+            parse_module_version("0.10.65535").unwrap(),
+            EffectiveVersion::new(0, 10, 65535)
+        );
+
+        for version in [
+            "",
+            "1",
+            "1.2",
+            "1.2.3.4",
+            "1.x.3",
+            "1.-2.3",
+            "1.+2.3",
+            "1.2.65536",
+        ] {
+            assert!(matches!(
+                parse_module_version(version),
+                Err(AssemblerError {
+                    error_type: AssembleErrorType::IncorrectModuleVersion(_),
+                    ..
+                })
+            ));
+        }
+
+        let module_node = parse_from_str("fn foo() nop()").unwrap();
+        let assemble_with_version = |submodule_full_name: &str, module_version: Option<&str>| {
+            assemble_module_node_with_options(
+                &module_node,
+                submodule_full_name,
+                &[],
+                &[],
+                &AssembleOptions {
+                    module_version: module_version.map(|version| version.to_owned()),
+                    ..AssembleOptions::default()
+                },
+            )
+            .map(|(entry, _)| entry)
+        };
+
+        // default version
+        assert_eq!(
+            assemble_with_version("mymodule", None).unwrap().version,
+            EffectiveVersion::new(0, 0, 0)
+        );
+
+        let entry0 = assemble_with_version("mymodule", Some("1.2.0")).unwrap();
+        let entry1 = assemble_with_version("mymodule::foo", Some("1.2.0")).unwrap();
+        let entry2 = assemble_with_version("mymodule::bar", Some("1.3.0")).unwrap();
+
+        assert_eq!(entry0.version, EffectiveVersion::new(1, 2, 0));
+        assert!(check_submodule_versions(&[&entry0, &entry1]).is_ok());
+        assert!(matches!(
+            check_submodule_versions(&[&entry0, &entry1, &entry2]),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::InconsistentModuleVersion(module_name),
+                ..
+            }) if module_name == "mymodule"
+        ));
+
+        assert!(matches!(
+            assemble_with_version("mymodule", Some("1.2")),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::IncorrectModuleVersion(_),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_resolve_relative_full_name() {
+        let submodule_full_name = "hello_world::utils::foo";
+
+        assert_eq!(
+            resolve_relative_full_name("self::bar", submodule_full_name).unwrap(),
+            "module::utils::foo::bar"
+        );
+        assert_eq!(
+            resolve_relative_full_name("super::bar", submodule_full_name).unwrap(),
+            "module::utils::bar"
+        );
+        assert_eq!(
+            resolve_relative_full_name("super::super::bar", submodule_full_name).unwrap(),
+            "module::bar"
+        );
+        assert_eq!(
+            resolve_relative_full_name("super::baz::bar", submodule_full_name).unwrap(),
+            "module::utils::baz::bar"
+        );
+        assert_eq!(
+            resolve_relative_full_name("self::super::bar", submodule_full_name).unwrap(),
+            "module::utils::bar"
+        );
+
+        // the non-relative full names
+        assert_eq!(
+            resolve_relative_full_name("std::memory::copy", submodule_full_name).unwrap(),
+            "std::memory::copy"
+        );
+
+        // walks above the module root
+        assert!(matches!(
+            resolve_relative_full_name("super::super::super::bar", submodule_full_name),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::RelativePathAboveModuleRoot { full_name, submodule_full_name },
+                ..
+            }) if full_name == "super::super::super::bar" && submodule_full_name == "hello_world::utils::foo"
+        ));
+
+        assert!(resolve_relative_full_name("super::bar", "hello_world").is_err());
+    }
+
+    #[test]
+    fn test_assemble_import_nodes_with_relative_full_names() {
+        let module_node = parse_from_str(
+            r#"
+import fn self::foo()
+import fn super::bar(i32) -> i32
+import readonly data super::super::msg type byte[]
+"#,
+        )
+        .unwrap();
+
+        let entry =
+            assemble_module_node(&module_node, "hello_world::utils::baz", &[], &[]).unwrap();
+
+        assert_eq!(
+            entry.import_function_entries,
+            vec![
+                ImportFunctionEntry::new("hello_world::utils::baz::foo".to_owned(), 0, 0),
+                ImportFunctionEntry::new("hello_world::utils::bar".to_owned(), 0, 1),
+            ]
+        );
+
+        assert_eq!(
+            entry.import_data_entries,
+            vec![ImportDataEntry::new(
+                "hello_world::msg".to_owned(),
+                0,
+                DataSectionType::ReadOnly,
+                MemoryDataType::Bytes
+            )]
+        );
+
+        // walks above the module root
+        let module_node = parse_from_str("import fn super::foo()").unwrap();
+        assert!(matches!(
+            assemble_module_node(&module_node, "hello_world", &[], &[]),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::RelativePathAboveModuleRoot { .. },
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_assemble_reexport_imported_items() {
+        let module_node = parse_from_str(
+            r#"
+pub import fn module::utils::add(i32, i32) -> i32
+pub import fn module::utils::inc(i64) -> i64 as inc_i64
+import fn module::utils::dec(i32) -> i32
+import uninit data module::utils::buf type i64
+pub import data module::utils::count type i32 as total
+pub import readonly data module::utils::msg type byte[]
+fn main() -> i32
+    call(dec, imm_i32(1))
+"#,
+        )
+        .unwrap();
+
+        let entry = assemble_module_node(&module_node, "hello_world::app", &[], &[]).unwrap();
+
+        // the re-exported items do not add internal functions or export entries
+        assert_eq!(entry.import_function_entries.len(), 3);
+        assert_eq!(entry.function_entries.len(), 1);
+        assert_eq!(
+            &entry.export_function_entries,
+            &vec![ExportFunctionEntry::new(
+                "hello_world::app::main".to_owned(),
+                Visibility::Private
+            )]
+        );
+
+        // the re-export entries refer to the import entries
+        assert_eq!(
+            &entry.reexport_function_entries,
+            &vec![
+                ReexportFunctionEntry::new(
+                    "hello_world::app::add".to_owned(),
+                    Visibility::Public,
+                    0
+                ),
+                ReexportFunctionEntry::new(
+                    "hello_world::app::inc_i64".to_owned(),
+                    Visibility::Public,
+                    1
+                ),
+            ]
+        );
+
+        // the import data entries are sorted by section, i.e.
+        // "msg" (read-only), "count" (read-write) and "buf" (uninit).
+        assert_eq!(
+            &entry.reexport_data_entries,
+            &vec![
+                ReexportDataEntry::new("hello_world::app::total".to_owned(), Visibility::Public, 1),
+                ReexportDataEntry::new("hello_world::app::msg".to_owned(), Visibility::Public, 0),
+            ]
+        );
+
+        // the re-exported function conflicts with the user-defined function
+        let module_node =
+            parse_from_str("pub import fn module::utils::add(i32, i32) -> i32\nfn add() nop()")
+                .unwrap();
+        assert!(matches!(
+            assemble_module_node(&module_node, "hello_world::app", &[], &[]),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::DuplicatedIdentifiers(_),
+                ..
+            })
+        ));
+    }
 }
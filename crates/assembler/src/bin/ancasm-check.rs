@@ -0,0 +1,152 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// check the source files by the parser and the assembler, and
+// print the diagnostics, e.g.
+//
+// `$ ancasm-check --message-format=json src/main.ancasm`
+//
+// or print the explanation of an error code, e.g.
+//
+// `$ ancasm-check --explain A0001`
+
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use anc_assembler::checker::check_source;
+use anc_parser_asm::{
+    diagnostic::{Diagnostic, RenderOptions, Severity},
+    errorcode::explain,
+};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum MessageFormat {
+    // the human readable text, which is printed to the stderr.
+    Human,
+
+    // one JSON object per line, which is printed to the stdout.
+    Json,
+}
+
+const USAGE: &str = "\
+Usage: ancasm-check [OPTIONS] <FILE>...
+       ancasm-check --explain <CODE>
+
+Options:
+    --message-format=<human|json>  the format of the diagnostics, defaults to \"human\"
+    --color                        output the ANSI colour escape codes
+    --explain <CODE>               print the explanation of the error code, e.g. \"A0001\"
+    -h, --help                     print this help";
+
+fn main() -> ExitCode {
+    let mut message_format = MessageFormat::Human;
+    let mut colored = false;
+    let mut file_paths: Vec<PathBuf> = vec![];
+
+    let mut arguments = std::env::args().skip(1);
+    while let Some(argument) = arguments.next() {
+        match argument.as_str() {
+            "--message-format=human" => message_format = MessageFormat::Human,
+            "--message-format=json" => message_format = MessageFormat::Json,
+            "--color" => colored = true,
+            "--explain" => {
+                let Some(code) = arguments.next() else {
+                    eprintln!("Missing the error code.\n\n{}", USAGE);
+                    return ExitCode::from(2);
+                };
+                return print_explanation(&code);
+            }
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return ExitCode::SUCCESS;
+            }
+            _ if argument.starts_with('-') => {
+                eprintln!("Unknown option \"{}\".\n\n{}", argument, USAGE);
+                return ExitCode::from(2);
+            }
+            _ => file_paths.push(PathBuf::from(argument)),
+        }
+    }
+
+    if file_paths.is_empty() {
+        eprintln!("{}", USAGE);
+        return ExitCode::from(2);
+    }
+
+    let render_options = RenderOptions {
+        colored,
+        ..RenderOptions::default()
+    };
+
+    let mut has_error = false;
+
+    for file_path in &file_paths {
+        let (source_code, diagnostics) = check_file(file_path);
+        let file_path_string = file_path.to_string_lossy();
+
+        for diagnostic in &diagnostics {
+            has_error |= diagnostic.severity == Severity::Error;
+
+            match message_format {
+                MessageFormat::Human => {
+                    let options = RenderOptions {
+                        source_name: Some(file_path_string.to_string()),
+                        ..render_options.clone()
+                    };
+                    eprintln!("{}\n", diagnostic.render(&source_code, &options));
+                }
+                MessageFormat::Json => {
+                    println!(
+                        "{}",
+                        diagnostic.to_json(&source_code, Some(&file_path_string))
+                    );
+                }
+            }
+        }
+    }
+
+    if has_error {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn print_explanation(code: &str) -> ExitCode {
+    match explain(code) {
+        Some(entry) => {
+            println!("{}: {}\n\n{}", entry.code, entry.title, entry.explanation);
+            ExitCode::SUCCESS
+        }
+        None => {
+            eprintln!("Unknown error code \"{}\".", code);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn check_file(file_path: &Path) -> (String, Vec<Diagnostic>) {
+    let source_code = match std::fs::read_to_string(file_path) {
+        Ok(source_code) => source_code,
+        Err(error) => {
+            let diagnostic = Diagnostic::error(&format!(
+                "Can not read the file \"{}\": {}.",
+                file_path.to_string_lossy(),
+                error
+            ));
+            return (String::new(), vec![diagnostic]);
+        }
+    };
+
+    // the absolute path is required for locating the module.
+    let absolute_file_path =
+        std::fs::canonicalize(file_path).unwrap_or_else(|_| file_path.to_path_buf());
+    let diagnostics = check_source(&source_code, Some(&absolute_file_path));
+
+    (source_code, diagnostics)
+}
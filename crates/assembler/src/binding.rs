@@ -0,0 +1,650 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the Rust binding generator.
+//
+// it generates a Rust module which contains one typed wrapper function for
+// each public function of an ancasm (sub)module, the wrapper converts the
+// arguments to `ForeignValue`s, calls the function by `process_function`
+// and converts the results back, e.g.
+//
+// ```rust
+// pub fn add(
+//     handler: &Handler,
+//     thread_context: &mut ThreadContext,
+//     left: i32,
+//     right: i32,
+// ) -> Result<i32, HandlerError> {
+//     ...
+// }
+// ```
+//
+// so the changes of the function signatures become compile errors of
+// the host program instead of runtime panics.
+
+use anc_assembly::ast::{ImportNode, ModuleNode};
+use anc_image::{entry::ImageCommonEntry, module_image::Visibility};
+use anc_isa::OperandDataType;
+use anc_parser_asm::NAME_PATH_SEPARATOR;
+
+use crate::{AssembleErrorType, AssemblerError};
+
+// the names of the parameters and local variables of the wrapper functions,
+// the parameters of the ancasm functions with the same names are renamed.
+const RESERVED_NAMES: [&str; 3] = ["handler", "thread_context", "results"];
+
+const RUST_KEYWORDS: [&str; 38] = [
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while",
+];
+
+struct BindingFunction {
+    name: String,
+    function_public_index: usize,
+    params: Vec<(String, OperandDataType)>,
+    results: Vec<OperandDataType>,
+    document: Option<String>,
+}
+
+/// generate the Rust binding of the public functions of a (sub)module
+/// by its source.
+///
+/// the function public indices are calculated in the same way as the assembler,
+/// i.e. the imported functions first, then the internal functions, so the
+/// glob imports must be expanded and the omitted signatures of the re-exported
+/// functions must be resolved before generating, see the `verifier` module.
+///
+/// `module_index` is the index of the module in the application image,
+/// it is 0 for the main module.
+pub fn generate_rust_binding(
+    module_node: &ModuleNode,
+    module_index: usize,
+) -> Result<String, AssemblerError> {
+    let mut import_function_count = 0;
+    for import_node in &module_node.imports {
+        match import_node {
+            ImportNode::Function(_) => import_function_count += 1,
+            ImportNode::Glob(import_glob_node) => {
+                return Err(
+                    AssemblerError::new(AssembleErrorType::UnexpandedGlobImport(format!(
+                        "{}::*",
+                        import_glob_node.module_path
+                    )))
+                    .with_location(import_glob_node.location),
+                );
+            }
+            _ => {
+                // not a function
+            }
+        }
+    }
+
+    let mut binding_functions = vec![];
+
+    for (function_index, function_node) in module_node.functions.iter().enumerate() {
+        if !function_node.public {
+            continue;
+        }
+
+        binding_functions.push(BindingFunction {
+            name: function_node.name.to_owned(),
+            function_public_index: import_function_count + function_index,
+            params: function_node
+                .params
+                .iter()
+                .map(|param| (param.name.to_owned(), param.data_type))
+                .collect(),
+            results: function_node.results.clone(),
+            document: function_node.document.clone(),
+        });
+    }
+
+    // the re-exported functions (i.e. `pub import fn ...`) are exported by the
+    // re-export entries, which refer to the imported functions, so their public
+    // indices are the indices of the imported functions.
+    let mut import_function_index = 0;
+    for import_node in &module_node.imports {
+        let ImportNode::Function(import_function_node) = import_node else {
+            continue;
+        };
+
+        let function_public_index = import_function_index;
+        import_function_index += 1;
+
+        if !import_function_node.public {
+            continue;
+        }
+
+        let Some((params, results)) = &import_function_node.signature else {
+            return Err(AssemblerError::new(AssembleErrorType::UnresolvedImportItem(
+                import_function_node.full_name.to_owned(),
+            ))
+            .with_location(import_function_node.location));
+        };
+
+        let name = match &import_function_node.alias_name {
+            Some(alias_name) => alias_name.to_owned(),
+            None => get_identifier(&import_function_node.full_name).to_owned(),
+        };
+
+        binding_functions.push(BindingFunction {
+            name,
+            function_public_index,
+            params: get_anonymous_params(params),
+            results: results.clone(),
+            document: import_function_node.document.clone(),
+        });
+    }
+
+    Ok(format_binding(&binding_functions, module_index))
+}
+
+/// generate the Rust binding of the public functions of an assembled (sub)module,
+/// the names of the parameters are "p0", "p1", ... since they are not
+/// preserved in the image.
+pub fn generate_rust_binding_by_image_common_entry(
+    image_common_entry: &ImageCommonEntry,
+    module_index: usize,
+) -> String {
+    let import_function_count = image_common_entry.import_function_entries.len();
+
+    // the export function entries are in the same order as the internal functions.
+    let mut binding_functions: Vec<BindingFunction> = image_common_entry
+        .export_function_entries
+        .iter()
+        .zip(&image_common_entry.function_entries)
+        .enumerate()
+        .filter(|(_, (export_function_entry, _))| {
+            export_function_entry.visibility == Visibility::Public
+        })
+        .map(
+            |(function_index, (export_function_entry, function_entry))| {
+                let type_entry = &image_common_entry.type_entries[function_entry.type_index];
+                BindingFunction {
+                    name: get_identifier(&export_function_entry.full_name).to_owned(),
+                    function_public_index: import_function_count + function_index,
+                    params: get_anonymous_params(&type_entry.params),
+                    results: type_entry.results.clone(),
+                    document: None,
+                }
+            },
+        )
+        .collect();
+
+    // the public index of a re-exported function is the index of the imported function.
+    for reexport_function_entry in &image_common_entry.reexport_function_entries {
+        if reexport_function_entry.visibility != Visibility::Public {
+            continue;
+        }
+
+        let import_function_index = reexport_function_entry.import_function_index;
+        let import_function_entry =
+            &image_common_entry.import_function_entries[import_function_index];
+        let type_entry = &image_common_entry.type_entries[import_function_entry.type_index];
+        binding_functions.push(BindingFunction {
+            name: get_identifier(&reexport_function_entry.full_name).to_owned(),
+            function_public_index: import_function_index,
+            params: get_anonymous_params(&type_entry.params),
+            results: type_entry.results.clone(),
+            document: None,
+        });
+    }
+
+    format_binding(&binding_functions, module_index)
+}
+
+fn get_identifier(full_name: &str) -> &str {
+    full_name
+        .rsplit_once(NAME_PATH_SEPARATOR)
+        .map(|(_, identifier)| identifier)
+        .unwrap_or(full_name)
+}
+
+fn get_anonymous_params(params: &[OperandDataType]) -> Vec<(String, OperandDataType)> {
+    params
+        .iter()
+        .enumerate()
+        .map(|(idx, data_type)| (format!("p{}", idx), *data_type))
+        .collect()
+}
+
+// the keywords are converted to the raw identifiers, except the ones
+// which can not be raw identifiers, e.g. "self" -> "self_".
+fn get_rust_identifier(name: &str) -> String {
+    if RUST_KEYWORDS.contains(&name) {
+        if matches!(name, "crate" | "self" | "Self" | "super") {
+            format!("{}_", name)
+        } else {
+            format!("r#{}", name)
+        }
+    } else {
+        name.to_owned()
+    }
+}
+
+fn get_rust_param_name(name: &str) -> String {
+    if RESERVED_NAMES.contains(&name) {
+        format!("{}_", name)
+    } else {
+        get_rust_identifier(name)
+    }
+}
+
+fn get_rust_type_name(data_type: &OperandDataType) -> &'static str {
+    match data_type {
+        OperandDataType::I64 => "i64",
+        OperandDataType::I32 => "i32",
+        OperandDataType::F64 => "f64",
+        OperandDataType::F32 => "f32",
+    }
+}
+
+// convert the Rust value to `ForeignValue`, e.g.
+// "left" -> "ForeignValue::U32(left as u32)"
+fn format_foreign_value(name: &str, data_type: &OperandDataType) -> String {
+    match data_type {
+        OperandDataType::I64 => format!("ForeignValue::U64({} as u64)", name),
+        OperandDataType::I32 => format!("ForeignValue::U32({} as u32)", name),
+        OperandDataType::F64 => format!("ForeignValue::F64({})", name),
+        OperandDataType::F32 => format!("ForeignValue::F32({})", name),
+    }
+}
+
+// convert the `ForeignValue` to the Rust value, e.g.
+// "results[0]" -> "match results[0] { ForeignValue::U32(value) => value as i32, ... }"
+//
+// the mismatched data type is returned as an error instead of panicking,
+// since the image may be changed after the binding is generated.
+fn format_rust_value(value: &str, data_type: &OperandDataType) -> String {
+    let (variant, conversion) = match data_type {
+        OperandDataType::I64 => ("U64", " as i64"),
+        OperandDataType::I32 => ("U32", " as i32"),
+        OperandDataType::F64 => ("F64", ""),
+        OperandDataType::F32 => ("F32", ""),
+    };
+
+    format!(
+        "match {} {{ ForeignValue::{}(value) => value{}, _ => return Err(HandlerError {{ error_type: HandleErrorType::DataTypeMissmatch }}) }}",
+        value, variant, conversion
+    )
+}
+
+fn format_binding(binding_functions: &[BindingFunction], module_index: usize) -> String {
+    let mut text = String::new();
+
+    text.push_str(
+        "\
+// This file is generated by `anc_assembler::binding`, do not edit it manually.
+
+use anc_context::thread_context::ThreadContext;
+use anc_isa::ForeignValue;
+",
+    );
+
+    // `HandleErrorType` is only used for converting the results.
+    if binding_functions
+        .iter()
+        .any(|binding_function| !binding_function.results.is_empty())
+    {
+        text.push_str(
+            "use anc_processor::{handler::Handler, process::process_function, HandleErrorType, HandlerError};\n",
+        );
+    } else {
+        text.push_str(
+            "use anc_processor::{handler::Handler, process::process_function, HandlerError};\n",
+        );
+    }
+
+    text.push_str(&format!(
+        "\npub const MODULE_INDEX: usize = {};\n",
+        module_index
+    ));
+
+    for binding_function in binding_functions {
+        text.push('\n');
+        text.push_str(&format_binding_function(binding_function));
+    }
+
+    text
+}
+
+fn format_binding_function(binding_function: &BindingFunction) -> String {
+    let mut text = String::new();
+
+    if let Some(document) = &binding_function.document {
+        for line in document.split('\n') {
+            if line.is_empty() {
+                text.push_str("///\n");
+            } else {
+                text.push_str(&format!("/// {}\n", line));
+            }
+        }
+    }
+
+    let mut param_names: Vec<String> = vec![];
+    for (name, _) in &binding_function.params {
+        // the renamed parameter may conflict with another parameter,
+        // e.g. "handler" is renamed to "handler_" while there is a parameter
+        // named "handler_", so append more underscores.
+        let mut param_name = get_rust_param_name(name);
+        while param_names.contains(&param_name) {
+            param_name.push('_');
+        }
+        param_names.push(param_name);
+    }
+
+    let results = &binding_function.results;
+    let result_type = match results.len() {
+        0 => "()".to_owned(),
+        1 => get_rust_type_name(&results[0]).to_owned(),
+        _ => format!(
+            "({})",
+            results
+                .iter()
+                .map(get_rust_type_name)
+                .collect::<Vec<&str>>()
+                .join(", ")
+        ),
+    };
+
+    // the signature
+    text.push_str(&format!(
+        "pub fn {}(\n    handler: &Handler,\n    thread_context: &mut ThreadContext,\n",
+        get_rust_identifier(&binding_function.name)
+    ));
+
+    for (name, (_, data_type)) in param_names.iter().zip(&binding_function.params) {
+        text.push_str(&format!(
+            "    {}: {},\n",
+            name,
+            get_rust_type_name(data_type)
+        ));
+    }
+
+    text.push_str(&format!(") -> Result<{}, HandlerError> {{\n", result_type));
+
+    // the body
+    let arguments: Vec<String> = param_names
+        .iter()
+        .zip(&binding_function.params)
+        .map(|(name, (_, data_type))| {
+            format!("            {},\n", format_foreign_value(name, data_type))
+        })
+        .collect();
+
+    let arguments_text = if arguments.is_empty() {
+        "&[]".to_owned()
+    } else {
+        format!("&[\n{}        ]", arguments.join(""))
+    };
+
+    text.push_str(&format!(
+        "    let {} = process_function(\n        handler,\n        thread_context,\n        MODULE_INDEX,\n        {},\n        {},\n    )?;\n",
+        if results.is_empty() { "_" } else { "results" },
+        binding_function.function_public_index,
+        arguments_text
+    ));
+
+    match results.len() {
+        0 => {
+            text.push_str("    Ok(())\n");
+        }
+        1 => {
+            text.push_str(&format!(
+                "    Ok({})\n",
+                format_rust_value("results[0]", &results[0])
+            ));
+        }
+        _ => {
+            text.push_str("    Ok((\n");
+            for (idx, data_type) in results.iter().enumerate() {
+                text.push_str(&format!(
+                    "        {},\n",
+                    format_rust_value(&format!("results[{}]", idx), data_type)
+                ));
+            }
+            text.push_str("    ))\n");
+        }
+    }
+
+    text.push_str("}\n");
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use anc_parser_asm::parser::parse_from_str;
+    use pretty_assertions::assert_eq;
+
+    use crate::{assembler::assemble_module_node, AssembleErrorType, AssemblerError};
+
+    use super::{generate_rust_binding, generate_rust_binding_by_image_common_entry};
+
+    #[test]
+    fn test_generate_rust_binding() {
+        let module_node = parse_from_str(
+            r#"
+import fn std::memory::copy(i64, i64, i64) -> ()
+
+/// Adds two numbers.
+pub fn add(left:i32, right:i32) -> i32
+    add_i32(local_load_i32_s(left), local_load_i32_s(right))
+
+fn helper() nop()
+
+pub fn swap(handler:f64, ref:i64) -> (i64, f64)
+{
+    local_load_i64(ref)
+    local_load_f64(handler)
+}
+
+pub fn reset() nop()
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            generate_rust_binding(&module_node, 0).unwrap(),
+            "\
+// This file is generated by `anc_assembler::binding`, do not edit it manually.
+
+use anc_context::thread_context::ThreadContext;
+use anc_isa::ForeignValue;
+use anc_processor::{handler::Handler, process::process_function, HandleErrorType, HandlerError};
+
+pub const MODULE_INDEX: usize = 0;
+
+/// Adds two numbers.
+pub fn add(
+    handler: &Handler,
+    thread_context: &mut ThreadContext,
+    left: i32,
+    right: i32,
+) -> Result<i32, HandlerError> {
+    let results = process_function(
+        handler,
+        thread_context,
+        MODULE_INDEX,
+        1,
+        &[
+            ForeignValue::U32(left as u32),
+            ForeignValue::U32(right as u32),
+        ],
+    )?;
+    Ok(match results[0] { ForeignValue::U32(value) => value as i32, _ => return Err(HandlerError { error_type: HandleErrorType::DataTypeMissmatch }) })
+}
+
+pub fn swap(
+    handler: &Handler,
+    thread_context: &mut ThreadContext,
+    handler_: f64,
+    r#ref: i64,
+) -> Result<(i64, f64), HandlerError> {
+    let results = process_function(
+        handler,
+        thread_context,
+        MODULE_INDEX,
+        3,
+        &[
+            ForeignValue::F64(handler_),
+            ForeignValue::U64(r#ref as u64),
+        ],
+    )?;
+    Ok((
+        match results[0] { ForeignValue::U64(value) => value as i64, _ => return Err(HandlerError { error_type: HandleErrorType::DataTypeMissmatch }) },
+        match results[1] { ForeignValue::F64(value) => value, _ => return Err(HandlerError { error_type: HandleErrorType::DataTypeMissmatch }) },
+    ))
+}
+
+pub fn reset(
+    handler: &Handler,
+    thread_context: &mut ThreadContext,
+) -> Result<(), HandlerError> {
+    let _ = process_function(
+        handler,
+        thread_context,
+        MODULE_INDEX,
+        4,
+        &[],
+    )?;
+    Ok(())
+}
+"
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_binding_by_image_common_entry() {
+        let module_node = parse_from_str(
+            r#"
+fn helper() nop()
+
+pub fn add(left:i32, right:i32) -> i32
+    add_i32(local_load_i32_s(left), local_load_i32_s(right))
+"#,
+        )
+        .unwrap();
+
+        let image_common_entry = assemble_module_node(&module_node, "mymodule", &[], &[]).unwrap();
+        let binding = generate_rust_binding_by_image_common_entry(&image_common_entry, 0);
+
+        assert!(binding.contains(
+            "\
+pub fn add(
+    handler: &Handler,
+    thread_context: &mut ThreadContext,
+    p0: i32,
+    p1: i32,
+) -> Result<i32, HandlerError> {
+    let results = process_function(
+        handler,
+        thread_context,
+        MODULE_INDEX,
+        1,"
+        ));
+        assert!(!binding.contains("pub fn helper("));
+    }
+
+    #[test]
+    fn test_generate_rust_binding_with_reexported_functions() {
+        let module_node = parse_from_str(
+            r#"
+import fn module::utils::dec(i32) -> i32
+pub import fn module::utils::add(i32, i32) -> i32 as plus
+pub fn inc(value:i32) -> i32 nop()
+"#,
+        )
+        .unwrap();
+
+        // the public index of the re-exported function is the index of the imported function,
+        // and the internal functions follow the imported functions.
+        let expected_plus = "\
+pub fn plus(
+    handler: &Handler,
+    thread_context: &mut ThreadContext,
+    p0: i32,
+    p1: i32,
+) -> Result<i32, HandlerError> {
+    let results = process_function(
+        handler,
+        thread_context,
+        MODULE_INDEX,
+        1,";
+
+        let binding = generate_rust_binding(&module_node, 0).unwrap();
+        assert!(binding.contains(expected_plus));
+        assert!(binding.contains("MODULE_INDEX,\n        2,"));
+        assert!(!binding.contains("pub fn dec("));
+
+        // the binding of the assembled image is the same
+        let image_common_entry =
+            assemble_module_node(&module_node, "mymodule::app", &[], &[]).unwrap();
+        let binding = generate_rust_binding_by_image_common_entry(&image_common_entry, 0);
+        assert!(binding.contains(expected_plus));
+        assert!(binding.contains("MODULE_INDEX,\n        2,"));
+        assert!(!binding.contains("pub fn dec("));
+    }
+
+    #[test]
+    fn test_generate_rust_binding_with_conflicting_param_names() {
+        let module_node = parse_from_str(
+            r#"
+pub fn foo(handler_:i32, handler:i32) nop()
+pub fn bar(handler:i32, handler_:i32, handler__:i32) nop()
+"#,
+        )
+        .unwrap();
+
+        let binding = generate_rust_binding(&module_node, 0).unwrap();
+
+        assert!(binding.contains(
+            "\
+pub fn foo(
+    handler: &Handler,
+    thread_context: &mut ThreadContext,
+    handler_: i32,
+    handler__: i32,
+) -> Result<(), HandlerError> {"
+        ));
+
+        assert!(binding.contains(
+            "\
+pub fn bar(
+    handler: &Handler,
+    thread_context: &mut ThreadContext,
+    handler_: i32,
+    handler__: i32,
+    handler___: i32,
+) -> Result<(), HandlerError> {"
+        ));
+
+        // `HandleErrorType` is not imported when there are no results.
+        assert!(binding.contains(
+            "use anc_processor::{handler::Handler, process::process_function, HandlerError};"
+        ));
+    }
+
+    #[test]
+    fn test_generate_rust_binding_with_unexpanded_glob_import() {
+        let module_node = parse_from_str(
+            r#"
+import fn std::memory::*
+pub fn foo() nop()
+"#,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            generate_rust_binding(&module_node, 0),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::UnexpandedGlobImport(_),
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// check the source file by the parser and the assembler.
+//
+// the import modules and external libraries are declared in the manifest,
+// so they are only available when the source file is in the "src" folder
+// of a module (i.e. the folder which contains the manifest "module.ason"),
+// otherwise the source file is assembled without dependencies.
+
+use std::path::{Path, PathBuf};
+
+use anc_parser_asm::{diagnostic::Diagnostic, parser::parse_from_str};
+
+use crate::{
+    assembler::{assemble_module_node, assemble_module_node_with_options, AssembleOptions},
+    manifest::{
+        get_submodule_full_name, load_manifest, parse_valid_submodule_sources, MANIFEST_FILE_NAME,
+        SOURCE_DIRECTORY_NAME,
+    },
+    verifier::{expand_glob_import_nodes, SiblingSubmodule},
+};
+
+// the module name of the source file which does not belong to a module.
+const STANDALONE_MODULE_NAME: &str = "module";
+
+/// check the source code, returns the first error of the parser or the assembler.
+///
+/// `source_file_path` is used to locate the module which the source file belongs to.
+pub fn check_source(source_code: &str, source_file_path: Option<&Path>) -> Vec<Diagnostic> {
+    let mut module_node = match parse_from_str(source_code) {
+        Ok(node) => node,
+        Err(error) => return vec![error.to_diagnostic(source_code)],
+    };
+
+    let module_file_paths = source_file_path.and_then(|source_file_path| {
+        find_module_directory(source_file_path)
+            .map(|module_directory| (source_file_path, module_directory))
+    });
+
+    let Some((source_file_path, module_directory)) = module_file_paths else {
+        return match assemble_module_node(&module_node, STANDALONE_MODULE_NAME, &[], &[]) {
+            Ok(_) => vec![],
+            Err(error) => error.to_diagnostics(),
+        };
+    };
+
+    let manifest = match load_manifest(&module_directory.join(MANIFEST_FILE_NAME)) {
+        Ok(manifest) => manifest,
+        Err(error) => {
+            return vec![Diagnostic::error(&error.to_string()).with_code(error.get_code())]
+        }
+    };
+
+    let source_directory = module_directory.join(SOURCE_DIRECTORY_NAME);
+    let submodule_full_name =
+        get_submodule_full_name(&manifest.name, &source_directory, source_file_path);
+
+    // the sibling submodules are read from the disk, the ones with errors are
+    // skipped, and the glob imports which refer to them are reported as unexpanded.
+    let parsed_submodules = parse_valid_submodule_sources(&manifest.name, &module_directory);
+
+    let sibling_submodules: Vec<SiblingSubmodule> = parsed_submodules
+        .iter()
+        .filter(|(name, _, _)| name != &submodule_full_name)
+        .map(|(submodule_full_name, _, module_node)| SiblingSubmodule {
+            submodule_full_name,
+            module_node,
+        })
+        .collect();
+
+    let result =
+        expand_glob_import_nodes(&module_node, &submodule_full_name, &[], &sibling_submodules)
+            .and_then(|import_nodes| {
+                module_node.imports = import_nodes;
+
+                let options = AssembleOptions {
+                    deduplicate_read_only_data: manifest.options.deduplicate_read_only_data,
+                    source_file_path: Some(source_file_path.to_path_buf()),
+                    module_version: Some(manifest.version.clone()),
+                };
+
+                assemble_module_node_with_options(
+                    &module_node,
+                    &submodule_full_name,
+                    &manifest.get_import_module_entries(),
+                    &manifest.get_external_library_entries(),
+                    &options,
+                )
+            });
+
+    match result {
+        Ok(_) => vec![],
+        Err(error) => error.to_diagnostics(),
+    }
+}
+
+/// find the module folder, i.e. the ancestor folder which contains the
+/// manifest file, and the source file is in its "src" folder.
+pub fn find_module_directory(source_file_path: &Path) -> Option<PathBuf> {
+    source_file_path
+        .ancestors()
+        .skip(1)
+        .find(|directory| {
+            directory.join(MANIFEST_FILE_NAME).is_file()
+                && source_file_path.starts_with(directory.join(SOURCE_DIRECTORY_NAME))
+        })
+        .map(|directory| directory.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use anc_parser_asm::{diagnostic::Diagnostic, location::Location};
+    use pretty_assertions::assert_eq;
+
+    use super::check_source;
+
+    #[test]
+    fn test_check_source() {
+        assert_eq!(check_source("fn foo() nop()", None), vec![]);
+
+        // the source file which does not belong to a module is assembled
+        // without dependencies.
+        assert_eq!(
+            check_source("fn foo() call(bar)", None),
+            vec![Diagnostic::error("Can not find the function \"bar\".")
+                .with_code("A0003")
+                .with_primary_label(Location::new_range(14, 0, 14, 3), "")]
+        );
+
+        assert_eq!(
+            check_source("fn 文foo@bar() nop()", None),
+            vec![Diagnostic::error("Invalid char '@' for identifier.")
+                .with_code("P0002")
+                .with_primary_label(Location::new_position(7, 0, 7), "")]
+        );
+    }
+
+    #[test]
+    fn test_check_source_in_module() {
+        let module_directory = std::env::temp_dir().join("anc_assembler_test_checker");
+        let _ = std::fs::remove_dir_all(&module_directory);
+        std::fs::create_dir_all(module_directory.join("src")).unwrap();
+
+        std::fs::write(
+            module_directory.join("module.ason"),
+            r#"{
+    name: "hello_world"
+    version: "1.0.0"
+}"#,
+        )
+        .unwrap();
+
+        std::fs::write(
+            module_directory.join("src").join("utils.anca"),
+            "pub fn foo() nop()",
+        )
+        .unwrap();
+
+        // the sibling submodule with syntax errors is skipped.
+        std::fs::write(
+            module_directory.join("src").join("broken.anca"),
+            "pub fn bar(",
+        )
+        .unwrap();
+
+        let source_file_path = module_directory.join("src").join("main.anca");
+        let source_code = "import fn module::utils::*\nfn test() call(foo)";
+
+        assert_eq!(check_source(source_code, Some(&source_file_path)), vec![]);
+
+        // the error of the manifest
+        std::fs::write(module_directory.join("module.ason"), "{").unwrap();
+
+        let diagnostics = check_source(source_code, Some(&source_file_path));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code, Some("A0035".to_owned()));
+    }
+}
@@ -0,0 +1,366 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// convert the assembler errors to diagnostics.
+//
+// the labels are located by the locations which the assembler records
+// on the errors, i.e. the locations of the AST nodes, e.g.
+//
+// - the declarations, e.g. `foo` in `fn foo()`, `data foo:i32` and `... as foo`.
+// - the first arguments of instructions, e.g. `foo` in `call(foo, ...)`,
+//   for the errors of missing functions, data and local variables.
+// - the names of instructions, for the other errors of instructions.
+//
+// the label is omitted if the error does not come from the source file,
+// e.g. the errors of the configuration and the dependency images.
+
+use anc_parser_asm::{diagnostic::Diagnostic, location::Location};
+
+use crate::{manifest::ManifestError, AssembleErrorType, AssemblerError};
+
+impl AssembleErrorType {
+    /// get the stable code of the error, see the file "docs/error-codes.md"
+    /// for the explanations.
+    pub fn get_code(&self) -> &'static str {
+        match self {
+            AssembleErrorType::UnknownInstruction { .. } => "A0001",
+            AssembleErrorType::IncorrectInstructionParameterType { .. } => "A0002",
+            AssembleErrorType::FunctionNotFound(_) => "A0003",
+            AssembleErrorType::DataNotFound(_) => "A0004",
+            AssembleErrorType::LocalVariableNotFound { .. } => "A0005",
+            AssembleErrorType::ExternalFunctionNotFound(_) => "A0006",
+            AssembleErrorType::ExternalDataNotFound(_) => "A0007",
+            AssembleErrorType::ImportModuleNotFound(_) => "A0008",
+            AssembleErrorType::ExternalLibraryNotFound(_) => "A0009",
+            AssembleErrorType::ConflictingImportModule { .. } => "A0010",
+            AssembleErrorType::ConflictingExternalLibrary { .. } => "A0011",
+            AssembleErrorType::UnsupportedDependencySource(_) => "A0012",
+            AssembleErrorType::IncorrectModuleVersion(_) => "A0013",
+            AssembleErrorType::InconsistentModuleVersion(_) => "A0014",
+            AssembleErrorType::IncorrectDependencyImage(_) => "A0015",
+            AssembleErrorType::ImportItemNotFound { .. } => "A0016",
+            AssembleErrorType::ImportItemNotPublic { .. } => "A0017",
+            AssembleErrorType::ImportFunctionSignatureMismatch { .. } => "A0018",
+            AssembleErrorType::ImportDataSectionMismatch { .. } => "A0019",
+            AssembleErrorType::UnresolvedImportItem(_) => "A0020",
+            AssembleErrorType::UnexpandedGlobImport(_) => "A0021",
+            AssembleErrorType::RelativePathAboveModuleRoot { .. } => "A0023",
+            AssembleErrorType::IncompleteControlFlow { .. } => "A0025",
+            AssembleErrorType::DuplicatedLocalVariable { .. } => "A0026",
+            AssembleErrorType::DuplicatedIdentifiers(_) => "A0027",
+            AssembleErrorType::IncorrectDataValueType { .. } => "A0028",
+            AssembleErrorType::IncludeFileNotFound { .. } => "A0029",
+            AssembleErrorType::IncludeFileOutOfRange { .. } => "A0030",
+            AssembleErrorType::IncorrectIncludeFileEncoding { .. } => "A0031",
+            AssembleErrorType::DataTooLarge { .. } => "A0032",
+            AssembleErrorType::IncludeFileReadError { .. } => "A0033",
+            AssembleErrorType::ImportDataTypeMismatch { .. } => "A0034",
+        }
+    }
+}
+
+impl ManifestError {
+    /// get the stable code of the error, see the file "docs/error-codes.md"
+    /// for the explanations.
+    pub fn get_code(&self) -> &'static str {
+        "A0035"
+    }
+}
+
+impl AssemblerError {
+    /// convert the error to the diagnostics, the primary label is the location
+    /// of the error.
+    ///
+    /// the duplicated identifiers produce one diagnostic for each duplicate, the first
+    /// declaration is the secondary label and the redeclaration is the primary label.
+    pub fn to_diagnostics(&self) -> Vec<Diagnostic> {
+        let AssembleErrorType::DuplicatedIdentifiers(duplicated_identifiers) = &self.error_type
+        else {
+            return vec![self.to_diagnostic()];
+        };
+
+        duplicated_identifiers
+            .iter()
+            .map(|duplicated_identifier| {
+                let diagnostic = with_duplicated_labels(
+                    Diagnostic::error(&format!(
+                        "Duplicated identifier \"{}\".",
+                        duplicated_identifier.identifier
+                    )),
+                    duplicated_identifier.first_location,
+                    duplicated_identifier.second_location,
+                    &format!(
+                        "first declared by {}",
+                        duplicated_identifier.first_declaration
+                    ),
+                    &format!("redeclared by {}", duplicated_identifier.second_declaration),
+                );
+
+                let diagnostic = match &duplicated_identifier.alias_suggestion {
+                    Some(declaration) => diagnostic.with_suggestion(&format!(
+                        "add an alias (`as new_name`) to {} to resolve the conflict",
+                        declaration
+                    )),
+                    None => diagnostic,
+                };
+
+                diagnostic.with_code(self.error_type.get_code())
+            })
+            .collect()
+    }
+
+    fn to_diagnostic(&self) -> Diagnostic {
+        let diagnostic = match (&self.error_type, self.location) {
+            (
+                AssembleErrorType::DuplicatedLocalVariable {
+                    variable_name,
+                    function_name,
+                    first_location,
+                },
+                Some(location),
+            ) => with_duplicated_labels(
+                Diagnostic::error(&format!(
+                    "Duplicated local variable \"{}\" in function \"{}\".",
+                    variable_name, function_name
+                )),
+                *first_location,
+                location,
+                "first declared here",
+                "redeclared here",
+            ),
+            (_, Some(location)) => {
+                Diagnostic::error(&self.to_string()).with_primary_label(location, "")
+            }
+            (_, None) => Diagnostic::error(&self.to_string()),
+        };
+
+        diagnostic.with_code(self.error_type.get_code())
+    }
+}
+
+fn with_duplicated_labels(
+    diagnostic: Diagnostic,
+    first_location: Location,
+    second_location: Location,
+    first_message: &str,
+    second_message: &str,
+) -> Diagnostic {
+    diagnostic
+        .with_secondary_label(first_location, first_message)
+        .with_primary_label(second_location, second_message)
+}
+
+#[cfg(test)]
+mod tests {
+    use anc_parser_asm::{
+        diagnostic::{Diagnostic, LabelStyle, RenderOptions},
+        errorcode::explain,
+        parser::parse_from_str,
+    };
+    use pretty_assertions::assert_eq;
+
+    use crate::{assembler::assemble_module_node, AssembleErrorType, AssemblerError};
+
+    fn assemble_and_get_diagnostics(source_code: &str) -> Vec<Diagnostic> {
+        let module_node = parse_from_str(source_code).unwrap();
+        assemble_module_node(&module_node, "mymodule", &[], &[])
+            .unwrap_err()
+            .to_diagnostics()
+    }
+
+    // the styles and char indices of the labels
+    fn get_labels(diagnostic: &Diagnostic) -> Vec<(LabelStyle, usize)> {
+        diagnostic
+            .labels
+            .iter()
+            .map(|label| (label.style, label.location.index))
+            .collect()
+    }
+
+    #[test]
+    fn test_assembler_error_to_diagnostic() {
+        let source_code = "\
+import fn math::add(i32,i32)->i32
+fn add() nop()";
+
+        assert_eq!(
+            assemble_and_get_diagnostics(source_code)[0]
+                .render(source_code, &RenderOptions::default()),
+            "\
+error[A0027]: Duplicated identifier \"add\".
+ --> 2:4
+  |
+1 | import fn math::add(i32,i32)->i32
+  |                 --- first declared by import fn \"math::add\"
+2 | fn add() nop()
+  |    ^^^ redeclared by fn \"add\"
+  |
+  = help: add an alias (`as new_name`) to import fn \"math::add\" to resolve the conflict"
+        );
+
+        let source_code = "fn add() call(foo)";
+
+        assert_eq!(
+            assemble_and_get_diagnostics(source_code)[0]
+                .render(source_code, &RenderOptions::default()),
+            "\
+error[A0003]: Can not find the function \"foo\".
+ --> 1:15
+  |
+1 | fn add() call(foo)
+  |               ^^^"
+        );
+    }
+
+    #[test]
+    fn test_assembler_error_labels_at_nodes() {
+        // the reference `call(add)` between the two declarations is not a label.
+        assert_eq!(
+            get_labels(
+                &assemble_and_get_diagnostics(
+                    "\
+fn add() nop()
+fn test() call(add)
+fn add() nop()"
+                )[0]
+            ),
+            vec![(LabelStyle::Secondary, 3), (LabelStyle::Primary, 38)]
+        );
+
+        // the grouped import and the alias
+        assert_eq!(
+            get_labels(
+                &assemble_and_get_diagnostics(
+                    "\
+import fn math::{add, sub as minus}
+fn test() call(minus)
+fn minus() nop()"
+                )[0]
+            ),
+            vec![(LabelStyle::Secondary, 29), (LabelStyle::Primary, 61)]
+        );
+
+        // the module "math" is not declared.
+        assert_eq!(
+            get_labels(&assemble_and_get_diagnostics("import fn math::{add, sub as minus}")[0]),
+            vec![(LabelStyle::Primary, 17)]
+        );
+
+        // the reference of the missing function
+        assert_eq!(
+            get_labels(&assemble_and_get_diagnostics("data add:i32 = 11\nfn test() call(add)")[0]),
+            vec![(LabelStyle::Primary, 33)]
+        );
+
+        // the data with the incorrect value
+        assert_eq!(
+            get_labels(&assemble_and_get_diagnostics("data foo:i32 = 1.5")[0]),
+            vec![(LabelStyle::Primary, 5)]
+        );
+
+        // the label is omitted if the error does not come from the source file.
+        assert_eq!(
+            get_labels(
+                &AssemblerError::new(AssembleErrorType::IncorrectModuleVersion("1.0".to_owned()))
+                    .to_diagnostics()[0]
+            ),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_assembler_error_labels_of_duplicated_local_variable() {
+        // only the local variables of the function "bar" are duplicated.
+        assert_eq!(
+            get_labels(
+                &assemble_and_get_diagnostics(
+                    "\
+fn foo(left:i32) [right:i32] nop()
+fn bar(left:i32) [left:i32] nop()"
+                )[0]
+            ),
+            vec![(LabelStyle::Secondary, 42), (LabelStyle::Primary, 53)]
+        );
+
+        // the first declaration is in the outer layer.
+        assert_eq!(
+            get_labels(
+                &assemble_and_get_diagnostics(
+                    "\
+fn foo(left:i32) {
+    block(left:i32=imm_i32(0x11)) {
+        nop()
+    }
+}"
+                )[0]
+            ),
+            vec![(LabelStyle::Secondary, 7), (LabelStyle::Primary, 29)]
+        );
+    }
+
+    #[test]
+    fn test_assembler_error_to_multiple_diagnostics() {
+        // the identifier "add" is redeclared twice, and "foo" once.
+        let diagnostics = assemble_and_get_diagnostics(
+            "\
+fn add() nop()
+fn add() nop()
+data foo:i32=1
+fn add() nop()
+data foo:i32=2",
+        );
+
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|diagnostic| (diagnostic.code.clone(), get_labels(diagnostic)))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    Some("A0027".to_owned()),
+                    vec![(LabelStyle::Secondary, 3), (LabelStyle::Primary, 18)]
+                ),
+                (
+                    Some("A0027".to_owned()),
+                    vec![(LabelStyle::Secondary, 3), (LabelStyle::Primary, 48)]
+                ),
+                (
+                    Some("A0027".to_owned()),
+                    vec![(LabelStyle::Secondary, 35), (LabelStyle::Primary, 65)]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_assembler_error_code() {
+        let error_types = [
+            AssembleErrorType::UnknownInstruction {
+                instruction_name: "foo".to_owned(),
+                function_name: "main".to_owned(),
+            },
+            AssembleErrorType::FunctionNotFound("foo".to_owned()),
+            AssembleErrorType::IncorrectIncludeFileEncoding {
+                file_path: "foo.txt".to_owned(),
+                data_name: "foo".to_owned(),
+            },
+        ];
+
+        assert_eq!(
+            error_types
+                .iter()
+                .map(|error_type| error_type.get_code())
+                .collect::<Vec<_>>(),
+            vec!["A0001", "A0003", "A0031"]
+        );
+
+        // all assembler error codes are registered
+        for index in 1..=35 {
+            let code = format!("A{:04}", index);
+            assert!(explain(&code).is_some(), "Unregistered code \"{}\".", code);
+        }
+    }
+}
@@ -0,0 +1,421 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the import header generator.
+//
+// it generates the `import fn ...` and `import data ...` statements of
+// all public functions and data of a module, the consumers of the module
+// can copy the statements to their source files instead of writing
+// them by hand, e.g.
+//
+// ```
+// import fn hello_world::utils::add(i32, i32) -> i32
+// import readonly data hello_world::utils::msg type byte[]
+// ```
+//
+// the statements can be generated from the sources of the module, or from
+// the assembled image common entry. the latter is more accurate since
+// the glob re-exports (i.e. `pub import fn foo::*`) have been expanded
+// and the omitted signatures have been resolved.
+
+use std::path::Path;
+
+use anc_assembly::{
+    ast::{ImportDataNode, ImportFunctionNode, ImportNode, ModuleNode},
+    location::Location,
+    printer::print_to_string,
+};
+use anc_image::{entry::ImageCommonEntry, module_image::Visibility};
+use anc_isa::{DataSectionType, MemoryDataType};
+use anc_parser_asm::NAME_PATH_SEPARATOR;
+
+use crate::{
+    apidoc::DocumentSubmodule,
+    assembler::get_export_full_name,
+    manifest::{load_manifest, parse_submodule_sources, ModuleAssembleError, MANIFEST_FILE_NAME},
+    verifier::get_data_section_type_and_data_type,
+};
+
+/// generate the import statements of the public functions and data
+/// of the specified submodules.
+///
+/// the submodules are sorted by their full names, and the functions are
+/// placed before the data.
+///
+/// note that the glob re-exports (i.e. `pub import fn foo::*`) are not
+/// listed since they can not be expanded without the dependency images,
+/// use `generate_import_header_by_image_common_entry` instead if the module
+/// contains glob re-exports.
+pub fn generate_import_header(submodules: &[DocumentSubmodule]) -> String {
+    let mut sorted_submodules: Vec<&DocumentSubmodule> = submodules.iter().collect();
+    sorted_submodules
+        .sort_by(|left, right| left.submodule_full_name.cmp(right.submodule_full_name));
+
+    let mut import_function_nodes = vec![];
+    let mut import_data_nodes = vec![];
+
+    for submodule in sorted_submodules {
+        let submodule_full_name = submodule.submodule_full_name;
+        let module_node = submodule.module_node;
+
+        for function_node in module_node.functions.iter().filter(|node| node.public) {
+            import_function_nodes.push(ImportNode::Function(ImportFunctionNode {
+                document: function_node.document.clone(),
+                public: false,
+                full_name: get_export_full_name(submodule_full_name, &function_node.name),
+                signature: Some((
+                    function_node
+                        .params
+                        .iter()
+                        .map(|param| param.data_type)
+                        .collect(),
+                    function_node.results.clone(),
+                )),
+                alias_name: None,
+                from: None,
+                location: Location::default(),
+            }));
+        }
+
+        // the re-exported functions are exported with their aliases or identifiers,
+        // the signatures are kept omitted if they are omitted in the sources.
+        for import_node in &module_node.imports {
+            let ImportNode::Function(import_function_node) = import_node else {
+                continue;
+            };
+
+            if !import_function_node.public {
+                continue;
+            }
+
+            let identifier = get_reexport_identifier(
+                &import_function_node.full_name,
+                &import_function_node.alias_name,
+            );
+
+            import_function_nodes.push(ImportNode::Function(ImportFunctionNode {
+                document: import_function_node.document.clone(),
+                public: false,
+                full_name: get_export_full_name(submodule_full_name, identifier),
+                signature: import_function_node.signature.clone(),
+                alias_name: None,
+                from: None,
+                location: Location::default(),
+            }));
+        }
+
+        for data_node in module_node.datas.iter().filter(|node| node.public) {
+            let (data_section_type, data_type) = get_data_section_type_and_data_type(data_node);
+            import_data_nodes.push(ImportNode::Data(ImportDataNode {
+                document: data_node.document.clone(),
+                public: false,
+                data_section_type: Some(data_section_type),
+                full_name: get_export_full_name(submodule_full_name, &data_node.name),
+                data_type: Some(data_type),
+                alias_name: None,
+                from: None,
+                location: Location::default(),
+            }));
+        }
+
+        // the re-exported data are exported with their aliases or identifiers,
+        // the sections and types are kept omitted if they are omitted in the sources.
+        for import_node in &module_node.imports {
+            let ImportNode::Data(import_data_node) = import_node else {
+                continue;
+            };
+
+            if !import_data_node.public {
+                continue;
+            }
+
+            let identifier =
+                get_reexport_identifier(&import_data_node.full_name, &import_data_node.alias_name);
+
+            import_data_nodes.push(ImportNode::Data(ImportDataNode {
+                document: import_data_node.document.clone(),
+                public: false,
+                data_section_type: import_data_node.data_section_type,
+                full_name: get_export_full_name(submodule_full_name, identifier),
+                data_type: import_data_node.data_type,
+                alias_name: None,
+                from: None,
+                location: Location::default(),
+            }));
+        }
+    }
+
+    import_function_nodes.append(&mut import_data_nodes);
+    print_import_nodes(import_function_nodes)
+}
+
+/// generate the import statements of a module project, i.e. all source
+/// files in the "src" folder of the module.
+pub fn generate_import_header_by_manifest(
+    module_directory: &Path,
+) -> Result<String, ModuleAssembleError> {
+    let manifest_file_path = module_directory.join(MANIFEST_FILE_NAME);
+    let manifest = load_manifest(&manifest_file_path).map_err(ModuleAssembleError::Manifest)?;
+    let parsed_submodules = parse_submodule_sources(&manifest.name, module_directory)?;
+
+    let submodules: Vec<DocumentSubmodule> = parsed_submodules
+        .iter()
+        .map(|(submodule_full_name, _, module_node)| DocumentSubmodule {
+            submodule_full_name,
+            module_node,
+        })
+        .collect();
+
+    Ok(generate_import_header(&submodules))
+}
+
+/// generate the import statements of the public exported functions and data
+/// of an assembled (sub)module.
+///
+/// the statements are in the same order as the export entries.
+pub fn generate_import_header_by_image_common_entry(
+    image_common_entry: &ImageCommonEntry,
+) -> String {
+    let mut import_nodes = vec![];
+
+    // the export function entries are in the same order as the internal functions.
+    for (export_function_entry, function_entry) in image_common_entry
+        .export_function_entries
+        .iter()
+        .zip(&image_common_entry.function_entries)
+    {
+        if export_function_entry.visibility != Visibility::Public {
+            continue;
+        }
+
+        let type_entry = &image_common_entry.type_entries[function_entry.type_index];
+        import_nodes.push(ImportNode::Function(ImportFunctionNode {
+            document: None,
+            public: false,
+            full_name: export_function_entry.full_name.to_owned(),
+            signature: Some((type_entry.params.clone(), type_entry.results.clone())),
+            alias_name: None,
+            from: None,
+            location: Location::default(),
+        }));
+    }
+
+    // the re-exported functions take the signatures of the import entries they refer to.
+    for reexport_function_entry in &image_common_entry.reexport_function_entries {
+        if reexport_function_entry.visibility != Visibility::Public {
+            continue;
+        }
+
+        let import_function_entry = &image_common_entry.import_function_entries
+            [reexport_function_entry.import_function_index];
+        let type_entry = &image_common_entry.type_entries[import_function_entry.type_index];
+        import_nodes.push(ImportNode::Function(ImportFunctionNode {
+            document: None,
+            public: false,
+            full_name: reexport_function_entry.full_name.to_owned(),
+            signature: Some((type_entry.params.clone(), type_entry.results.clone())),
+            alias_name: None,
+            from: None,
+            location: Location::default(),
+        }));
+    }
+
+    // the export data entries are sorted by section (read-only, read-write and uninit),
+    // and in the same order as the items of the corresponding data section.
+    let read_only_data_types: Vec<MemoryDataType> = image_common_entry
+        .read_only_data_entries
+        .iter()
+        .map(|entry| entry.memory_data_type)
+        .collect();
+
+    let read_write_data_types: Vec<MemoryDataType> = image_common_entry
+        .read_write_data_entries
+        .iter()
+        .map(|entry| entry.memory_data_type)
+        .collect();
+
+    let uninit_data_types: Vec<MemoryDataType> = image_common_entry
+        .uninit_data_entries
+        .iter()
+        .map(|entry| entry.memory_data_type)
+        .collect();
+
+    let mut section_item_counts = [0usize; 3];
+    for export_data_entry in &image_common_entry.export_data_entries {
+        let (section_index, data_types) = match export_data_entry.section_type {
+            DataSectionType::ReadOnly => (0, &read_only_data_types),
+            DataSectionType::ReadWrite => (1, &read_write_data_types),
+            DataSectionType::Uninit => (2, &uninit_data_types),
+        };
+        let data_type = data_types[section_item_counts[section_index]];
+        section_item_counts[section_index] += 1;
+
+        if export_data_entry.visibility != Visibility::Public {
+            continue;
+        }
+
+        import_nodes.push(ImportNode::Data(ImportDataNode {
+            document: None,
+            public: false,
+            data_section_type: Some(export_data_entry.section_type),
+            full_name: export_data_entry.full_name.to_owned(),
+            data_type: Some(data_type),
+            alias_name: None,
+            from: None,
+            location: Location::default(),
+        }));
+    }
+
+    // the re-exported data take the sections and types of the import entries they refer to.
+    for reexport_data_entry in &image_common_entry.reexport_data_entries {
+        if reexport_data_entry.visibility != Visibility::Public {
+            continue;
+        }
+
+        let import_data_entry =
+            &image_common_entry.import_data_entries[reexport_data_entry.import_data_index];
+        import_nodes.push(ImportNode::Data(ImportDataNode {
+            document: None,
+            public: false,
+            data_section_type: Some(import_data_entry.data_section_type),
+            full_name: reexport_data_entry.full_name.to_owned(),
+            data_type: Some(import_data_entry.memory_data_type),
+            alias_name: None,
+            from: None,
+            location: Location::default(),
+        }));
+    }
+
+    print_import_nodes(import_nodes)
+}
+
+// the identifier which the re-exported item is exported with,
+// i.e. the alias name or the last part of the full name.
+fn get_reexport_identifier<'a>(full_name: &'a str, alias_name: &'a Option<String>) -> &'a str {
+    match alias_name {
+        Some(alias_name) => alias_name,
+        None => full_name
+            .rsplit_once(NAME_PATH_SEPARATOR)
+            .map(|(_, identifier)| identifier)
+            .unwrap_or(full_name),
+    }
+}
+
+fn print_import_nodes(import_nodes: Vec<ImportNode>) -> String {
+    let module_node = ModuleNode {
+        document: None,
+        imports: import_nodes,
+        externals: vec![],
+        datas: vec![],
+        functions: vec![],
+    };
+
+    print_to_string(&module_node)
+}
+
+#[cfg(test)]
+mod tests {
+    use anc_parser_asm::parser::parse_from_str;
+    use pretty_assertions::assert_eq;
+
+    use crate::{apidoc::DocumentSubmodule, assembler::assemble_module_node};
+
+    use super::{generate_import_header, generate_import_header_by_image_common_entry};
+
+    #[test]
+    fn test_generate_import_header() {
+        let utils_node = parse_from_str(
+            r#"
+/// Adds two numbers.
+pub fn add(left:i32, right:i32) -> i32
+    add_i32(local_load_i32_s(left), local_load_i32_s(right))
+
+fn helper() nop()
+
+pub readonly data msg:byte[] = "hello"
+data secret:i32 = 11
+pub uninit data buf:i64
+"#,
+        )
+        .unwrap();
+
+        let app_node = parse_from_str(
+            r#"
+pub import fn module::utils::add(i32, i32) -> i32 as plus
+pub import fn std::memory::copy
+pub import readonly data module::utils::msg type byte[] as greeting
+pub import data std::env::args
+"#,
+        )
+        .unwrap();
+
+        let submodules = [
+            DocumentSubmodule {
+                submodule_full_name: "hello_world::utils",
+                module_node: &utils_node,
+            },
+            DocumentSubmodule {
+                submodule_full_name: "hello_world::app",
+                module_node: &app_node,
+            },
+        ];
+
+        assert_eq!(
+            generate_import_header(&submodules),
+            "\
+import fn hello_world::app::plus(i32, i32) -> i32
+import fn hello_world::app::copy
+/// Adds two numbers.
+import fn hello_world::utils::add(i32, i32) -> i32
+import readonly data hello_world::app::greeting type byte[]
+import data hello_world::app::args
+import readonly data hello_world::utils::msg type byte[]
+import uninit data hello_world::utils::buf type i64
+
+"
+        );
+
+        // the header of the assembled image
+        let image_common_entry =
+            assemble_module_node(&utils_node, "hello_world::utils", &[], &[]).unwrap();
+
+        let header = generate_import_header_by_image_common_entry(&image_common_entry);
+        assert_eq!(
+            header,
+            "\
+import fn hello_world::utils::add(i32, i32) -> i32
+import readonly data hello_world::utils::msg type byte[]
+import uninit data hello_world::utils::buf type i64
+
+"
+        );
+
+        // the header can be parsed
+        assert!(parse_from_str(&header).is_ok());
+
+        // the re-exported items take the signatures of their import entries
+        let reexport_node = parse_from_str(
+            r#"
+pub import fn module::utils::add(i32, i32) -> i32 as plus
+pub import readonly data module::utils::msg type byte[] as greeting
+pub fn inc(value:i32) -> i32 nop()
+"#,
+        )
+        .unwrap();
+        let image_common_entry =
+            assemble_module_node(&reexport_node, "hello_world::app", &[], &[]).unwrap();
+
+        assert_eq!(
+            generate_import_header_by_image_common_entry(&image_common_entry),
+            "\
+import fn hello_world::app::inc(i32) -> i32
+import fn hello_world::app::plus(i32, i32) -> i32
+import readonly data hello_world::app::greeting type byte[]
+
+"
+        );
+    }
+}
@@ -6,7 +6,16 @@
 
 use std::fmt::Display;
 
+use anc_assembly::location::Location;
+
+pub mod apidoc;
 pub mod assembler;
+pub mod binding;
+pub mod checker;
+pub mod diagnostic;
+pub mod header;
+pub mod manifest;
+pub mod verifier;
 
 // https://doc.rust-lang.org/reference/conditional-compilation.html#debug_assertions
 // https://doc.rust-lang.org/reference/conditional-compilation.html#test
@@ -16,6 +25,12 @@ pub mod utils;
 #[derive(Debug)]
 pub struct AssemblerError {
     pub error_type: AssembleErrorType,
+
+    /// the location of the node which the error is about, e.g. the name of
+    /// a declaration, an instruction or an identifier argument.
+    /// it is `None` if the error does not come from the source file,
+    /// e.g. the errors of the configuration and the dependency images.
+    pub location: Option<Location>,
 }
 
 #[derive(Debug)]
@@ -31,6 +46,73 @@ pub enum AssembleErrorType {
     ImportModuleNotFound(String),
     ExternalLibraryNotFound(String),
 
+    /// the import module (or external library) declared in the source file
+    /// has a different dependency from the previous one with the same name,
+    /// which is declared in the configuration or in the same source file.
+    ConflictingImportModule {
+        module_name: String,
+        first_origin: DependencyOrigin,
+        second_origin: DependencyOrigin,
+    },
+    ConflictingExternalLibrary {
+        library_name: String,
+        first_origin: DependencyOrigin,
+        second_origin: DependencyOrigin,
+    },
+
+    /// the source is not available for the dependency, i.e. the 'system' for
+    /// the import module, or the 'runtime' for the external library.
+    UnsupportedDependencySource(String),
+
+    /// the module version is not in the format "major.minor.patch".
+    IncorrectModuleVersion(String),
+
+    /// the submodules of a module have different module names or versions.
+    InconsistentModuleVersion(String),
+
+    /// the errors of verifying the imports against the dependency module images.
+    IncorrectDependencyImage(String),
+    ImportItemNotFound {
+        full_name: String,
+        module_name: String,
+    },
+    ImportItemNotPublic {
+        full_name: String,
+        module_name: String,
+    },
+    ImportFunctionSignatureMismatch {
+        full_name: String,
+        expected: String,
+        actual: String,
+    },
+    ImportDataSectionMismatch {
+        full_name: String,
+        expected: String,
+        actual: String,
+    },
+    ImportDataTypeMismatch {
+        full_name: String,
+        expected: String,
+        actual: String,
+    },
+
+    /// the signature of the imported function (or the type of the imported data)
+    /// is omitted, but it can not be resolved since the image of the
+    /// dependency module is not provided.
+    UnresolvedImportItem(String),
+
+    /// the glob import (e.g. `import fn std::memory::*`) is not expanded since
+    /// neither the image of the dependency module nor the source of the
+    /// sibling submodule is provided.
+    UnexpandedGlobImport(String),
+
+    /// the relative full name (e.g. `super::super::foo`) walks above
+    /// the root of the module.
+    RelativePathAboveModuleRoot {
+        full_name: String,
+        submodule_full_name: String,
+    },
+
     /// the last control flow does not close.
     IncompleteControlFlow {
         control_flow_path: String,
@@ -39,12 +121,27 @@ pub enum AssembleErrorType {
     DuplicatedLocalVariable {
         variable_name: String,
         function_name: String,
+
+        /// the location of the first declaration, the location of
+        /// the error is the redeclaration.
+        first_location: Location,
     },
+
+    /// the declarations in the same namespace (functions or data) have the same
+    /// identifier, all duplicates of the module are reported together.
+    DuplicatedIdentifiers(Vec<DuplicatedIdentifier>),
     IncorrectDataValueType {
         expected: String,
         actual: String,
         data_name: String,
     },
+    /// the length of the data exceeds the limit, e.g. the repetition
+    /// `[0_i64; 0x7fffffff]` expands to about 16 GiB.
+    DataTooLarge {
+        length: usize,
+        max_length: usize,
+        data_name: String,
+    },
     IncorrectInstructionParameterType {
         expected: String,
         actual: String,
@@ -55,11 +152,98 @@ pub enum AssembleErrorType {
         instruction_name: String,
         function_name: String,
     },
+    IncludeFileNotFound {
+        file_path: String,
+        data_name: String,
+    },
+    /// the included file exists but can not be read, e.g. the permission
+    /// is denied or the path is a directory.
+    IncludeFileReadError {
+        file_path: String,
+        message: String,
+        data_name: String,
+    },
+    IncludeFileOutOfRange {
+        file_path: String,
+        offset: usize,
+        length: usize,
+        file_length: usize,
+        data_name: String,
+    },
+    /// the content of `include_str(...)` is not a valid UTF-8 text.
+    IncorrectIncludeFileEncoding {
+        file_path: String,
+        data_name: String,
+    },
+}
+
+/// where an import module or an external library is declared.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum DependencyOrigin {
+    Configuration,
+    SourceFile,
+}
+
+impl Display for DependencyOrigin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DependencyOrigin::Configuration => f.write_str("the configuration"),
+            DependencyOrigin::SourceFile => f.write_str("the source file"),
+        }
+    }
+}
+
+/// two declarations in the same namespace have the same identifier.
+#[derive(Debug)]
+pub struct DuplicatedIdentifier {
+    pub identifier: String,
+    pub first_declaration: String,
+    pub second_declaration: String,
+    pub first_location: Location,
+    pub second_location: Location,
+
+    /// the import or external declaration which can be renamed by `as`.
+    pub alias_suggestion: Option<String>,
+}
+
+impl Display for DuplicatedIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Duplicated identifier \"{}\", it is declared by both {} and {}.",
+            self.identifier, self.first_declaration, self.second_declaration
+        )?;
+        if let Some(declaration) = &self.alias_suggestion {
+            write!(
+                f,
+                " Add an alias (`as new_name`) to {} to resolve the conflict.",
+                declaration
+            )?;
+        }
+        Ok(())
+    }
 }
 
 impl AssemblerError {
     pub fn new(error_type: AssembleErrorType) -> Self {
-        Self { error_type }
+        Self {
+            error_type,
+            location: None,
+        }
+    }
+
+    pub fn with_location(mut self, location: Location) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    /// set the location if the error is not located yet, i.e. the location
+    /// of the inner node takes precedence over the outer node.
+    pub fn with_location_if_absent(self, location: Location) -> Self {
+        match self.location {
+            Some(_) => self,
+            None => self.with_location(location),
+        }
     }
 }
 
@@ -73,19 +257,77 @@ impl Display for AssemblerError {
             AssembleErrorType::LocalVariableNotFound { local_variable_name: variable_name, function_name } => write!(f, "Can not find the local variable \"{variable_name}\" in function \"{function_name}\"."),
             AssembleErrorType::ImportModuleNotFound(module_name) => write!(f, "Can not find the import module \"{module_name}\"."),
             AssembleErrorType::ExternalLibraryNotFound(external_library_name) => write!(f, "Can not find the external library \"{external_library_name}\"."),
+            AssembleErrorType::ConflictingImportModule { module_name, first_origin, second_origin } => write_conflicting_dependency(f,
+                "import module", module_name, *first_origin, *second_origin),
+            AssembleErrorType::ConflictingExternalLibrary { library_name, first_origin, second_origin } => write_conflicting_dependency(f,
+                "external library", library_name, *first_origin, *second_origin),
+            AssembleErrorType::UnsupportedDependencySource(name) => write!(f, "The source of dependency \"{name}\" is not supported, the system library is only available for the external library, and the runtime module is only available for the import module."),
+            AssembleErrorType::IncorrectModuleVersion(version) => write!(f, "Incorrect module version \"{version}\", expected the format \"major.minor.patch\"."),
+            AssembleErrorType::InconsistentModuleVersion(module_name) => write!(f, "The submodules of module \"{module_name}\" have different versions."),
+            AssembleErrorType::IncorrectDependencyImage(module_name) => write!(f, "Can not read the image of the dependency module \"{module_name}\"."),
+            AssembleErrorType::ImportItemNotFound { full_name, module_name } => write!(f,
+                "Can not find the imported item \"{full_name}\" in the module \"{module_name}\"."),
+            AssembleErrorType::ImportItemNotPublic { full_name, module_name } => write!(f,
+                "The imported item \"{full_name}\" is not public in the module \"{module_name}\"."),
+            AssembleErrorType::ImportFunctionSignatureMismatch { full_name, expected, actual } => write!(f,
+                "The signature of the imported function \"{full_name}\" does not match, expected \"{expected}\", actual \"{actual}\"."),
+            AssembleErrorType::ImportDataSectionMismatch { full_name, expected, actual } => write!(f,
+                "The section of the imported data \"{full_name}\" does not match, expected \"{expected}\", actual \"{actual}\"."),
+            AssembleErrorType::ImportDataTypeMismatch { full_name, expected, actual } => write!(f,
+                "The type of the imported data \"{full_name}\" does not match, expected \"{expected}\", actual \"{actual}\"."),
+            AssembleErrorType::UnresolvedImportItem(full_name) => write!(f,
+                "The signature or type of the imported item \"{full_name}\" is omitted, but the image of its module is not provided."),
+            AssembleErrorType::UnexpandedGlobImport(glob) => write!(f,
+                "Can not expand the glob import \"{glob}\", the image or the source of its module is not provided."),
+            AssembleErrorType::RelativePathAboveModuleRoot { full_name, submodule_full_name } => write!(f,
+                "The relative path \"{full_name}\" walks above the root of the module in the submodule \"{submodule_full_name}\"."),
             AssembleErrorType::IncompleteControlFlow { control_flow_path: flow_path, function_name } => write!(f,
                 "Incomplete control flow \"{flow_path}\" in function \"{function_name}\"."),
-            AssembleErrorType::DuplicatedLocalVariable { variable_name, function_name } => write!(f,
+            AssembleErrorType::DuplicatedLocalVariable { variable_name, function_name, .. } => write!(f,
                 "Duplicated local variable \"{variable_name}\" in function \"{function_name}\"."),
+            AssembleErrorType::DuplicatedIdentifiers(duplicated_identifiers) => {
+                let messages: Vec<String> = duplicated_identifiers.iter().map(|item| item.to_string()).collect();
+                f.write_str(&messages.join("\n"))
+            }
             AssembleErrorType::IncorrectDataValueType { expected, actual , data_name} => write!(f,
                 "Incorrect value type for data \"{data_name}\", expected \"{expected}\", actual \"{actual}\"."),
+            AssembleErrorType::DataTooLarge { length, max_length, data_name } => write!(f,
+                "The length {length} of data \"{data_name}\" exceeds the limit {max_length}."),
             AssembleErrorType::IncorrectInstructionParameterType { expected, actual, instruction_name, function_name } => write!(f,
                 "Incorrect parameter for instruction \"{instruction_name}\" in function \"{function_name}\", expected \"{expected}\", actual \"{actual}\"."),
             AssembleErrorType::UnknownInstruction { instruction_name, function_name } => write!(f,
                 "Unknown instruction \"{instruction_name}\" in function \"{function_name}\"."),
+            AssembleErrorType::IncludeFileNotFound { file_path, data_name } => write!(f,
+                "Can not find the file \"{file_path}\" included by data \"{data_name}\"."),
+            AssembleErrorType::IncludeFileReadError { file_path, message, data_name } => write!(f,
+                "Can not read the file \"{file_path}\" included by data \"{data_name}\", {message}."),
+            AssembleErrorType::IncludeFileOutOfRange { file_path, offset, length, file_length, data_name } => write!(f,
+                "The range (offset {offset}, length {length}) is out of the file \"{file_path}\" (length {file_length}) included by data \"{data_name}\"."),
+            AssembleErrorType::IncorrectIncludeFileEncoding { file_path, data_name } => write!(f,
+                "The file \"{file_path}\" included by data \"{data_name}\" is not a valid UTF-8 text."),
 
         }
     }
 }
 
+fn write_conflicting_dependency(
+    f: &mut std::fmt::Formatter<'_>,
+    kind: &str,
+    name: &str,
+    first_origin: DependencyOrigin,
+    second_origin: DependencyOrigin,
+) -> std::fmt::Result {
+    if first_origin == second_origin {
+        write!(
+            f,
+            "The {kind} \"{name}\" is declared more than once in {first_origin} with different dependencies."
+        )
+    } else {
+        write!(
+            f,
+            "The {kind} \"{name}\" declared in {second_origin} conflicts with the one in {first_origin}."
+        )
+    }
+}
+
 impl std::error::Error for AssemblerError {}
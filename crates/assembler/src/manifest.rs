@@ -0,0 +1,916 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the module manifest file (i.e., "module.ason") describes the name, version,
+// dependencies and external libraries of a module, e.g.
+//
+// ```ason
+// {
+//     name: "hello_world"
+//     version: "1.0.0"
+//     dependencies: [
+//         {
+//             name: "std"
+//             source: DependencySource::Runtime
+//         }
+//         {
+//             name: "http_client"
+//             source: DependencySource::Share("1.2.0")
+//         }
+//     ]
+//     libraries: [
+//         {
+//             name: "libfoo"
+//             source: DependencySource::Local("lib/libfoo.so")
+//         }
+//     ]
+//     options: {
+//         deduplicate_read_only_data: true
+//     }
+// }
+// ```
+//
+// the source files are placed in the "src" folder of the module, and each
+// source file is a submodule, see "docs/statements.md" for details.
+
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use anc_assembly::ast::{DependencySource, ImportNode, ModuleNode};
+use anc_image::entry::{ExternalLibraryEntry, ImageCommonEntry, ImportModuleEntry};
+use anc_parser_asm::{parser::parse_from_str, NAME_PATH_SEPARATOR};
+use serde::{de::Error, Deserialize, Deserializer};
+
+use crate::{
+    assembler::{
+        assemble_module_node_with_options,
+        convert_dependency_source_to_external_library_dependency,
+        convert_dependency_source_to_module_dependency, parse_module_version, AssembleOptions,
+        AssembleReport,
+    },
+    verifier::{expand_glob_import_nodes, SiblingSubmodule},
+    AssemblerError,
+};
+
+pub const MANIFEST_FILE_NAME: &str = "module.ason";
+pub const SOURCE_DIRECTORY_NAME: &str = "src";
+pub const SOURCE_FILE_EXTENSIONS: [&str; 2] = ["anca", "ancasm"];
+
+// the top-level source files, their submodule name is empty.
+const TOP_LEVEL_SOURCE_FILE_NAMES: [&str; 2] = ["lib", "main"];
+
+// the version, the duplicated items and the sources of the dependencies
+// are checked by the deserializer, so the errors carry the positions
+// in the manifest in the same way as the syntax errors.
+#[derive(Debug, PartialEq, Deserialize)]
+pub struct ModuleManifest {
+    pub name: String,
+
+    #[serde(deserialize_with = "deserialize_module_version")]
+    pub version: String,
+
+    #[serde(default, deserialize_with = "deserialize_dependency_items")]
+    pub dependencies: Vec<DependencyItem>,
+
+    #[serde(default, deserialize_with = "deserialize_library_items")]
+    pub libraries: Vec<DependencyItem>,
+
+    #[serde(default)]
+    pub options: ManifestOptions,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+pub struct DependencyItem {
+    pub name: String,
+    pub source: DependencySource,
+}
+
+#[derive(Debug, PartialEq, Default, Deserialize)]
+pub struct ManifestOptions {
+    #[serde(default)]
+    pub deduplicate_read_only_data: bool,
+}
+
+#[derive(Debug)]
+pub struct ManifestError {
+    pub file_path: PathBuf,
+
+    // the line number (starts from 1) of the item that causes the error,
+    // it is `None` if the line can not be located.
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl Display for ManifestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "{}:{}: {}", self.file_path.display(), line, self.message)
+        } else {
+            write!(f, "{}: {}", self.file_path.display(), self.message)
+        }
+    }
+}
+
+impl std::error::Error for ManifestError {}
+
+#[derive(Debug)]
+pub enum ModuleAssembleError {
+    Manifest(ManifestError),
+    Io {
+        file_path: PathBuf,
+        message: String,
+    },
+    Parser {
+        file_path: PathBuf,
+
+        // the human readable message with the source snippet.
+        message: String,
+    },
+    Assembler {
+        file_path: PathBuf,
+        error: AssemblerError,
+    },
+}
+
+impl Display for ModuleAssembleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ModuleAssembleError::Manifest(error) => write!(f, "{}", error),
+            ModuleAssembleError::Io { file_path, message } => {
+                write!(f, "{}: {}", file_path.display(), message)
+            }
+            ModuleAssembleError::Parser { file_path, message } => {
+                write!(f, "{}:\n{}", file_path.display(), message)
+            }
+            ModuleAssembleError::Assembler { file_path, error } => {
+                write!(f, "{}: {}", file_path.display(), error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ModuleAssembleError {}
+
+#[derive(Debug)]
+pub struct AssembledSubmodule {
+    pub submodule_full_name: String,
+    pub source_file_path: PathBuf,
+    pub image_common_entry: ImageCommonEntry,
+    pub report: AssembleReport,
+}
+
+/// the `file_path` is used for building error message only.
+pub fn parse_manifest_from_str(
+    text: &str,
+    file_path: &Path,
+) -> Result<ModuleManifest, ManifestError> {
+    ason::from_str(text).map_err(|error| {
+        let (message, line) = match error {
+            ason::Error::Message(message) | ason::Error::UnexpectedEndOfDocument(message) => {
+                (message, None)
+            }
+            // the line index of ASON starts from 0.
+            ason::Error::MessageWithPosition(message, position) => {
+                (message, Some(position.line + 1))
+            }
+            ason::Error::MessageWithRange(message, range) => (message, Some(range.line + 1)),
+        };
+
+        ManifestError {
+            file_path: file_path.to_path_buf(),
+            line,
+            message,
+        }
+    })
+}
+
+pub fn load_manifest(file_path: &Path) -> Result<ModuleManifest, ManifestError> {
+    let text = std::fs::read_to_string(file_path).map_err(|error| ManifestError {
+        file_path: file_path.to_path_buf(),
+        line: None,
+        message: error.to_string(),
+    })?;
+
+    parse_manifest_from_str(&text, file_path)
+}
+
+fn deserialize_module_version<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let version = String::deserialize(deserializer)?;
+    parse_module_version(&version).map_err(D::Error::custom)?;
+    Ok(version)
+}
+
+fn deserialize_dependency_items<'de, D>(deserializer: D) -> Result<Vec<DependencyItem>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let items = Vec::<DependencyItem>::deserialize(deserializer)?;
+    check_duplicated_items(&items, "dependency").map_err(D::Error::custom)?;
+
+    for item in &items {
+        convert_dependency_source_to_module_dependency(&item.name, &item.source)
+            .map_err(D::Error::custom)?;
+    }
+
+    Ok(items)
+}
+
+fn deserialize_library_items<'de, D>(deserializer: D) -> Result<Vec<DependencyItem>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let items = Vec::<DependencyItem>::deserialize(deserializer)?;
+    check_duplicated_items(&items, "library").map_err(D::Error::custom)?;
+
+    for item in &items {
+        convert_dependency_source_to_external_library_dependency(&item.name, &item.source)
+            .map_err(D::Error::custom)?;
+    }
+
+    Ok(items)
+}
+
+fn check_duplicated_items(items: &[DependencyItem], kind: &str) -> Result<(), String> {
+    match items
+        .iter()
+        .enumerate()
+        .find(|(idx, item)| items[..*idx].iter().any(|other| other.name == item.name))
+    {
+        Some((_, item)) => Err(format!("Duplicated {} \"{}\".", kind, item.name)),
+        None => Ok(()),
+    }
+}
+
+impl ModuleManifest {
+    pub fn get_import_module_entries(&self) -> Vec<ImportModuleEntry> {
+        self.dependencies
+            .iter()
+            .map(|item| {
+                let module_dependency =
+                    convert_dependency_source_to_module_dependency(&item.name, &item.source)
+                        .expect("The dependency sources are checked when parsing the manifest.");
+
+                ImportModuleEntry {
+                    name: item.name.to_owned(),
+                    module_dependency: Box::new(module_dependency),
+                }
+            })
+            .collect()
+    }
+
+    pub fn get_external_library_entries(&self) -> Vec<ExternalLibraryEntry> {
+        self.libraries
+            .iter()
+            .map(|item| {
+                let external_library_dependency =
+                    convert_dependency_source_to_external_library_dependency(
+                        &item.name,
+                        &item.source,
+                    )
+                    .expect("The library sources are checked when parsing the manifest.");
+
+                ExternalLibraryEntry::new(
+                    item.name.to_owned(),
+                    Box::new(external_library_dependency),
+                )
+            })
+            .collect()
+    }
+}
+
+/// load the manifest file "module.ason" in the module folder, and assemble
+/// all source files in the "src" folder.
+///
+/// the glob imports of the current module (e.g. `import fn module::utils::*`)
+/// are expanded by the sources of the sibling submodules.
+///
+/// the submodules are sorted by their full names.
+pub fn assemble_module_by_manifest(
+    module_directory: &Path,
+) -> Result<Vec<AssembledSubmodule>, ModuleAssembleError> {
+    let manifest = load_manifest(&module_directory.join(MANIFEST_FILE_NAME))
+        .map_err(ModuleAssembleError::Manifest)?;
+
+    let import_module_entries = manifest.get_import_module_entries();
+    let external_library_entries = manifest.get_external_library_entries();
+
+    // parse all source files first, since the glob imports may
+    // refer to the sibling submodules.
+    let parsed_submodules = parse_submodule_sources(&manifest.name, module_directory)?;
+
+    let sibling_submodules: Vec<SiblingSubmodule> = parsed_submodules
+        .iter()
+        .map(|(submodule_full_name, _, module_node)| SiblingSubmodule {
+            submodule_full_name,
+            module_node,
+        })
+        .collect();
+
+    let expanded_import_nodes_list = parsed_submodules
+        .iter()
+        .map(|(submodule_full_name, source_file_path, module_node)| {
+            expand_glob_import_nodes(module_node, submodule_full_name, &[], &sibling_submodules)
+                .map_err(|error| ModuleAssembleError::Assembler {
+                    file_path: source_file_path.clone(),
+                    error,
+                })
+        })
+        .collect::<Result<Vec<Vec<ImportNode>>, ModuleAssembleError>>()?;
+
+    let mut submodules: Vec<AssembledSubmodule> = vec![];
+
+    for ((submodule_full_name, source_file_path, mut module_node), import_nodes) in
+        parsed_submodules
+            .into_iter()
+            .zip(expanded_import_nodes_list)
+    {
+        module_node.imports = import_nodes;
+
+        let options = AssembleOptions {
+            deduplicate_read_only_data: manifest.options.deduplicate_read_only_data,
+            source_file_path: Some(source_file_path.clone()),
+            module_version: Some(manifest.version.clone()),
+        };
+
+        let (image_common_entry, report) = assemble_module_node_with_options(
+            &module_node,
+            &submodule_full_name,
+            &import_module_entries,
+            &external_library_entries,
+            &options,
+        )
+        .map_err(|error| ModuleAssembleError::Assembler {
+            file_path: source_file_path.clone(),
+            error,
+        })?;
+
+        submodules.push(AssembledSubmodule {
+            submodule_full_name,
+            source_file_path,
+            image_common_entry,
+            report,
+        });
+    }
+
+    // all submodules are assembled with the version of the manifest, so
+    // they are consistent and there is no need to check them.
+    submodules.sort_by(|left, right| left.submodule_full_name.cmp(&right.submodule_full_name));
+
+    Ok(submodules)
+}
+
+/// parse all source files in the "src" folder of a module.
+///
+/// returns `(submodule_full_name, source_file_path, module_node)` for each source file.
+pub fn parse_submodule_sources(
+    module_name: &str,
+    module_directory: &Path,
+) -> Result<Vec<(String, PathBuf, ModuleNode)>, ModuleAssembleError> {
+    let mut source_file_paths: Vec<PathBuf> = vec![];
+    let source_directory = module_directory.join(SOURCE_DIRECTORY_NAME);
+    collect_source_file_paths(&source_directory, &mut source_file_paths)?;
+
+    check_submodule_full_names(
+        module_name,
+        module_directory,
+        &source_directory,
+        &source_file_paths,
+    )
+    .map_err(ModuleAssembleError::Manifest)?;
+
+    source_file_paths
+        .into_iter()
+        .map(|source_file_path| {
+            parse_submodule_source(module_name, &source_directory, source_file_path)
+        })
+        .collect()
+}
+
+/// parse the source files in the "src" folder of a module, the files which
+/// can not be read or parsed are skipped.
+///
+/// it is used for checking a single source file, where the errors of the
+/// other source files should not be reported.
+pub fn parse_valid_submodule_sources(
+    module_name: &str,
+    module_directory: &Path,
+) -> Vec<(String, PathBuf, ModuleNode)> {
+    let mut source_file_paths: Vec<PathBuf> = vec![];
+    let source_directory = module_directory.join(SOURCE_DIRECTORY_NAME);
+
+    // the paths collected before the IO error are kept.
+    let _ = collect_source_file_paths(&source_directory, &mut source_file_paths);
+
+    source_file_paths
+        .into_iter()
+        .filter_map(|source_file_path| {
+            parse_submodule_source(module_name, &source_directory, source_file_path).ok()
+        })
+        .collect()
+}
+
+/// different source files may have the same submodule full name, e.g.
+/// "src/lib.anca" and "src/main.anca", or "src/one.anca" and "src/one.ancasm".
+fn check_submodule_full_names(
+    module_name: &str,
+    module_directory: &Path,
+    source_directory: &Path,
+    source_file_paths: &[PathBuf],
+) -> Result<(), ManifestError> {
+    let mut sorted_source_file_paths: Vec<&PathBuf> = source_file_paths.iter().collect();
+    sorted_source_file_paths.sort();
+
+    let submodule_full_names: Vec<String> = sorted_source_file_paths
+        .iter()
+        .map(|source_file_path| {
+            get_submodule_full_name(module_name, source_directory, source_file_path)
+        })
+        .collect();
+
+    for (idx, submodule_full_name) in submodule_full_names.iter().enumerate() {
+        if let Some(first_idx) = submodule_full_names[..idx]
+            .iter()
+            .position(|name| name == submodule_full_name)
+        {
+            return Err(ManifestError {
+                file_path: module_directory.join(MANIFEST_FILE_NAME),
+                line: None,
+                message: format!(
+                    "The source files \"{}\" and \"{}\" have the same submodule name \"{}\".",
+                    sorted_source_file_paths[first_idx].display(),
+                    sorted_source_file_paths[idx].display(),
+                    submodule_full_name
+                ),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_submodule_source(
+    module_name: &str,
+    source_directory: &Path,
+    source_file_path: PathBuf,
+) -> Result<(String, PathBuf, ModuleNode), ModuleAssembleError> {
+    let submodule_full_name =
+        get_submodule_full_name(module_name, source_directory, &source_file_path);
+
+    let source_code =
+        std::fs::read_to_string(&source_file_path).map_err(|error| ModuleAssembleError::Io {
+            file_path: source_file_path.clone(),
+            message: error.to_string(),
+        })?;
+
+    let module_node =
+        parse_from_str(&source_code).map_err(|error| ModuleAssembleError::Parser {
+            file_path: source_file_path.clone(),
+            message: error.with_source(&source_code),
+        })?;
+
+    Ok((submodule_full_name, source_file_path, module_node))
+}
+
+fn collect_source_file_paths(
+    directory: &Path,
+    source_file_paths: &mut Vec<PathBuf>,
+) -> Result<(), ModuleAssembleError> {
+    let to_io_error = |error: std::io::Error| ModuleAssembleError::Io {
+        file_path: directory.to_path_buf(),
+        message: error.to_string(),
+    };
+
+    for dir_entry in std::fs::read_dir(directory).map_err(to_io_error)? {
+        let path = dir_entry.map_err(to_io_error)?.path();
+
+        if path.is_dir() {
+            collect_source_file_paths(&path, source_file_paths)?;
+        } else if path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| SOURCE_FILE_EXTENSIONS.contains(&extension))
+        {
+            source_file_paths.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+/// e.g.
+///
+/// - "./src/lib.anca" -> "hello_world"
+/// - "./src/one.anca" -> "hello_world::one"
+/// - "./src/utils/foo.anca" -> "hello_world::utils::foo"
+pub fn get_submodule_full_name(
+    module_name: &str,
+    source_directory: &Path,
+    source_file_path: &Path,
+) -> String {
+    let relative_path = source_file_path
+        .strip_prefix(source_directory)
+        .unwrap()
+        .with_extension("");
+
+    let names: Vec<String> = relative_path
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy().to_string())
+        .collect();
+
+    if names.len() == 1 && TOP_LEVEL_SOURCE_FILE_NAMES.contains(&names[0].as_str()) {
+        module_name.to_owned()
+    } else {
+        let mut full_name = module_name.to_owned();
+        for name in names {
+            full_name.push_str(NAME_PATH_SEPARATOR);
+            full_name.push_str(&name);
+        }
+        full_name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, path::Path};
+
+    use anc_assembly::ast::DependencySource;
+    use anc_image::{
+        entry::{ExportFunctionEntry, ExternalLibraryEntry, ImportModuleEntry},
+        module_image::Visibility,
+    };
+    use anc_isa::{
+        DependencyCondition, DependencyLocal, DependencyShare, EffectiveVersion,
+        ExternalLibraryDependency, ModuleDependency,
+    };
+    use pretty_assertions::assert_eq;
+
+    use super::{
+        assemble_module_by_manifest, get_submodule_full_name, parse_manifest_from_str,
+        DependencyItem, ManifestOptions, ModuleAssembleError, ModuleManifest,
+    };
+
+    #[test]
+    fn test_parse_manifest() {
+        let manifest = parse_manifest_from_str(
+            r#"{
+    name: "hello_world"
+    version: "1.0.0"
+    dependencies: [
+        {
+            name: "std"
+            source: DependencySource::Runtime
+        }
+        {
+            name: "http_client"
+            source: DependencySource::Share("1.2.0")
+        }
+    ]
+    libraries: [
+        {
+            name: "libfoo"
+            source: DependencySource::Local("lib/libfoo.so")
+        }
+        {
+            name: "libc"
+            source: DependencySource::System("libc.so.6")
+        }
+    ]
+    options: {
+        deduplicate_read_only_data: true
+    }
+}"#,
+            Path::new("module.ason"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            manifest,
+            ModuleManifest {
+                name: "hello_world".to_owned(),
+                version: "1.0.0".to_owned(),
+                dependencies: vec![
+                    DependencyItem {
+                        name: "std".to_owned(),
+                        source: DependencySource::Runtime
+                    },
+                    DependencyItem {
+                        name: "http_client".to_owned(),
+                        source: DependencySource::Version("1.2.0".to_owned())
+                    }
+                ],
+                libraries: vec![
+                    DependencyItem {
+                        name: "libfoo".to_owned(),
+                        source: DependencySource::Local("lib/libfoo.so".to_owned())
+                    },
+                    DependencyItem {
+                        name: "libc".to_owned(),
+                        source: DependencySource::System("libc.so.6".to_owned())
+                    }
+                ],
+                options: ManifestOptions {
+                    deduplicate_read_only_data: true
+                }
+            }
+        );
+
+        assert_eq!(
+            manifest.get_import_module_entries(),
+            vec![
+                ImportModuleEntry {
+                    name: "std".to_owned(),
+                    module_dependency: Box::new(ModuleDependency::Runtime)
+                },
+                ImportModuleEntry {
+                    name: "http_client".to_owned(),
+                    module_dependency: Box::new(ModuleDependency::Share(Box::new(
+                        DependencyShare {
+                            version: "1.2.0".to_owned(),
+                            condition: DependencyCondition::True,
+                            parameters: HashMap::default(),
+                        }
+                    )))
+                }
+            ]
+        );
+
+        assert_eq!(
+            manifest.get_external_library_entries(),
+            vec![
+                ExternalLibraryEntry::new(
+                    "libfoo".to_owned(),
+                    Box::new(ExternalLibraryDependency::Local(Box::new(
+                        DependencyLocal {
+                            path: "lib/libfoo.so".to_owned(),
+                            condition: DependencyCondition::True,
+                            parameters: HashMap::default(),
+                        }
+                    )))
+                ),
+                ExternalLibraryEntry::new(
+                    "libc".to_owned(),
+                    Box::new(ExternalLibraryDependency::System("libc.so.6".to_owned()))
+                )
+            ]
+        );
+
+        // the optional fields
+        assert_eq!(
+            parse_manifest_from_str(
+                r#"{
+    name: "hello_world"
+    version: "1.0.0"
+}"#,
+                Path::new("module.ason"),
+            )
+            .unwrap(),
+            ModuleManifest {
+                name: "hello_world".to_owned(),
+                version: "1.0.0".to_owned(),
+                dependencies: vec![],
+                libraries: vec![],
+                options: ManifestOptions::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_manifest_error() {
+        // duplicated dependencies
+        let error = parse_manifest_from_str(
+            r#"{
+    name: "hello_world"
+    version: "1.0.0"
+    dependencies: [
+        {
+            name: "std"
+            source: DependencySource::Runtime
+        }
+        {
+            name: "std"
+            source: DependencySource::Share("1.0.0")
+        }
+    ]
+}"#,
+            Path::new("/path/to/module.ason"),
+        )
+        .unwrap_err();
+
+        assert!(error.message.contains("Duplicated dependency \"std\"."));
+        assert!(error.to_string().starts_with("/path/to/module.ason:"));
+
+        // runtime library
+        let error = parse_manifest_from_str(
+            r#"{
+    name: "hello_world"
+    version: "1.0.0"
+    libraries: [
+        {
+            name: "libfoo"
+            source: DependencySource::Runtime
+        }
+    ]
+}"#,
+            Path::new("module.ason"),
+        )
+        .unwrap_err();
+
+        assert!(error
+            .message
+            .contains("The source of dependency \"libfoo\" is not supported"));
+
+        // system module
+        let error = parse_manifest_from_str(
+            r#"{
+    name: "hello_world"
+    version: "1.0.0"
+    dependencies: [
+        {
+            name: "libc"
+            source: DependencySource::System("libc.so.6")
+        }
+    ]
+}"#,
+            Path::new("module.ason"),
+        )
+        .unwrap_err();
+
+        assert!(error
+            .message
+            .contains("The source of dependency \"libc\" is not supported"));
+
+        // incorrect version
+        let error = parse_manifest_from_str(
+            r#"{
+    name: "hello_world"
+    version: "1.0"
+}"#,
+            Path::new("module.ason"),
+        )
+        .unwrap_err();
+
+        assert!(error.message.contains("Incorrect module version \"1.0\""));
+
+        // syntax error, the line is taken from the ASON error.
+        let error = parse_manifest_from_str(
+            r#"{
+    name: "hello_world"
+    version:
+}"#,
+            Path::new("module.ason"),
+        )
+        .unwrap_err();
+
+        assert_eq!(error.line, Some(4));
+    }
+
+    #[test]
+    fn test_get_submodule_full_name() {
+        let source_directory = Path::new("/path/to/hello_world/src");
+
+        assert_eq!(
+            get_submodule_full_name(
+                "hello_world",
+                source_directory,
+                &source_directory.join("lib.anca")
+            ),
+            "hello_world"
+        );
+
+        assert_eq!(
+            get_submodule_full_name(
+                "hello_world",
+                source_directory,
+                &source_directory.join("main.anca")
+            ),
+            "hello_world"
+        );
+
+        assert_eq!(
+            get_submodule_full_name(
+                "hello_world",
+                source_directory,
+                &source_directory.join("one.anca")
+            ),
+            "hello_world::one"
+        );
+
+        assert_eq!(
+            get_submodule_full_name(
+                "hello_world",
+                source_directory,
+                &source_directory
+                    .join("utils")
+                    .join("codegen")
+                    .join("bar.anca")
+            ),
+            "hello_world::utils::codegen::bar"
+        );
+    }
+
+    #[test]
+    fn test_assemble_module_by_manifest() {
+        let module_directory = std::env::temp_dir().join("anc_assembler_test_manifest");
+        let _ = std::fs::remove_dir_all(&module_directory);
+        std::fs::create_dir_all(module_directory.join("src").join("utils")).unwrap();
+
+        std::fs::write(
+            module_directory.join("module.ason"),
+            r#"{
+    name: "hello_world"
+    version: "1.0.0"
+}"#,
+        )
+        .unwrap();
+
+        std::fs::write(
+            module_directory.join("src").join("lib.anca"),
+            "pub fn foo() nop()",
+        )
+        .unwrap();
+
+        std::fs::write(
+            module_directory.join("src").join("utils").join("bar.anca"),
+            "import fn module::foo()\npub fn bar() call(foo)",
+        )
+        .unwrap();
+
+        let submodules = assemble_module_by_manifest(&module_directory).unwrap();
+
+        assert_eq!(
+            submodules
+                .iter()
+                .map(|item| item.submodule_full_name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["hello_world", "hello_world::utils::bar"]
+        );
+
+        assert!(submodules
+            .iter()
+            .all(|item| item.image_common_entry.version == EffectiveVersion::new(1, 0, 0)));
+
+        assert_eq!(
+            submodules[1].image_common_entry.export_function_entries,
+            vec![ExportFunctionEntry::new(
+                "hello_world::utils::bar::bar".to_owned(),
+                Visibility::Public
+            )]
+        );
+
+        // the glob import is expanded by the sibling submodule
+        std::fs::write(
+            module_directory.join("src").join("utils").join("bar.anca"),
+            "import fn module::*\npub fn bar() call(foo)",
+        )
+        .unwrap();
+
+        let submodules = assemble_module_by_manifest(&module_directory).unwrap();
+        assert_eq!(
+            submodules[1]
+                .image_common_entry
+                .import_function_entries
+                .len(),
+            1
+        );
+
+        // the error of source file contains the file path
+        std::fs::write(
+            module_directory.join("src").join("utils").join("bar.anca"),
+            "pub fn bar() call(baz)",
+        )
+        .unwrap();
+
+        assert!(matches!(
+            assemble_module_by_manifest(&module_directory),
+            Err(ModuleAssembleError::Assembler { file_path, .. })
+                if file_path.ends_with("utils/bar.anca")
+        ));
+
+        // the top-level source files "lib.anca" and "main.anca" have the same submodule name
+        std::fs::write(
+            module_directory.join("src").join("utils").join("bar.anca"),
+            "pub fn bar() nop()",
+        )
+        .unwrap();
+
+        std::fs::write(
+            module_directory.join("src").join("main.anca"),
+            "pub fn main() nop()",
+        )
+        .unwrap();
+
+        assert!(matches!(
+            assemble_module_by_manifest(&module_directory),
+            Err(ModuleAssembleError::Manifest(error))
+                if error.message.ends_with("have the same submodule name \"hello_world\".")
+        ));
+    }
+}
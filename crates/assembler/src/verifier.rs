@@ -0,0 +1,1241 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// verify the import statements against the images of the dependency modules,
+// so that the mismatches are reported at assembling time instead of
+// linking or loading time.
+//
+// the omitted signatures of imported functions and the omitted types of
+// imported data are also resolved from the images, and the glob imports
+// are expanded by the images or the sources of the sibling submodules.
+
+use anc_assembly::ast::{
+    DataNode, DataSection, DeclareDataType, FixedDeclareDataType, ImportDataNode,
+    ImportFunctionNode, ImportGlobNode, ImportItemType, ImportNode, ModuleNode,
+};
+use anc_image::{
+    entry::{ExportDataEntry, ExportFunctionEntry, TypeEntry},
+    module_image::{ModuleImage, Visibility},
+};
+use anc_isa::{DataSectionType, MemoryDataType, OperandDataType, SELF_REFERENCE_MODULE_NAME};
+use anc_parser_asm::NAME_PATH_SEPARATOR;
+
+use crate::{
+    assembler::{
+        check_duplicated_declarations, collect_declarations, resolve_relative_full_name,
+        Declaration,
+    },
+    AssembleErrorType, AssemblerError,
+};
+
+/// the image of a dependency module.
+pub struct DependencyImage<'a> {
+    pub module_name: &'a str,
+
+    // the binary of the module image, i.e. the object file or
+    // the shared module file.
+    pub image_binary: &'a [u8],
+}
+
+/// the source of another submodule in the current module.
+pub struct SiblingSubmodule<'a> {
+    pub submodule_full_name: &'a str,
+    pub module_node: &'a ModuleNode,
+}
+
+// the exported items of a dependency module, and the signatures
+// of the exported functions.
+//
+// the re-exported items (i.e. `pub import ...`) are appended after the
+// internal items, with the signatures of the import entries they refer to.
+struct DependencyExports {
+    module_name: String,
+    export_function_entries: Vec<ExportFunctionEntry>,
+    export_function_types: Vec<TypeEntry>,
+    export_data_entries: Vec<ExportDataEntry>,
+    export_data_types: Vec<MemoryDataType>,
+}
+
+/// check each `import fn ...` and `import data ...` statement against the
+/// export entries of the dependency module images.
+///
+/// the following items are checked:
+///
+/// - the imported function or data exists.
+/// - the imported item is public (i.e., declared with `pub`).
+/// - the signature of the imported function matches, if it is not omitted.
+/// - the section (read-only, read-write or uninit) and the type of the imported data
+///   match, if they are not omitted.
+///
+/// the imports from the current module (i.e., the module name is "module" or the
+/// name of the current module), and from the modules whose images are not provided,
+/// are skipped.
+pub fn verify_import_nodes(
+    module_node: &ModuleNode,
+    submodule_full_name: &str,
+    dependency_images: &[DependencyImage],
+) -> Result<(), AssemblerError> {
+    let (module_name, _) = submodule_full_name
+        .split_once(NAME_PATH_SEPARATOR)
+        .unwrap_or((submodule_full_name, ""));
+
+    let dependency_exports_list = read_dependency_exports_list(dependency_images)?;
+
+    for import_node in &module_node.imports {
+        match import_node {
+            ImportNode::Function(import_function_node) => {
+                if let Some(dependency_exports) = find_dependency_exports(
+                    &dependency_exports_list,
+                    module_name,
+                    &import_function_node.full_name,
+                    &import_function_node.from,
+                ) {
+                    verify_import_function_node(import_function_node, dependency_exports)
+                        .map_err(|error| error.with_location(import_function_node.location))?;
+                }
+            }
+            ImportNode::Data(import_data_node) => {
+                if let Some(dependency_exports) = find_dependency_exports(
+                    &dependency_exports_list,
+                    module_name,
+                    &import_data_node.full_name,
+                    &import_data_node.from,
+                ) {
+                    verify_import_data_node(import_data_node, dependency_exports)
+                        .map_err(|error| error.with_location(import_data_node.location))?;
+                }
+            }
+            ImportNode::Module(_) => {
+                // nothing to verify
+            }
+            ImportNode::Glob(_) => {
+                // the items are verified when the glob import is expanded.
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// fill the omitted signatures of `import fn ...` statements and the omitted
+/// types of `import data ...` statements by the export entries of the
+/// dependency module images, e.g.
+///
+/// - `import fn std::memory::copy` -> `import fn std::memory::copy(i64, i64, i64) -> ()`
+/// - `import data foo::bar` -> `import readonly data foo::bar type byte[]`
+///
+/// note that the data section type of the imported data is also taken from
+/// the dependency module if it is omitted, and the declared section (e.g.
+/// `import readonly data foo::bar`) must match the exported data.
+pub fn resolve_import_nodes(
+    module_node: &mut ModuleNode,
+    submodule_full_name: &str,
+    dependency_images: &[DependencyImage],
+) -> Result<(), AssemblerError> {
+    let (module_name, _) = submodule_full_name
+        .split_once(NAME_PATH_SEPARATOR)
+        .unwrap_or((submodule_full_name, ""));
+
+    let dependency_exports_list = read_dependency_exports_list(dependency_images)?;
+
+    for import_node in &mut module_node.imports {
+        match import_node {
+            ImportNode::Function(import_function_node) => {
+                if import_function_node.signature.is_some() {
+                    continue;
+                }
+
+                let dependency_exports = find_dependency_exports(
+                    &dependency_exports_list,
+                    module_name,
+                    &import_function_node.full_name,
+                    &import_function_node.from,
+                )
+                .ok_or_else(|| {
+                    AssemblerError::new(AssembleErrorType::UnresolvedImportItem(
+                        import_function_node.full_name.to_owned(),
+                    ))
+                    .with_location(import_function_node.location)
+                })?;
+
+                let export_index =
+                    find_export_function_index(&import_function_node.full_name, dependency_exports)
+                        .map_err(|error| error.with_location(import_function_node.location))?;
+                let type_entry = &dependency_exports.export_function_types[export_index];
+
+                import_function_node.signature =
+                    Some((type_entry.params.clone(), type_entry.results.clone()));
+            }
+            ImportNode::Data(import_data_node) => {
+                if import_data_node.data_section_type.is_some()
+                    && import_data_node.data_type.is_some()
+                {
+                    continue;
+                }
+
+                let dependency_exports = find_dependency_exports(
+                    &dependency_exports_list,
+                    module_name,
+                    &import_data_node.full_name,
+                    &import_data_node.from,
+                )
+                .ok_or_else(|| {
+                    AssemblerError::new(AssembleErrorType::UnresolvedImportItem(
+                        import_data_node.full_name.to_owned(),
+                    ))
+                    .with_location(import_data_node.location)
+                })?;
+
+                let export_index =
+                    find_export_data_index(&import_data_node.full_name, dependency_exports)
+                        .map_err(|error| error.with_location(import_data_node.location))?;
+
+                let section_type =
+                    dependency_exports.export_data_entries[export_index].section_type;
+                verify_import_data_section_type(import_data_node, section_type)
+                    .map_err(|error| error.with_location(import_data_node.location))?;
+
+                import_data_node.data_section_type = Some(section_type);
+                import_data_node.data_type =
+                    Some(dependency_exports.export_data_types[export_index]);
+            }
+            ImportNode::Module(_) | ImportNode::Glob(_) => {
+                // nothing to resolve
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// expand the glob imports to the individual `import fn ...` and `import data ...`
+/// statements with signatures filled in, e.g.
+///
+/// `import fn std::memory::*` -> `import fn std::memory::copy(i64, i64, i64) -> ()`, ...
+///
+/// - the glob imports from the current module (i.e., the module name is "module" or
+///   the name of the current module) are expanded by the sources of the sibling submodules.
+/// - the others are expanded by the images of the dependency modules.
+///
+/// only the public items which are directly in the specified (sub)module are imported,
+/// and the expanded identifiers must not conflict with the other declarations.
+///
+/// returns the new list of import nodes, in which the glob imports are replaced
+/// by the expanded nodes.
+pub fn expand_glob_import_nodes(
+    module_node: &ModuleNode,
+    submodule_full_name: &str,
+    dependency_images: &[DependencyImage],
+    sibling_submodules: &[SiblingSubmodule],
+) -> Result<Vec<ImportNode>, AssemblerError> {
+    let (module_name, _) = submodule_full_name
+        .split_once(NAME_PATH_SEPARATOR)
+        .unwrap_or((submodule_full_name, ""));
+
+    let dependency_exports_list = read_dependency_exports_list(dependency_images)?;
+    let (mut function_declarations, mut data_declarations) = collect_declarations(module_node);
+
+    let mut import_nodes: Vec<ImportNode> = vec![];
+
+    for import_node in &module_node.imports {
+        let ImportNode::Glob(import_glob_node) = import_node else {
+            import_nodes.push(import_node.clone());
+            continue;
+        };
+
+        let glob = format!("{}::*", import_glob_node.module_path);
+        let unexpanded_error = || {
+            AssemblerError::new(AssembleErrorType::UnexpandedGlobImport(glob.clone()))
+                .with_location(import_glob_node.location)
+        };
+
+        // convert "self::..." and "super::..." to "module::...".
+        let module_path =
+            resolve_relative_full_name(&import_glob_node.module_path, submodule_full_name)
+                .map_err(|error| error.with_location(import_glob_node.location))?;
+
+        let (declare_module_name, namespace) = module_path
+            .split_once(NAME_PATH_SEPARATOR)
+            .unwrap_or((&module_path, ""));
+
+        // contains "from ..."
+        let actual_module_name = match &import_glob_node.from {
+            Some(from) => from.as_str(),
+            None => declare_module_name,
+        };
+
+        let expanded_nodes = if actual_module_name == SELF_REFERENCE_MODULE_NAME
+            || actual_module_name == module_name
+        {
+            let expected_submodule_full_name = if namespace.is_empty() {
+                module_name.to_owned()
+            } else {
+                format!("{}::{}", module_name, namespace)
+            };
+
+            let sibling_submodule = sibling_submodules
+                .iter()
+                .find(|item| item.submodule_full_name == expected_submodule_full_name)
+                .ok_or_else(unexpanded_error)?;
+
+            list_sibling_submodule_items(import_glob_node, sibling_submodule.module_node)
+        } else {
+            let dependency_exports = dependency_exports_list
+                .iter()
+                .find(|item| item.module_name == actual_module_name)
+                .ok_or_else(unexpanded_error)?;
+
+            list_dependency_module_items(import_glob_node, dependency_exports)
+        };
+
+        for expanded_node in &expanded_nodes {
+            let (declarations, item_type, full_name) = match expanded_node {
+                ImportNode::Function(node) => (&mut function_declarations, "fn", &node.full_name),
+                ImportNode::Data(node) => (&mut data_declarations, "data", &node.full_name),
+                _ => unreachable!(),
+            };
+
+            let (_, identifier) = full_name.rsplit_once(NAME_PATH_SEPARATOR).unwrap();
+
+            declarations.push(Declaration {
+                identifier: identifier.to_owned(),
+                description: format!("import {} \"{}\" (by \"{}\")", item_type, full_name, glob),
+                aliasable: false,
+                location: import_glob_node.location,
+            });
+        }
+
+        import_nodes.extend(expanded_nodes);
+    }
+
+    check_duplicated_declarations(&[&function_declarations, &data_declarations])?;
+
+    Ok(import_nodes)
+}
+
+// list the public functions or data of the sibling submodule.
+fn list_sibling_submodule_items(
+    import_glob_node: &ImportGlobNode,
+    module_node: &ModuleNode,
+) -> Vec<ImportNode> {
+    let get_full_name = |name: &str| format!("{}::{}", import_glob_node.module_path, name);
+
+    match import_glob_node.item_type {
+        ImportItemType::Function => module_node
+            .functions
+            .iter()
+            .filter(|function_node| function_node.public)
+            .map(|function_node| {
+                ImportNode::Function(ImportFunctionNode {
+                    document: import_glob_node.document.clone(),
+                    public: import_glob_node.public,
+                    full_name: get_full_name(&function_node.name),
+                    signature: Some((
+                        function_node
+                            .params
+                            .iter()
+                            .map(|param| param.data_type)
+                            .collect(),
+                        function_node.results.clone(),
+                    )),
+                    alias_name: None,
+                    from: import_glob_node.from.clone(),
+                    location: import_glob_node.location,
+                })
+            })
+            .collect(),
+        ImportItemType::Data => module_node
+            .datas
+            .iter()
+            .filter(|data_node| data_node.public)
+            .map(|data_node| {
+                let (data_section_type, data_type) = get_data_section_type_and_data_type(data_node);
+                ImportNode::Data(ImportDataNode {
+                    document: import_glob_node.document.clone(),
+                    public: import_glob_node.public,
+                    data_section_type: Some(data_section_type),
+                    full_name: get_full_name(&data_node.name),
+                    data_type: Some(data_type),
+                    alias_name: None,
+                    from: import_glob_node.from.clone(),
+                    location: import_glob_node.location,
+                })
+            })
+            .collect(),
+    }
+}
+
+// list the public exported functions or data of the dependency module.
+fn list_dependency_module_items(
+    import_glob_node: &ImportGlobNode,
+    dependency_exports: &DependencyExports,
+) -> Vec<ImportNode> {
+    let prefix = format!("{}::", import_glob_node.module_path);
+
+    // the item which is directly in the (sub)module, i.e., the rest of
+    // the full name does not contain "::".
+    let is_direct_item = |full_name: &str| {
+        full_name
+            .strip_prefix(&prefix)
+            .is_some_and(|name| !name.contains(NAME_PATH_SEPARATOR))
+    };
+
+    // the export entries and the types are paired by index, their counts
+    // are checked when the dependency image is read.
+    match import_glob_node.item_type {
+        ImportItemType::Function => dependency_exports
+            .export_function_entries
+            .iter()
+            .zip(&dependency_exports.export_function_types)
+            .filter(|(entry, _)| {
+                entry.visibility == Visibility::Public && is_direct_item(&entry.full_name)
+            })
+            .map(|(entry, type_entry)| {
+                ImportNode::Function(ImportFunctionNode {
+                    document: import_glob_node.document.clone(),
+                    public: import_glob_node.public,
+                    full_name: entry.full_name.to_owned(),
+                    signature: Some((type_entry.params.clone(), type_entry.results.clone())),
+                    alias_name: None,
+                    from: import_glob_node.from.clone(),
+                    location: import_glob_node.location,
+                })
+            })
+            .collect(),
+        ImportItemType::Data => dependency_exports
+            .export_data_entries
+            .iter()
+            .zip(&dependency_exports.export_data_types)
+            .filter(|(entry, _)| {
+                entry.visibility == Visibility::Public && is_direct_item(&entry.full_name)
+            })
+            .map(|(entry, data_type)| {
+                ImportNode::Data(ImportDataNode {
+                    document: import_glob_node.document.clone(),
+                    public: import_glob_node.public,
+                    data_section_type: Some(entry.section_type),
+                    full_name: entry.full_name.to_owned(),
+                    data_type: Some(*data_type),
+                    alias_name: None,
+                    from: import_glob_node.from.clone(),
+                    location: import_glob_node.location,
+                })
+            })
+            .collect(),
+    }
+}
+
+// the types "i16", "i8" and byte arrays are stored as bytes.
+pub(crate) fn get_data_section_type_and_data_type(
+    data_node: &DataNode,
+) -> (DataSectionType, MemoryDataType) {
+    let convert_declare_data_type = |data_type: &DeclareDataType| match data_type {
+        DeclareDataType::I64 => MemoryDataType::I64,
+        DeclareDataType::I32 => MemoryDataType::I32,
+        DeclareDataType::F64 => MemoryDataType::F64,
+        DeclareDataType::F32 => MemoryDataType::F32,
+        _ => MemoryDataType::Bytes,
+    };
+
+    match &data_node.data_section {
+        DataSection::ReadOnly(data_type_value_pair) => (
+            DataSectionType::ReadOnly,
+            convert_declare_data_type(&data_type_value_pair.data_type),
+        ),
+        DataSection::ReadWrite(data_type_value_pair) => (
+            DataSectionType::ReadWrite,
+            convert_declare_data_type(&data_type_value_pair.data_type),
+        ),
+        DataSection::Uninit(fixed_declare_data_type) => {
+            let data_type = match fixed_declare_data_type {
+                FixedDeclareDataType::I64 => MemoryDataType::I64,
+                FixedDeclareDataType::I32 => MemoryDataType::I32,
+                FixedDeclareDataType::F64 => MemoryDataType::F64,
+                FixedDeclareDataType::F32 => MemoryDataType::F32,
+                _ => MemoryDataType::Bytes,
+            };
+            (DataSectionType::Uninit, data_type)
+        }
+    }
+}
+
+fn read_dependency_exports_list(
+    dependency_images: &[DependencyImage],
+) -> Result<Vec<DependencyExports>, AssemblerError> {
+    dependency_images
+        .iter()
+        .map(read_dependency_exports)
+        .collect::<Result<Vec<DependencyExports>, AssemblerError>>()
+}
+
+/// returns `None` if the item is imported from the current module, or
+/// the image of the dependency module is not provided.
+fn find_dependency_exports<'a>(
+    dependency_exports_list: &'a [DependencyExports],
+    module_name: &str,
+    full_name: &str,
+    from: &Option<String>,
+) -> Option<&'a DependencyExports> {
+    let actual_module_name = match from {
+        Some(from) => from.as_str(),
+        None => full_name
+            .split_once(NAME_PATH_SEPARATOR)
+            .map(|(name, _)| name)
+            .unwrap_or(full_name),
+    };
+
+    if actual_module_name == SELF_REFERENCE_MODULE_NAME || actual_module_name == module_name {
+        None
+    } else {
+        dependency_exports_list
+            .iter()
+            .find(|item| item.module_name == actual_module_name)
+    }
+}
+
+// find the public exported function.
+fn find_export_function_index(
+    full_name: &str,
+    dependency_exports: &DependencyExports,
+) -> Result<usize, AssemblerError> {
+    let Some(export_index) = dependency_exports
+        .export_function_entries
+        .iter()
+        .position(|entry| entry.full_name == full_name)
+    else {
+        return Err(AssemblerError::new(AssembleErrorType::ImportItemNotFound {
+            full_name: full_name.to_owned(),
+            module_name: dependency_exports.module_name.to_owned(),
+        }));
+    };
+
+    if dependency_exports.export_function_entries[export_index].visibility != Visibility::Public {
+        return Err(AssemblerError::new(
+            AssembleErrorType::ImportItemNotPublic {
+                full_name: full_name.to_owned(),
+                module_name: dependency_exports.module_name.to_owned(),
+            },
+        ));
+    }
+
+    Ok(export_index)
+}
+
+// find the public exported data.
+fn find_export_data_index(
+    full_name: &str,
+    dependency_exports: &DependencyExports,
+) -> Result<usize, AssemblerError> {
+    let Some(export_index) = dependency_exports
+        .export_data_entries
+        .iter()
+        .position(|entry| entry.full_name == full_name)
+    else {
+        return Err(AssemblerError::new(AssembleErrorType::ImportItemNotFound {
+            full_name: full_name.to_owned(),
+            module_name: dependency_exports.module_name.to_owned(),
+        }));
+    };
+
+    if dependency_exports.export_data_entries[export_index].visibility != Visibility::Public {
+        return Err(AssemblerError::new(
+            AssembleErrorType::ImportItemNotPublic {
+                full_name: full_name.to_owned(),
+                module_name: dependency_exports.module_name.to_owned(),
+            },
+        ));
+    }
+
+    Ok(export_index)
+}
+
+fn read_dependency_exports(
+    dependency_image: &DependencyImage,
+) -> Result<DependencyExports, AssemblerError> {
+    let incorrect_image_error = || {
+        AssemblerError::new(AssembleErrorType::IncorrectDependencyImage(
+            dependency_image.module_name.to_owned(),
+        ))
+    };
+
+    let module_image =
+        ModuleImage::read(dependency_image.image_binary).map_err(|_| incorrect_image_error())?;
+
+    let type_entries = module_image.get_type_section().convert_to_entries();
+    let function_entries = module_image.get_function_section().convert_to_entries();
+
+    let mut export_function_entries = module_image
+        .get_optional_export_function_section()
+        .map(|section| section.convert_to_entries())
+        .unwrap_or_default();
+
+    let mut export_data_entries = module_image
+        .get_optional_export_data_section()
+        .map(|section| section.convert_to_entries())
+        .unwrap_or_default();
+
+    // the export function entries are in the same order as the internal functions,
+    // i.e. the export function index is the internal function index.
+    if export_function_entries.len() != function_entries.len() {
+        return Err(incorrect_image_error());
+    }
+
+    let mut export_function_types = function_entries
+        .iter()
+        .map(|function_entry| {
+            type_entries
+                .get(function_entry.type_index)
+                .cloned()
+                .ok_or_else(incorrect_image_error)
+        })
+        .collect::<Result<Vec<TypeEntry>, AssemblerError>>()?;
+
+    let read_only_data_types: Vec<MemoryDataType> = module_image
+        .get_optional_read_only_data_section()
+        .map(|section| section.convert_to_entries())
+        .unwrap_or_default()
+        .iter()
+        .map(|entry| entry.memory_data_type)
+        .collect();
+
+    let read_write_data_types: Vec<MemoryDataType> = module_image
+        .get_optional_read_write_data_section()
+        .map(|section| section.convert_to_entries())
+        .unwrap_or_default()
+        .iter()
+        .map(|entry| entry.memory_data_type)
+        .collect();
+
+    let uninit_data_types: Vec<MemoryDataType> = module_image
+        .get_optional_uninit_data_section()
+        .map(|section| section.convert_to_entries())
+        .unwrap_or_default()
+        .iter()
+        .map(|entry| entry.memory_data_type)
+        .collect();
+
+    // the export data entries are sorted by section (read-only, read-write and uninit),
+    // and in the same order as the items of the corresponding data section.
+    let mut section_item_counts = [0usize; 3];
+    let mut export_data_types = export_data_entries
+        .iter()
+        .map(|entry| {
+            let (section_index, data_types) = match entry.section_type {
+                DataSectionType::ReadOnly => (0, &read_only_data_types),
+                DataSectionType::ReadWrite => (1, &read_write_data_types),
+                DataSectionType::Uninit => (2, &uninit_data_types),
+            };
+            let data_type = data_types
+                .get(section_item_counts[section_index])
+                .copied()
+                .ok_or_else(incorrect_image_error)?;
+            section_item_counts[section_index] += 1;
+            Ok(data_type)
+        })
+        .collect::<Result<Vec<MemoryDataType>, AssemblerError>>()?;
+
+    // each data item has exactly one export entry.
+    if section_item_counts
+        != [
+            read_only_data_types.len(),
+            read_write_data_types.len(),
+            uninit_data_types.len(),
+        ]
+    {
+        return Err(incorrect_image_error());
+    }
+
+    let import_function_entries = module_image
+        .get_optional_import_function_section()
+        .map(|section| section.convert_to_entries())
+        .unwrap_or_default();
+
+    let import_data_entries = module_image
+        .get_optional_import_data_section()
+        .map(|section| section.convert_to_entries())
+        .unwrap_or_default();
+
+    let reexport_function_entries = module_image
+        .get_optional_reexport_function_section()
+        .map(|section| section.convert_to_entries())
+        .unwrap_or_default();
+
+    let reexport_data_entries = module_image
+        .get_optional_reexport_data_section()
+        .map(|section| section.convert_to_entries())
+        .unwrap_or_default();
+
+    for reexport_function_entry in reexport_function_entries {
+        let type_entry = import_function_entries
+            .get(reexport_function_entry.import_function_index)
+            .and_then(|import_function_entry| type_entries.get(import_function_entry.type_index))
+            .cloned()
+            .ok_or_else(incorrect_image_error)?;
+
+        export_function_entries.push(ExportFunctionEntry::new(
+            reexport_function_entry.full_name,
+            reexport_function_entry.visibility,
+        ));
+        export_function_types.push(type_entry);
+    }
+
+    for reexport_data_entry in reexport_data_entries {
+        let import_data_entry = import_data_entries
+            .get(reexport_data_entry.import_data_index)
+            .ok_or_else(incorrect_image_error)?;
+
+        export_data_entries.push(ExportDataEntry::new(
+            reexport_data_entry.full_name,
+            reexport_data_entry.visibility,
+            import_data_entry.data_section_type,
+        ));
+        export_data_types.push(import_data_entry.memory_data_type);
+    }
+
+    Ok(DependencyExports {
+        module_name: dependency_image.module_name.to_owned(),
+        export_function_entries,
+        export_function_types,
+        export_data_entries,
+        export_data_types,
+    })
+}
+
+fn verify_import_function_node(
+    import_function_node: &ImportFunctionNode,
+    dependency_exports: &DependencyExports,
+) -> Result<(), AssemblerError> {
+    let full_name = &import_function_node.full_name;
+    let export_index = find_export_function_index(full_name, dependency_exports)?;
+    let type_entry = &dependency_exports.export_function_types[export_index];
+
+    // the omitted signature is resolved by `resolve_import_nodes`.
+    let Some((params, results)) = &import_function_node.signature else {
+        return Ok(());
+    };
+
+    if &type_entry.params != params || &type_entry.results != results {
+        return Err(AssemblerError::new(
+            AssembleErrorType::ImportFunctionSignatureMismatch {
+                full_name: full_name.to_owned(),
+                expected: format_signature(&type_entry.params, &type_entry.results),
+                actual: format_signature(params, results),
+            },
+        ));
+    }
+
+    Ok(())
+}
+
+fn verify_import_data_node(
+    import_data_node: &ImportDataNode,
+    dependency_exports: &DependencyExports,
+) -> Result<(), AssemblerError> {
+    let full_name = &import_data_node.full_name;
+    let export_index = find_export_data_index(full_name, dependency_exports)?;
+    let export_data_entry = &dependency_exports.export_data_entries[export_index];
+
+    verify_import_data_section_type(import_data_node, export_data_entry.section_type)?;
+
+    let data_type = dependency_exports.export_data_types[export_index];
+    match import_data_node.data_type {
+        Some(import_data_type) if import_data_type != data_type => Err(AssemblerError::new(
+            AssembleErrorType::ImportDataTypeMismatch {
+                full_name: full_name.to_owned(),
+                expected: data_type.to_string(),
+                actual: import_data_type.to_string(),
+            },
+        )),
+        _ => Ok(()),
+    }
+}
+
+// the omitted section is resolved by `resolve_import_nodes`.
+fn verify_import_data_section_type(
+    import_data_node: &ImportDataNode,
+    section_type: DataSectionType,
+) -> Result<(), AssemblerError> {
+    match import_data_node.data_section_type {
+        Some(import_section_type) if import_section_type != section_type => Err(
+            AssemblerError::new(AssembleErrorType::ImportDataSectionMismatch {
+                full_name: import_data_node.full_name.to_owned(),
+                expected: format_data_section_type(section_type),
+                actual: format_data_section_type(import_section_type),
+            }),
+        ),
+        _ => Ok(()),
+    }
+}
+
+// e.g. "(i32, i32) -> i64"
+fn format_signature(params: &[OperandDataType], results: &[OperandDataType]) -> String {
+    let format_list = |items: &[OperandDataType]| {
+        items
+            .iter()
+            .map(|item| item.to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
+    };
+
+    let results_text = match results.len() {
+        0 => "()".to_owned(),
+        1 => results[0].to_string(),
+        _ => format!("({})", format_list(results)),
+    };
+
+    format!("({}) -> {}", format_list(params), results_text)
+}
+
+fn format_data_section_type(data_section_type: DataSectionType) -> String {
+    match data_section_type {
+        DataSectionType::ReadOnly => "readonly",
+        DataSectionType::ReadWrite => "read-write",
+        DataSectionType::Uninit => "uninit",
+    }
+    .to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use anc_assembly::{ast::ImportNode, printer::print_to_string};
+    use anc_image::entry_writer::write_object_file;
+    use anc_isa::{DataSectionType, MemoryDataType, OperandDataType};
+    use anc_parser_asm::parser::parse_from_str;
+
+    use crate::{assembler::assemble_module_node, AssembleErrorType, AssemblerError};
+
+    use super::{
+        expand_glob_import_nodes, resolve_import_nodes, verify_import_nodes, DependencyImage,
+        SiblingSubmodule,
+    };
+
+    fn assemble_object_file(source_code: &str, module_name: &str) -> Vec<u8> {
+        let module_node = parse_from_str(source_code).unwrap();
+        let entry = assemble_module_node(&module_node, module_name, &[], &[]).unwrap();
+        let mut buf: Vec<u8> = vec![];
+        write_object_file(&entry, false, &mut buf).unwrap();
+        buf
+    }
+
+    #[test]
+    fn test_verify_import_nodes() {
+        let binary = assemble_object_file(
+            r#"
+pub fn add(left:i32, right:i32) -> i32 nop()
+fn sub(left:i32, right:i32) -> i32 nop()
+pub readonly data msg:byte[] = "Hello"
+pub data count:i32 = 11
+"#,
+            "mymod",
+        );
+
+        let dependency_images = [DependencyImage {
+            module_name: "mymod",
+            image_binary: &binary,
+        }];
+
+        let verify = |source_code: &str| {
+            let module_node = parse_from_str(source_code).unwrap();
+            verify_import_nodes(&module_node, "myapp", &dependency_images)
+        };
+
+        assert!(verify(
+            r#"
+import fn mymod::add(i32, i32) -> i32
+import readonly data mymod::msg type byte[]
+import data mymod::count type i32
+import fn module::foo()             // the current module is skipped
+import fn other::bar(i64) -> i64    // the image is not provided
+"#
+        )
+        .is_ok());
+
+        // missing symbol
+        assert!(matches!(
+            verify("import fn mymod::mul(i32, i32) -> i32"),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::ImportItemNotFound { full_name, module_name },
+                ..
+            }) if full_name == "mymod::mul" && module_name == "mymod"
+        ));
+
+        // not public
+        assert!(matches!(
+            verify("import fn mymod::sub(i32, i32) -> i32"),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::ImportItemNotPublic { .. },
+                ..
+            })
+        ));
+
+        // signature mismatch
+        assert!(matches!(
+            verify("import fn mymod::add(i64) -> i64"),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::ImportFunctionSignatureMismatch { expected, actual, .. },
+                ..
+            }) if expected == "(i32, i32) -> i32" && actual == "(i64) -> i64"
+        ));
+
+        // section mismatch
+        assert!(matches!(
+            verify("import readonly data mymod::count type i32"),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::ImportDataSectionMismatch { expected, actual, .. },
+                ..
+            }) if expected == "read-write" && actual == "readonly"
+        ));
+
+        // data type mismatch
+        assert!(matches!(
+            verify("import data mymod::count type i64"),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::ImportDataTypeMismatch { expected, actual, .. },
+                ..
+            }) if expected == "i32" && actual == "i64"
+        ));
+
+        // the omitted items are verified as well, i.e. the existence
+        // and the declared section.
+        assert!(verify("import fn mymod::add\nimport data mymod::msg").is_ok());
+
+        assert!(matches!(
+            verify("import fn mymod::sub"),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::ImportItemNotPublic { .. },
+                ..
+            })
+        ));
+
+        assert!(matches!(
+            verify("import uninit data mymod::msg"),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::ImportDataSectionMismatch { expected, actual, .. },
+                ..
+            }) if expected == "readonly" && actual == "uninit"
+        ));
+
+        // malformed image, the export function entries do not match the functions
+        let module_node = parse_from_str("pub fn add(left:i32, right:i32) -> i32 nop()").unwrap();
+        let mut entry = assemble_module_node(&module_node, "mymod", &[], &[]).unwrap();
+        entry.export_function_entries.clear();
+        let mut malformed_binary: Vec<u8> = vec![];
+        write_object_file(&entry, false, &mut malformed_binary).unwrap();
+
+        let malformed_images = [DependencyImage {
+            module_name: "mymod",
+            image_binary: &malformed_binary,
+        }];
+        let module_node = parse_from_str("import fn mymod::add(i32, i32) -> i32").unwrap();
+        assert!(matches!(
+            verify_import_nodes(&module_node, "myapp", &malformed_images),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::IncorrectDependencyImage(_),
+                ..
+            })
+        ));
+
+        // the module name specified by 'from'
+        assert!(matches!(
+            verify("import fn mymod::add(i32) -> i32 from mymod"),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::ImportFunctionSignatureMismatch { .. },
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_verify_reexported_items() {
+        let binary = assemble_object_file(
+            r#"
+import module other version "1.0.0"
+pub import fn other::add(i32, i32) -> i32
+pub import readonly data other::msg type byte[] as greeting
+import fn other::sub(i32, i32) -> i32
+"#,
+            "mymod",
+        );
+
+        let dependency_images = [DependencyImage {
+            module_name: "mymod",
+            image_binary: &binary,
+        }];
+
+        let verify = |source_code: &str| {
+            let module_node = parse_from_str(source_code).unwrap();
+            verify_import_nodes(&module_node, "myapp", &dependency_images)
+        };
+
+        // the re-exported items are exported by the names in the dependency module
+        assert!(verify(
+            r#"
+import fn mymod::add(i32, i32) -> i32
+import readonly data mymod::greeting type byte[]
+"#
+        )
+        .is_ok());
+
+        // the signature of the re-exported function is taken from its import entry
+        assert!(matches!(
+            verify("import fn mymod::add(i64) -> i64"),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::ImportFunctionSignatureMismatch { expected, .. },
+                ..
+            }) if expected == "(i32, i32) -> i32"
+        ));
+
+        // the items which are imported but not re-exported can not be imported
+        assert!(matches!(
+            verify("import fn mymod::sub(i32, i32) -> i32"),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::ImportItemNotFound { .. },
+                ..
+            })
+        ));
+
+        // the omitted signature and type are resolved by the import entries
+        let mut module_node =
+            parse_from_str("import fn mymod::add\nimport data mymod::greeting").unwrap();
+        resolve_import_nodes(&mut module_node, "myapp", &dependency_images).unwrap();
+        assert_eq!(
+            print_to_string(&module_node),
+            "\
+import fn mymod::add(i32, i32) -> i32
+import readonly data mymod::greeting type byte[]
+
+"
+        );
+    }
+
+    #[test]
+    fn test_resolve_import_nodes() {
+        let binary = assemble_object_file(
+            r#"
+pub fn add(left:i32, right:i32) -> i32 nop()
+fn sub(left:i32, right:i32) -> i32 nop()
+pub readonly data msg:byte[] = "Hello"
+pub data count:i32 = 11
+pub uninit data buf:i64
+"#,
+            "mymod",
+        );
+
+        let dependency_images = [DependencyImage {
+            module_name: "mymod",
+            image_binary: &binary,
+        }];
+
+        let mut module_node = parse_from_str(
+            r#"
+import fn mymod::add
+import fn mymod::add(i32, i32) -> i32 as add2
+import data mymod::msg
+import data mymod::count as cnt
+import data mymod::buf
+"#,
+        )
+        .unwrap();
+
+        resolve_import_nodes(&mut module_node, "myapp", &dependency_images).unwrap();
+
+        let ImportNode::Function(f0) = &module_node.imports[0] else {
+            panic!()
+        };
+        assert_eq!(
+            f0.signature,
+            Some((
+                vec![OperandDataType::I32, OperandDataType::I32],
+                vec![OperandDataType::I32]
+            ))
+        );
+
+        let ImportNode::Data(d0) = &module_node.imports[2] else {
+            panic!()
+        };
+        assert_eq!(d0.data_section_type, Some(DataSectionType::ReadOnly));
+        assert_eq!(d0.data_type, Some(MemoryDataType::Bytes));
+
+        let ImportNode::Data(d1) = &module_node.imports[3] else {
+            panic!()
+        };
+        assert_eq!(d1.data_section_type, Some(DataSectionType::ReadWrite));
+        assert_eq!(d1.data_type, Some(MemoryDataType::I32));
+        assert_eq!(d1.alias_name, Some("cnt".to_owned()));
+
+        let ImportNode::Data(d2) = &module_node.imports[4] else {
+            panic!()
+        };
+        assert_eq!(d2.data_section_type, Some(DataSectionType::Uninit));
+        assert_eq!(d2.data_type, Some(MemoryDataType::I64));
+
+        // the resolved nodes pass the verification
+        assert!(verify_import_nodes(&module_node, "myapp", &dependency_images).is_ok());
+
+        let resolve = |source_code: &str| {
+            let mut module_node = parse_from_str(source_code).unwrap();
+            resolve_import_nodes(&mut module_node, "myapp", &dependency_images)
+        };
+
+        // missing symbol
+        assert!(matches!(
+            resolve("import fn mymod::mul"),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::ImportItemNotFound { full_name, .. },
+                ..
+            }) if full_name == "mymod::mul"
+        ));
+
+        // not public
+        assert!(matches!(
+            resolve("import fn mymod::sub"),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::ImportItemNotPublic { .. },
+                ..
+            })
+        ));
+
+        // the declared section does not match
+        assert!(matches!(
+            resolve("import readonly data mymod::count"),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::ImportDataSectionMismatch { full_name, .. },
+                ..
+            }) if full_name == "mymod::count"
+        ));
+
+        // the declared section matches
+        assert!(resolve("import uninit data mymod::buf").is_ok());
+
+        // the image is not provided
+        assert!(matches!(
+            resolve("import data other::foo"),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::UnresolvedImportItem(full_name),
+                ..
+            }) if full_name == "other::foo"
+        ));
+
+        // the unresolved import can not be assembled
+        let module_node = parse_from_str("import fn mymod::add").unwrap();
+        assert!(matches!(
+            assemble_module_node(&module_node, "myapp", &[], &[]),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::UnresolvedImportItem(_),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_expand_glob_import_nodes() {
+        let binary = assemble_object_file(
+            r#"
+pub fn add(left:i32, right:i32) -> i32 nop()
+fn sub(left:i32, right:i32) -> i32 nop()
+pub readonly data msg:byte[] = "Hello"
+pub data count:i32 = 11
+pub uninit data buf:i64
+"#,
+            "mymod",
+        );
+
+        let dependency_images = [DependencyImage {
+            module_name: "mymod",
+            image_binary: &binary,
+        }];
+
+        let utils_module_node = parse_from_str(
+            r#"
+pub fn inc(num:i32) -> i32 nop()
+fn dec(num:i32) -> i32 nop()
+pub readonly data PI:f32 = 3.1416_f32
+"#,
+        )
+        .unwrap();
+
+        let sibling_submodules = [SiblingSubmodule {
+            submodule_full_name: "myapp::utils",
+            module_node: &utils_module_node,
+        }];
+
+        let expand = |source_code: &str| {
+            let mut module_node = parse_from_str(source_code).unwrap();
+            module_node.imports = expand_glob_import_nodes(
+                &module_node,
+                "myapp",
+                &dependency_images,
+                &sibling_submodules,
+            )?;
+            Ok::<String, AssemblerError>(print_to_string(&module_node))
+        };
+
+        // expand by the image of dependency module
+        assert_eq!(
+            expand(
+                r#"
+import fn mymod::*
+import data mymod::*
+"#
+            )
+            .unwrap(),
+            "\
+import fn mymod::add(i32, i32) -> i32
+import readonly data mymod::msg type byte[]
+import data mymod::count type i32
+import uninit data mymod::buf type i64\n\n"
+        );
+
+        // expand by the source of sibling submodule
+        assert_eq!(
+            expand(
+                r#"
+import fn module::utils::*
+import data myapp::utils::*
+import fn mymod::add(i32, i32) -> i32 as add_i32
+"#
+            )
+            .unwrap(),
+            "\
+import fn module::utils::inc(i32) -> i32
+import readonly data myapp::utils::PI type f32
+import fn mymod::add(i32, i32) -> i32 as add_i32\n\n"
+        );
+
+        // conflict with the local function
+        assert!(matches!(
+            expand(
+                r#"
+import fn mymod::*
+fn add() nop()
+"#
+            ),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::DuplicatedIdentifiers(duplicated_identifiers),
+                ..
+            }) if duplicated_identifiers[0].identifier == "add"
+        ));
+
+        // neither the image nor the source is provided
+        assert!(matches!(
+            expand("import fn other::*"),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::UnexpandedGlobImport(glob),
+                ..
+            }) if glob == "other::*"
+        ));
+
+        // the unexpanded glob import can not be assembled
+        let module_node = parse_from_str("import fn mymod::*").unwrap();
+        assert!(matches!(
+            assemble_module_node(&module_node, "myapp", &[], &[]),
+            Err(AssemblerError {
+                error_type: AssembleErrorType::UnexpandedGlobImport(_),
+                ..
+            })
+        ));
+    }
+}
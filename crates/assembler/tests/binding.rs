@@ -0,0 +1,123 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the module `arith` is generated by `generate_rust_binding` from `ARITH_SOURCE`,
+// `test_binding_generated_file` checks that it is up to date.
+#[rustfmt::skip]
+mod arith;
+
+use anc_assembler::{
+    assembler::assemble_module_node,
+    binding::{generate_rust_binding, generate_rust_binding_by_image_common_entry},
+    utils::helper_make_single_module_app,
+};
+use anc_context::process_resource::ProcessResource;
+use anc_parser_asm::parser::parse_from_str;
+use anc_processor::{
+    handler::Handler, in_memory_process_resource::InMemoryProcessResource, HandleErrorType,
+    HandlerError,
+};
+use pretty_assertions::assert_eq;
+
+const ARITH_SOURCE: &str = r#"
+/// Adds two numbers.
+pub fn add(left:i32, right:i32) -> i32
+    add_i32(local_load_i32_s(left), local_load_i32_s(right))
+
+// the private functions are not in the binding, but they
+// still occupy the function public indices.
+fn helper() nop()
+
+pub fn swap(handler:f64, handler_:i64) -> (i64, f64)
+{
+    local_load_i64(handler_)
+    local_load_f64(handler)
+}
+"#;
+
+#[test]
+fn test_binding_generated_file() {
+    let module_node = parse_from_str(ARITH_SOURCE).unwrap();
+    assert_eq!(
+        generate_rust_binding(&module_node, 0).unwrap(),
+        include_str!("binding/arith.rs")
+    );
+}
+
+#[test]
+fn test_binding_generated_by_image_common_entry() {
+    // the binding generated from the image calls the same functions as the
+    // binding generated from the source, i.e. the function public indices match.
+    let get_function_public_indices = |binding: &str| -> Vec<String> {
+        binding
+            .lines()
+            .collect::<Vec<&str>>()
+            .windows(2)
+            .filter(|lines| lines[0].trim() == "MODULE_INDEX,")
+            .map(|lines| lines[1].trim().to_owned())
+            .collect()
+    };
+
+    let module_node = parse_from_str(ARITH_SOURCE).unwrap();
+    let image_common_entry = assemble_module_node(&module_node, "arith", &[], &[]).unwrap();
+
+    let indices = get_function_public_indices(include_str!("binding/arith.rs"));
+    assert_eq!(indices, vec!["0,", "2,"]);
+    assert_eq!(
+        get_function_public_indices(&generate_rust_binding_by_image_common_entry(
+            &image_common_entry,
+            0
+        )),
+        indices
+    );
+}
+
+#[test]
+fn test_binding_call() {
+    let binary0 = helper_make_single_module_app(ARITH_SOURCE);
+
+    let handler = Handler::new();
+    let resource0 = InMemoryProcessResource::new(vec![binary0]);
+    let process_context0 = resource0.create_process_context().unwrap();
+    let mut thread_context0 = process_context0.create_thread_context();
+
+    assert_eq!(
+        arith::add(&handler, &mut thread_context0, 11, 13).unwrap(),
+        24
+    );
+    assert_eq!(
+        arith::add(&handler, &mut thread_context0, -7, 2).unwrap(),
+        -5
+    );
+
+    assert_eq!(
+        arith::swap(&handler, &mut thread_context0, 3.5, -17).unwrap(),
+        (-17, 3.5)
+    );
+}
+
+#[test]
+fn test_binding_call_with_mismatched_result() {
+    // the function `add` returns i64 instead of i32 after the binding is generated.
+    let binary0 = helper_make_single_module_app(
+        r#"
+        pub fn add(left:i32, right:i32) -> i64
+            imm_i64(0)
+        "#,
+    );
+
+    let handler = Handler::new();
+    let resource0 = InMemoryProcessResource::new(vec![binary0]);
+    let process_context0 = resource0.create_process_context().unwrap();
+    let mut thread_context0 = process_context0.create_thread_context();
+
+    assert!(matches!(
+        arith::add(&handler, &mut thread_context0, 11, 13),
+        Err(HandlerError {
+            error_type: HandleErrorType::DataTypeMissmatch
+        })
+    ));
+}
@@ -0,0 +1,49 @@
+// This file is generated by `anc_assembler::binding`, do not edit it manually.
+
+use anc_context::thread_context::ThreadContext;
+use anc_isa::ForeignValue;
+use anc_processor::{handler::Handler, process::process_function, HandleErrorType, HandlerError};
+
+pub const MODULE_INDEX: usize = 0;
+
+/// Adds two numbers.
+pub fn add(
+    handler: &Handler,
+    thread_context: &mut ThreadContext,
+    left: i32,
+    right: i32,
+) -> Result<i32, HandlerError> {
+    let results = process_function(
+        handler,
+        thread_context,
+        MODULE_INDEX,
+        0,
+        &[
+            ForeignValue::U32(left as u32),
+            ForeignValue::U32(right as u32),
+        ],
+    )?;
+    Ok(match results[0] { ForeignValue::U32(value) => value as i32, _ => return Err(HandlerError { error_type: HandleErrorType::DataTypeMissmatch }) })
+}
+
+pub fn swap(
+    handler: &Handler,
+    thread_context: &mut ThreadContext,
+    handler_: f64,
+    handler__: i64,
+) -> Result<(i64, f64), HandlerError> {
+    let results = process_function(
+        handler,
+        thread_context,
+        MODULE_INDEX,
+        2,
+        &[
+            ForeignValue::F64(handler_),
+            ForeignValue::U64(handler__ as u64),
+        ],
+    )?;
+    Ok((
+        match results[0] { ForeignValue::U64(value) => value as i64, _ => return Err(HandlerError { error_type: HandleErrorType::DataTypeMissmatch }) },
+        match results[1] { ForeignValue::F64(value) => value, _ => return Err(HandlerError { error_type: HandleErrorType::DataTypeMissmatch }) },
+    ))
+}
@@ -7,67 +7,224 @@
 use std::fmt::Display;
 
 use anc_isa::{DataSectionType, MemoryDataType, OperandDataType};
+use serde::Deserialize;
+
+use crate::location::Location;
 
 #[derive(Debug, PartialEq)]
 pub struct ModuleNode {
+    // the document of this (sub)module, i.e. the `//!...` lines at the
+    // beginning of the source file, the lines are joined by '\n'.
+    pub document: Option<String>,
+
     pub imports: Vec<ImportNode>,
     pub externals: Vec<ExternalNode>,
     pub datas: Vec<DataNode>,
     pub functions: Vec<FunctionNode>,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum ImportNode {
     Function(ImportFunctionNode),
     Data(ImportDataNode),
+    Module(ImportModuleNode),
+    Glob(ImportGlobNode),
 }
 
-#[derive(Debug, PartialEq)]
+/// declare an import module in the source file, e.g.
+/// `import module http_client version "1.2.0"`
+#[derive(Debug, PartialEq, Clone)]
+pub struct ImportModuleNode {
+    // the document of this statement, i.e. the `///...` lines above it,
+    // the lines are joined by '\n'.
+    pub document: Option<String>,
+
+    pub name: String,
+    pub source: DependencySource,
+
+    // the location of the name in the source file.
+    pub location: Location,
+}
+
+#[derive(Debug, PartialEq, Clone)]
 pub struct ImportFunctionNode {
+    // the document of this statement, i.e. the `///...` lines above it,
+    // the lines are joined by '\n'.
+    pub document: Option<String>,
+
+    // re-export the imported function, e.g. `pub import fn foo::bar(i32) -> i32`,
+    // the current module exports the imported function by the same identifier.
+    pub public: bool,
+
     /// about the "full_name" and "name_path"
     /// -------------------------------------
     /// - "full_name" = "module_name::name_path"
     /// - "name_path" = "namespace::identifier"
     /// - "namespace" = "sub_module_name"{0,N}
     pub full_name: String,
-    pub params: Vec<OperandDataType>,
-    pub results: Vec<OperandDataType>,
+
+    // the `(params, results)` of the function, it is `None` if the signature
+    // is omitted in the source, e.g. `import fn std::memory::copy`, until it
+    // is resolved from the image of the dependency module.
+    pub signature: Option<(Vec<OperandDataType>, Vec<OperandDataType>)>,
     pub alias_name: Option<String>,
     pub from: Option<String>,
+
+    // the location of the identifier in the source file, i.e. the alias name
+    // if it presents, otherwise the last segment of the full name.
+    pub location: Location,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct ImportDataNode {
-    pub data_section_type: DataSectionType,
+    // the document of this statement, i.e. the `///...` lines above it,
+    // the lines are joined by '\n'.
+    pub document: Option<String>,
+
+    // re-export the imported data, e.g. `pub import data foo::count type i32`.
+    pub public: bool,
+
+    // it is `None` if both the section and the data type are omitted in
+    // the source, e.g. `import data foo::bar`, until it is resolved from the
+    // image of the dependency module.
+    // the section `import data ...` is read-write if the data type presents.
+    pub data_section_type: Option<DataSectionType>,
     pub full_name: String,
-    pub data_type: MemoryDataType,
+
+    // it is `None` if the data type is omitted in the source, e.g.
+    // `import readonly data foo::bar`, until it is resolved from the
+    // image of the dependency module.
+    pub data_type: Option<MemoryDataType>,
     pub alias_name: Option<String>,
     pub from: Option<String>,
+
+    // the location of the identifier in the source file, i.e. the alias name
+    // if it presents, otherwise the last segment of the full name.
+    pub location: Location,
+}
+
+/// import all public functions or data of a (sub)module, e.g.
+/// `import fn std::memory::*`
+///
+/// it is expanded to the individual `ImportFunctionNode`s or
+/// `ImportDataNode`s before assembling.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ImportGlobNode {
+    // the document of this statement, i.e. the `///...` lines above it,
+    // the lines are joined by '\n'.
+    pub document: Option<String>,
+
+    // re-export all the imported items.
+    pub public: bool,
+
+    pub item_type: ImportItemType,
+
+    // the full name of the (sub)module, e.g. "std::memory".
+    pub module_path: String,
+    pub from: Option<String>,
+
+    // the location of the name path in the source file, e.g. "std::memory::*".
+    pub location: Location,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ImportItemType {
+    Function,
+    Data,
 }
 
 #[derive(Debug, PartialEq)]
 pub enum ExternalNode {
     Function(ExternalFunctionNode),
     Data(ExternalDataNode),
+    Library(ExternalLibraryNode),
+}
+
+/// declare an external library in the source file, e.g.
+/// `external library libfoo from "lib/libfoo.so"`
+#[derive(Debug, PartialEq)]
+pub struct ExternalLibraryNode {
+    // the document of this statement, i.e. the `///...` lines above it,
+    // the lines are joined by '\n'.
+    pub document: Option<String>,
+
+    pub name: String,
+    pub source: DependencySource,
+
+    // the location of the name in the source file.
+    pub location: Location,
+}
+
+/// the location of an import module or an external library.
+///
+/// it is also the `source` of the dependencies in the module
+/// manifest (i.e. "module.ason"), e.g. `DependencySource::Share("1.2.0")`.
+#[derive(Debug, PartialEq, Clone, Deserialize)]
+pub enum DependencySource {
+    // `version "1.2.0"`, a shared module or library
+    #[serde(rename = "Share")]
+    Version(String),
+
+    // `from "path/to/module"`, a local module or library,
+    // the path is relative to the current project.
+    Local(String),
+
+    // `remote "url" "reversion"`, a module or library
+    // in a remote Git repository.
+    Remote(RemoteSource),
+
+    // `runtime`, a module provided by the runtime, e.g. "std",
+    // it is only available for the import module.
+    Runtime,
+
+    // `system "libc.so.6"`, a system library, it is only
+    // available for the external library.
+    System(String),
+}
+
+#[derive(Debug, PartialEq, Clone, Deserialize)]
+pub struct RemoteSource {
+    pub url: String,
+    pub reversion: String,
 }
 
 #[derive(Debug, PartialEq)]
 pub struct ExternalFunctionNode {
+    // the document of this statement, i.e. the `///...` lines above it,
+    // the lines are joined by '\n'.
+    pub document: Option<String>,
+
     pub full_name: String,
     pub params: Vec<OperandDataType>,
     pub result: Option<OperandDataType>,
     pub alias_name: Option<String>,
+
+    // the location of the identifier in the source file, i.e. the alias name
+    // if it presents, otherwise the last segment of the full name.
+    pub location: Location,
 }
 
 #[derive(Debug, PartialEq)]
 pub struct ExternalDataNode {
+    // the document of this statement, i.e. the `///...` lines above it,
+    // the lines are joined by '\n'.
+    pub document: Option<String>,
+
     pub full_name: String,
     pub data_type: MemoryDataType,
     pub alias_name: Option<String>,
+
+    // the location of the identifier in the source file, i.e. the alias name
+    // if it presents, otherwise the last segment of the full name.
+    pub location: Location,
 }
 
 #[derive(Debug, PartialEq)]
 pub struct DataNode {
+    // the document of this statement, i.e. the `///...` lines above it,
+    // the lines are joined by '\n'.
+    pub document: Option<String>,
+
     // field 'public' is used to indicate the visibility of this item when this
     // module is used as a shared module.
     // Note that in the same module, the data items are always
@@ -75,6 +232,9 @@ pub struct DataNode {
     pub public: bool,
     pub name: String,
     pub data_section: DataSection,
+
+    // the location of the name in the source file.
+    pub location: Location,
 }
 
 #[derive(Debug, PartialEq)]
@@ -94,6 +254,8 @@ pub struct DataTypeValuePair {
 pub enum DeclareDataType {
     I64,
     I32,
+    I16,
+    I8,
     F64,
     F32,
 
@@ -104,7 +266,7 @@ pub enum DeclareDataType {
     FixedBytes(/* length */ usize, /* align */ Option<usize>),
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum DataValue {
     I8(u8),
     I16(u16),
@@ -112,15 +274,55 @@ pub enum DataValue {
     I32(u32),
     F64(f64),
     F32(f32),
+
+    // the char literal, e.g. `'A'`, it is treated as an `i32` number.
+    Char(char),
+
     String(String),
     ByteData(Vec<u8>),
 
     // e.g. [11_i32, 13_i32, 17_i32, 19_i32]
     List(Vec<DataValue>),
+
+    // e.g. `[0_i32; 256]`, `[[0_i8; 4]; 16]`
+    Repetition(Box<DataValue>, /* count */ usize),
+
+    // e.g. `fill(0xcc_i8)`, it is only allowed for the fixed-length byte array,
+    // the content of the value is repeated until the byte array is filled.
+    Fill(Box<DataValue>),
+
+    // e.g. `include_bytes("assets/table.bin")`, `include_str("message.txt")`
+    IncludeFile(IncludeFile),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct IncludeFile {
+    pub file_type: IncludeFileType,
+
+    // the path is relative to the source file.
+    pub file_path: String,
+
+    // the optional slicing of the file content, in bytes.
+    // e.g. `include_bytes("assets/table.bin", offset=16, length=256)`
+    pub offset: Option<usize>,
+    pub length: Option<usize>,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum IncludeFileType {
+    // `include_bytes(...)`
+    Binary,
+
+    // `include_str(...)`, the content must be valid UTF-8 text.
+    Text,
 }
 
 #[derive(Debug, PartialEq)]
 pub struct FunctionNode {
+    // the document of this statement, i.e. the `///...` lines above it,
+    // the lines are joined by '\n'.
+    pub document: Option<String>,
+
     // field 'public' is used to indicate the visibility of this item when this
     // module is used as a shared module.
     // Note that in the same module, the data items are always
@@ -131,24 +333,39 @@ pub struct FunctionNode {
     pub results: Vec<OperandDataType>,
     pub locals: Vec<LocalVariable>,
     pub body: Box<ExpressionNode>,
+
+    // the location of the name in the source file.
+    pub location: Location,
+
+    // the location of the whole statement, i.e. from the keyword "fn"
+    // to the end of the body.
+    pub statement_location: Location,
 }
 
 #[derive(Debug, PartialEq)]
 pub struct NamedParameter {
     pub name: String,
     pub data_type: OperandDataType,
+
+    // the location of the name in the source file.
+    pub location: Location,
 }
 
 #[derive(Debug, PartialEq)]
 pub struct LocalVariable {
     pub name: String,
     pub data_type: FixedDeclareDataType,
+
+    // the location of the name in the source file.
+    pub location: Location,
 }
 
 #[derive(Debug, PartialEq)]
 pub enum FixedDeclareDataType {
     I64,
     I32,
+    I16,
+    I8,
     F64,
     F32,
 
@@ -211,6 +428,9 @@ pub struct NameValueParameter {
     pub name: String,
     pub data_type: OperandDataType,
     pub value: Box<ExpressionNode>,
+
+    // the location of the name in the source file.
+    pub location: Location,
 }
 
 #[derive(Debug, PartialEq)]
@@ -232,6 +452,9 @@ pub struct InstructionNode {
     pub name: String,
     pub positional_args: Vec<ArgumentValue>,
     pub named_args: Vec<NamedArgument>,
+
+    // the location of the instruction name in the source file.
+    pub location: Location,
 }
 
 #[derive(Debug, PartialEq)]
@@ -244,10 +467,19 @@ pub enum ArgumentValue {
     // - "full_name" = "module_name::name_path"
     // - "name_path" = "namespace::identifier"
     // - "namespace" = "sub_module_name"{0,N}
-    Identifier(String),
+    //
+    // the location is the range of the identifier in the source file.
+    Identifier(String, Location),
 
     LiteralNumber(LiteralNumber),
 
+    // The string, hex byte data and list literals, they are
+    // only allowed in the data loading instructions, e.g.
+    // `host_addr_data("Hello, World!")` and `data_load_i32_u([11, 13, 17], offset=4)`.
+    //
+    // The assembler converts these literals into anonymous read-only data.
+    LiteralData(DataValue),
+
     Expression(Box<ExpressionNode>),
 }
 
@@ -265,6 +497,9 @@ pub enum LiteralNumber {
     I64(u64),
     F32(f32),
     F64(f64),
+
+    // the char literal, e.g. `'A'`, it is treated as an `i32` number.
+    Char(char),
 }
 
 impl Display for DeclareDataType {
@@ -272,6 +507,8 @@ impl Display for DeclareDataType {
         match self {
             DeclareDataType::I64 => f.write_str("i64"),
             DeclareDataType::I32 => f.write_str("i32"),
+            DeclareDataType::I16 => f.write_str("i16"),
+            DeclareDataType::I8 => f.write_str("i8"),
             DeclareDataType::F64 => f.write_str("f64"),
             DeclareDataType::F32 => f.write_str("f32"),
             DeclareDataType::Bytes(opt_align) => {
@@ -297,6 +534,8 @@ impl Display for FixedDeclareDataType {
         match self {
             FixedDeclareDataType::I64 => f.write_str("i64"),
             FixedDeclareDataType::I32 => f.write_str("i32"),
+            FixedDeclareDataType::I16 => f.write_str("i16"),
+            FixedDeclareDataType::I8 => f.write_str("i8"),
             FixedDeclareDataType::F64 => f.write_str("f64"),
             FixedDeclareDataType::F32 => f.write_str("f32"),
             FixedDeclareDataType::FixedBytes(length, opt_align) => {
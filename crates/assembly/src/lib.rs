@@ -5,4 +5,5 @@
 // more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
 
 pub mod ast;
+pub mod location;
 pub mod printer;
@@ -4,7 +4,10 @@
 // the Mozilla Public License version 2.0 and additional exceptions,
 // more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
 
-#[derive(Debug, PartialEq, Clone, Copy)]
+// the location of a token or a node in the source file, the `Default` value
+// is used by the nodes which are not produced by the parser, e.g. the nodes
+// which are built from the image of dependency module.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
 pub struct Location {
     // pub unit: usize,   // the index of source file
     pub index: usize,  // character index
@@ -7,10 +7,11 @@
 use anc_isa::{DataSectionType, OperandDataType};
 
 use crate::ast::{
-    ArgumentValue, BlockNode, BreakNode, DataNode, DataSection, DataValue, ExpressionNode,
-    ExternalDataNode, ExternalFunctionNode, ExternalNode, FunctionNode, IfNode, ImportDataNode,
-    ImportFunctionNode, ImportNode, InstructionNode, LiteralNumber, LocalVariable, ModuleNode,
-    NameValueParameter, NamedParameter, WhenNode,
+    ArgumentValue, BlockNode, BreakNode, DataNode, DataSection, DataValue, DependencySource,
+    ExpressionNode, ExternalDataNode, ExternalFunctionNode, ExternalLibraryNode, ExternalNode,
+    FunctionNode, IfNode, ImportDataNode, ImportFunctionNode, ImportGlobNode, ImportItemType,
+    ImportModuleNode, ImportNode, IncludeFile, IncludeFileType, InstructionNode, LiteralNumber,
+    LocalVariable, ModuleNode, NameValueParameter, NamedParameter, WhenNode,
 };
 
 use std::io::{Error, Write};
@@ -31,6 +32,8 @@ fn print_function_node(
     //     body
     // ```
 
+    print_document(writer, &node.document, "///")?;
+
     if node.public {
         write!(writer, "pub ")?;
     }
@@ -65,6 +68,8 @@ fn print_data_node(
     node: &DataNode,
     indent_chars: &str,
 ) -> Result<(), Error> {
+    print_document(writer, &node.document, "///")?;
+
     if node.public {
         write!(writer, "pub ")?;
     }
@@ -100,20 +105,63 @@ fn print_import_node(writer: &mut dyn Write, node: &ImportNode) -> Result<(), Er
     match node {
         ImportNode::Function(node) => print_import_function_node(writer, node),
         ImportNode::Data(node) => print_import_data_node(writer, node),
+        ImportNode::Module(node) => print_import_module_node(writer, node),
+        ImportNode::Glob(node) => print_import_glob_node(writer, node),
+    }
+}
+
+fn print_import_glob_node(writer: &mut dyn Write, node: &ImportGlobNode) -> Result<(), Error> {
+    let item_type = match node.item_type {
+        ImportItemType::Function => "fn",
+        ImportItemType::Data => "data",
+    };
+
+    print_document(writer, &node.document, "///")?;
+
+    if node.public {
+        write!(writer, "pub ")?;
+    }
+
+    write!(writer, "import {} {}::*", item_type, node.module_path)?;
+
+    if let Some(from) = &node.from {
+        write!(writer, " from {}", from)?;
     }
+
+    Ok(())
+}
+
+fn print_import_module_node(writer: &mut dyn Write, node: &ImportModuleNode) -> Result<(), Error> {
+    print_document(writer, &node.document, "///")?;
+
+    write!(
+        writer,
+        "import module {} {}",
+        node.name,
+        format_dependency_source(&node.source)
+    )
 }
 
 fn print_import_function_node(
     writer: &mut dyn Write,
     node: &ImportFunctionNode,
 ) -> Result<(), Error> {
-    write!(
-        writer,
-        "import fn {}{} -> {}",
-        node.full_name,
-        format_import_params(&node.params),
-        format_results(&node.results),
-    )?;
+    print_document(writer, &node.document, "///")?;
+
+    if node.public {
+        write!(writer, "pub ")?;
+    }
+
+    write!(writer, "import fn {}", node.full_name)?;
+
+    if let Some((params, results)) = &node.signature {
+        write!(
+            writer,
+            "{} -> {}",
+            format_import_params(params),
+            format_results(results),
+        )?;
+    }
 
     if let Some(alias) = &node.alias_name {
         write!(writer, " as {}", alias)?;
@@ -127,30 +175,28 @@ fn print_import_function_node(
 }
 
 fn print_import_data_node(writer: &mut dyn Write, node: &ImportDataNode) -> Result<(), Error> {
+    print_document(writer, &node.document, "///")?;
+
+    if node.public {
+        write!(writer, "pub ")?;
+    }
+
     match node.data_section_type {
-        DataSectionType::ReadOnly => {
-            write!(
-                writer,
-                "import readonly data {} type {}",
-                node.full_name, node.data_type
-            )?;
+        Some(DataSectionType::ReadOnly) => {
+            write!(writer, "import readonly data {}", node.full_name)?;
         }
-        DataSectionType::ReadWrite => {
-            write!(
-                writer,
-                "import data {} type {}",
-                node.full_name, node.data_type
-            )?;
+        Some(DataSectionType::ReadWrite) | None => {
+            write!(writer, "import data {}", node.full_name)?;
         }
-        DataSectionType::Uninit => {
-            write!(
-                writer,
-                "import uninit data {} type {}",
-                node.full_name, node.data_type
-            )?;
+        Some(DataSectionType::Uninit) => {
+            write!(writer, "import uninit data {}", node.full_name)?;
         }
     }
 
+    if let Some(data_type) = &node.data_type {
+        write!(writer, " type {}", data_type)?;
+    }
+
     if let Some(alias) = &node.alias_name {
         write!(writer, " as {}", alias)?;
     }
@@ -166,6 +212,41 @@ fn print_external_node(writer: &mut dyn Write, node: &ExternalNode) -> Result<()
     match node {
         ExternalNode::Function(node) => print_external_function_node(writer, node),
         ExternalNode::Data(node) => print_external_data_node(writer, node),
+        ExternalNode::Library(node) => print_external_library_node(writer, node),
+    }
+}
+
+fn print_external_library_node(
+    writer: &mut dyn Write,
+    node: &ExternalLibraryNode,
+) -> Result<(), Error> {
+    print_document(writer, &node.document, "///")?;
+
+    write!(
+        writer,
+        "external library {} {}",
+        node.name,
+        format_dependency_source(&node.source)
+    )
+}
+
+/// format:
+/// - version "..."
+/// - from "..."
+/// - remote "..." "..."
+/// - runtime
+/// - system "..."
+pub fn format_dependency_source(source: &DependencySource) -> String {
+    match source {
+        DependencySource::Version(version) => format!("version {}", format_string(version)),
+        DependencySource::Local(path) => format!("from {}", format_string(path)),
+        DependencySource::Remote(remote) => format!(
+            "remote {} {}",
+            format_string(&remote.url),
+            format_string(&remote.reversion)
+        ),
+        DependencySource::Runtime => "runtime".to_owned(),
+        DependencySource::System(file_name) => format!("system {}", format_string(file_name)),
     }
 }
 
@@ -173,6 +254,8 @@ fn print_external_function_node(
     writer: &mut dyn Write,
     node: &ExternalFunctionNode,
 ) -> Result<(), Error> {
+    print_document(writer, &node.document, "///")?;
+
     write!(
         writer,
         "external fn {}{} -> {}",
@@ -192,6 +275,8 @@ fn print_external_function_node(
 }
 
 fn print_external_data_node(writer: &mut dyn Write, node: &ExternalDataNode) -> Result<(), Error> {
+    print_document(writer, &node.document, "///")?;
+
     write!(
         writer,
         "external data {} type {}",
@@ -204,11 +289,36 @@ fn print_external_data_node(writer: &mut dyn Write, node: &ExternalDataNode) ->
     Ok(())
 }
 
+/// print the document lines, the `marker` is `///` for
+/// statements and `//!` for module.
+fn print_document(
+    writer: &mut dyn Write,
+    document: &Option<String>,
+    marker: &str,
+) -> Result<(), Error> {
+    if let Some(document) = document {
+        for line in document.split('\n') {
+            if line.is_empty() {
+                writeln!(writer, "{}", marker)?;
+            } else {
+                writeln!(writer, "{} {}", marker, line)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn print_module_node(
     writer: &mut dyn Write,
     node: &ModuleNode,
     indent_chars: &str,
 ) -> Result<(), Error> {
+    if node.document.is_some() {
+        print_document(writer, &node.document, "//!")?;
+        writeln!(writer)?;
+    }
+
     if !node.imports.is_empty() {
         for item in &node.imports {
             print_import_node(writer, item)?;
@@ -279,8 +389,11 @@ fn format_expression_instruction(
     indent_level: usize,
 ) -> String {
     let pas = node.positional_args.iter().map(|item| match item {
-        ArgumentValue::Identifier(id) => id.to_owned(),
+        ArgumentValue::Identifier(id, _) => id.to_owned(),
         ArgumentValue::LiteralNumber(num) => format_literal_number(num),
+        ArgumentValue::LiteralData(data_value) => {
+            format_inline_data_value(data_value, &indent_chars.repeat(indent_level + 1))
+        }
         ArgumentValue::Expression(exp) => {
             format!(
                 "\n{}{}",
@@ -295,8 +408,10 @@ fn format_expression_instruction(
             "{}={}",
             &item.name,
             match &item.value {
-                ArgumentValue::Identifier(id) => id.to_owned(),
+                ArgumentValue::Identifier(id, _) => id.to_owned(),
                 ArgumentValue::LiteralNumber(num) => format_literal_number(num),
+                ArgumentValue::LiteralData(data_value) =>
+                    format_inline_data_value(data_value, &indent_chars.repeat(indent_level + 1)),
                 ArgumentValue::Expression(exp) =>
                     format_expression(exp, indent_chars, indent_level + 1),
             }
@@ -564,7 +679,7 @@ fn format_param_values(
 
 /// format:
 /// "(name0:data_type0, name1:data_type1, ...)"
-fn format_params(params: &[NamedParameter]) -> String {
+pub fn format_params(params: &[NamedParameter]) -> String {
     format!(
         "({})",
         params
@@ -575,7 +690,7 @@ fn format_params(params: &[NamedParameter]) -> String {
     )
 }
 
-fn format_import_params(params: &[OperandDataType]) -> String {
+pub fn format_import_params(params: &[OperandDataType]) -> String {
     format!(
         "({})",
         params
@@ -590,7 +705,7 @@ fn format_import_params(params: &[OperandDataType]) -> String {
 /// - ()
 /// - data_type
 /// - (data_type0, data_type1, ...)
-fn format_results(results: &[OperandDataType]) -> String {
+pub fn format_results(results: &[OperandDataType]) -> String {
     if results.is_empty() {
         "()".to_owned()
     } else if results.len() == 1 {
@@ -644,35 +759,40 @@ fn format_literal_number(num: &LiteralNumber) -> String {
             }
             s
         }
+        LiteralNumber::Char(v) => format_char(v),
     }
 }
 
-// fn print_char(writer: &mut dyn Write, ch: &char) -> Result<(), Error> {
-//     // escape single char
-//     let s = match ch {
-//         '\\' => "\\\\".to_owned(),
-//         '\'' => "\\'".to_owned(),
-//         '\t' => {
-//             // horizontal tabulation
-//             "\\t".to_owned()
-//         }
-//         '\r' => {
-//             // carriage return, jump to the beginning of the line (CR)
-//             "\\r".to_owned()
-//         }
-//         '\n' => {
-//             // new line/line feed (LF)
-//             "\\n".to_owned()
-//         }
-//         '\0' => {
-//             // null char
-//             "\\0".to_owned()
-//         }
-//         _ => ch.to_string(),
-//     };
-//
-//     write!(writer, "'{}'", s)
-// }
+fn format_char(ch: &char) -> String {
+    // escape single char
+    let s = match ch {
+        '\\' => "\\\\".to_owned(),
+        '\'' => "\\'".to_owned(),
+        '\t' => {
+            // horizontal tabulation
+            "\\t".to_owned()
+        }
+        '\r' => {
+            // carriage return, jump to the beginning of the line (CR)
+            "\\r".to_owned()
+        }
+        '\n' => {
+            // new line/line feed (LF)
+            "\\n".to_owned()
+        }
+        '\0' => {
+            // null char
+            "\\0".to_owned()
+        }
+        _ if ch.is_control() => {
+            // the other control chars are invisible, print the code point instead.
+            format!("\\u{{{:x}}}", *ch as u32)
+        }
+        _ => ch.to_string(),
+    };
+
+    format!("'{}'", s)
+}
 
 fn format_string(s: &str) -> String {
     format!(
@@ -746,6 +866,7 @@ fn format_data_value(data_value: &DataValue, indent_chars: &str, indent_level: u
         DataValue::I32(v) => format!("{}", v), // the default type for integer
         DataValue::F64(v) => format!("{}", v), // the default type for floating-point
         DataValue::F32(v) => format!("{}_f32", v),
+        DataValue::Char(v) => format_char(v),
         DataValue::ByteData(v) => format_hex_byte_data(v, &indent_chars.repeat(indent_level + 1)),
         DataValue::String(v) => format_string(v),
         DataValue::List(v) => format!(
@@ -760,6 +881,53 @@ fn format_data_value(data_value: &DataValue, indent_chars: &str, indent_level: u
                 .join("\n"),
             indent_chars.repeat(indent_level)
         ),
+        DataValue::Repetition(v, count) => format!(
+            "[{}; {}]",
+            format_data_value(v, indent_chars, indent_level),
+            count
+        ),
+        DataValue::Fill(v) => format!("fill({})", format_data_value(v, indent_chars, indent_level)),
+        DataValue::IncludeFile(include_file) => format_include_file(include_file),
+    }
+}
+
+fn format_include_file(include_file: &IncludeFile) -> String {
+    let mut args = vec![format_string(&include_file.file_path)];
+
+    if let Some(offset) = include_file.offset {
+        args.push(format!("offset={}", offset));
+    }
+
+    if let Some(length) = include_file.length {
+        args.push(format!("length={}", length));
+    }
+
+    let name = match include_file.file_type {
+        IncludeFileType::Binary => "include_bytes",
+        IncludeFileType::Text => "include_str",
+    };
+
+    format!("{}({})", name, args.join(", "))
+}
+
+/// format the data value which is used as the argument of instruction,
+/// the elements of list are written in a single line, e.g.
+///
+/// `data_load_i32_u([11, 13, 17], offset=4)`
+fn format_inline_data_value(data_value: &DataValue, indent_chars: &str) -> String {
+    match data_value {
+        DataValue::List(v) => format!(
+            "[{}]",
+            v.iter()
+                .map(|item| format_inline_data_value(item, indent_chars))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+        DataValue::Repetition(v, count) => {
+            format!("[{}; {}]", format_inline_data_value(v, indent_chars), count)
+        }
+        DataValue::ByteData(v) => format_hex_byte_data(v, indent_chars),
+        _ => format_data_value(data_value, indent_chars, 0),
     }
 }
 
@@ -782,15 +950,18 @@ mod tests {
 
     use crate::ast::{
         ArgumentValue, BlockNode, BreakNode, DataNode, DataSection, DataTypeValuePair, DataValue,
-        DeclareDataType, ExpressionNode, ExternalDataNode, ExternalFunctionNode, ExternalNode,
-        FixedDeclareDataType, FunctionNode, IfNode, ImportDataNode, ImportFunctionNode, ImportNode,
-        InstructionNode, LiteralNumber, LocalVariable, ModuleNode, NameValueParameter,
-        NamedArgument, NamedParameter, WhenNode,
+        DeclareDataType, DependencySource, ExpressionNode, ExternalDataNode, ExternalFunctionNode,
+        ExternalLibraryNode, ExternalNode, FixedDeclareDataType, FunctionNode, IfNode,
+        ImportDataNode, ImportFunctionNode, ImportGlobNode, ImportItemType, ImportModuleNode,
+        ImportNode, InstructionNode, LiteralNumber, LocalVariable, ModuleNode, NameValueParameter,
+        NamedArgument, NamedParameter, RemoteSource, WhenNode,
     };
 
+    use crate::location::Location;
     use crate::printer::{
-        print_external_data_node, print_external_function_node, print_function_node,
-        print_import_data_node, print_import_function_node, DEFAULT_INDENT_CHARS,
+        print_external_data_node, print_external_function_node, print_external_library_node,
+        print_function_node, print_import_data_node, print_import_function_node,
+        print_import_glob_node, print_import_module_node, DEFAULT_INDENT_CHARS,
     };
 
     use super::{print_data_node, print_to_string};
@@ -826,27 +997,61 @@ mod tests {
         };
 
         let f0 = ImportFunctionNode {
+            document: None,
+            public: false,
             full_name: "foo::bar".to_owned(),
-            params: vec![],
-            results: vec![],
+            signature: Some((vec![], vec![])),
             alias_name: None,
             from: None,
+            location: Location::default(),
         };
 
         assert_eq!(print(&f0), "import fn foo::bar() -> ()");
 
         let f1 = ImportFunctionNode {
+            document: None,
+            public: false,
             full_name: "foo::bar".to_owned(),
-            params: vec![OperandDataType::I32, OperandDataType::I32],
-            results: vec![OperandDataType::I64, OperandDataType::I64],
+            signature: Some((
+                vec![OperandDataType::I32, OperandDataType::I32],
+                vec![OperandDataType::I64, OperandDataType::I64],
+            )),
             alias_name: Some("baz".to_owned()),
             from: Some("mymod".to_owned()),
+            location: Location::default(),
         };
 
         assert_eq!(
             print(&f1),
             "import fn foo::bar(i32, i32) -> (i64, i64) as baz from mymod"
         );
+
+        let f2 = ImportFunctionNode {
+            document: None,
+            public: false,
+            full_name: "std::memory::copy".to_owned(),
+            signature: None,
+            alias_name: Some("copy".to_owned()),
+            from: None,
+            location: Location::default(),
+        };
+
+        assert_eq!(print(&f2), "import fn std::memory::copy as copy");
+
+        let f3 = ImportFunctionNode {
+            document: None,
+            public: true,
+            full_name: "foo::add".to_owned(),
+            signature: Some((
+                vec![OperandDataType::I32, OperandDataType::I32],
+                vec![OperandDataType::I32],
+            )),
+            alias_name: None,
+            from: None,
+            location: Location::default(),
+        };
+
+        assert_eq!(print(&f3), "pub import fn foo::add(i32, i32) -> i32");
     }
 
     #[test]
@@ -858,27 +1063,202 @@ mod tests {
         };
 
         let d0 = ImportDataNode {
-            data_section_type: DataSectionType::ReadWrite,
+            document: None,
+            public: false,
+            data_section_type: Some(DataSectionType::ReadWrite),
             full_name: "foo::count".to_owned(),
-            data_type: MemoryDataType::I32,
+            data_type: Some(MemoryDataType::I32),
             alias_name: None,
             from: None,
+            location: Location::default(),
         };
 
         assert_eq!(print(&d0), "import data foo::count type i32");
 
         let d1 = ImportDataNode {
-            data_section_type: DataSectionType::Uninit,
+            document: None,
+            public: false,
+            data_section_type: Some(DataSectionType::Uninit),
             full_name: "foo::got".to_owned(),
-            data_type: MemoryDataType::Bytes,
+            data_type: Some(MemoryDataType::Bytes),
             alias_name: Some("global_offset_table".to_owned()),
             from: Some("mymod".to_owned()),
+            location: Location::default(),
         };
 
         assert_eq!(
             print(&d1),
             "import uninit data foo::got type byte[] as global_offset_table from mymod"
         );
+
+        let d2 = ImportDataNode {
+            document: None,
+            public: false,
+            data_section_type: Some(DataSectionType::ReadOnly),
+            full_name: "foo::msg".to_owned(),
+            data_type: None,
+            alias_name: None,
+            from: None,
+            location: Location::default(),
+        };
+
+        assert_eq!(print(&d2), "import readonly data foo::msg");
+
+        // both the section and the data type are omitted
+        let d4 = ImportDataNode {
+            document: None,
+            public: false,
+            data_section_type: None,
+            full_name: "foo::bar".to_owned(),
+            data_type: None,
+            alias_name: None,
+            from: None,
+            location: Location::default(),
+        };
+
+        assert_eq!(print(&d4), "import data foo::bar");
+
+        let d3 = ImportDataNode {
+            document: None,
+            public: true,
+            data_section_type: Some(DataSectionType::ReadWrite),
+            full_name: "foo::count".to_owned(),
+            data_type: Some(MemoryDataType::I32),
+            alias_name: None,
+            from: None,
+            location: Location::default(),
+        };
+
+        assert_eq!(print(&d3), "pub import data foo::count type i32");
+    }
+
+    #[test]
+    fn test_print_import_module_node() {
+        let print = |e: &ImportModuleNode| {
+            let mut buf: Vec<u8> = vec![];
+            print_import_module_node(&mut buf, e).unwrap();
+            String::from_utf8(buf).unwrap()
+        };
+
+        let m0 = ImportModuleNode {
+            document: None,
+            name: "http_client".to_owned(),
+            source: DependencySource::Version("1.2.0".to_owned()),
+            location: Location::default(),
+        };
+
+        assert_eq!(print(&m0), "import module http_client version \"1.2.0\"");
+
+        let m1 = ImportModuleNode {
+            document: None,
+            name: "mymod".to_owned(),
+            source: DependencySource::Local("modules/mymod".to_owned()),
+            location: Location::default(),
+        };
+
+        assert_eq!(print(&m1), "import module mymod from \"modules/mymod\"");
+
+        let m2 = ImportModuleNode {
+            document: None,
+            name: "http_client".to_owned(),
+            source: DependencySource::Remote(RemoteSource {
+                url: "https://github.com/hemashushu/http_client.git".to_owned(),
+                reversion: "v1.0.0".to_owned(),
+            }),
+            location: Location::default(),
+        };
+
+        assert_eq!(
+            print(&m2),
+            "import module http_client remote \"https://github.com/hemashushu/http_client.git\" \"v1.0.0\""
+        );
+
+        let m3 = ImportModuleNode {
+            document: None,
+            name: "std".to_owned(),
+            source: DependencySource::Runtime,
+            location: Location::default(),
+        };
+
+        assert_eq!(print(&m3), "import module std runtime");
+    }
+
+    #[test]
+    fn test_print_import_glob_node() {
+        let print = |e: &ImportGlobNode| {
+            let mut buf: Vec<u8> = vec![];
+            print_import_glob_node(&mut buf, e).unwrap();
+            String::from_utf8(buf).unwrap()
+        };
+
+        let g0 = ImportGlobNode {
+            document: None,
+            public: false,
+            item_type: ImportItemType::Function,
+            module_path: "std::memory".to_owned(),
+            from: None,
+            location: Location::default(),
+        };
+
+        assert_eq!(print(&g0), "import fn std::memory::*");
+
+        let g1 = ImportGlobNode {
+            document: None,
+            public: false,
+            item_type: ImportItemType::Data,
+            module_path: "foo::bar".to_owned(),
+            from: Some("mymod".to_owned()),
+            location: Location::default(),
+        };
+
+        assert_eq!(print(&g1), "import data foo::bar::* from mymod");
+
+        let g2 = ImportGlobNode {
+            document: None,
+            public: true,
+            item_type: ImportItemType::Function,
+            module_path: "self::utils".to_owned(),
+            from: None,
+            location: Location::default(),
+        };
+
+        assert_eq!(print(&g2), "pub import fn self::utils::*");
+    }
+
+    #[test]
+    fn test_print_external_library_node() {
+        let print = |e: &ExternalLibraryNode| {
+            let mut buf: Vec<u8> = vec![];
+            print_external_library_node(&mut buf, e).unwrap();
+            String::from_utf8(buf).unwrap()
+        };
+
+        let l0 = ExternalLibraryNode {
+            document: None,
+            name: "libfoo".to_owned(),
+            source: DependencySource::Local("lib/libfoo.so".to_owned()),
+            location: Location::default(),
+        };
+
+        assert_eq!(print(&l0), "external library libfoo from \"lib/libfoo.so\"");
+
+        let l1 = ExternalLibraryNode {
+            document: None,
+            name: "libc".to_owned(),
+            source: DependencySource::System("libc.so.6".to_owned()),
+            location: Location::default(),
+        };
+
+        assert_eq!(print(&l1), "external library libc system \"libc.so.6\"");
+
+        let l2 = ExternalLibraryNode {
+            document: None,
+            name: "libbar".to_owned(),
+            source: DependencySource::Version("2.0.1".to_owned()),
+            location: Location::default(),
+        };
+
+        assert_eq!(print(&l2), "external library libbar version \"2.0.1\"");
     }
 
     #[test]
@@ -890,19 +1270,23 @@ mod tests {
         };
 
         let f0 = ExternalFunctionNode {
+            document: None,
             full_name: "libfoo::bar".to_owned(),
             params: vec![],
             result: None,
             alias_name: None,
+            location: Location::default(),
         };
 
         assert_eq!(print(&f0), "external fn libfoo::bar() -> ()");
 
         let f1 = ExternalFunctionNode {
+            document: None,
             full_name: "libfoo::bar".to_owned(),
             params: vec![OperandDataType::I32, OperandDataType::I32],
             result: Some(OperandDataType::I64),
             alias_name: Some("baz".to_owned()),
+            location: Location::default(),
         };
 
         assert_eq!(
@@ -920,17 +1304,21 @@ mod tests {
         };
 
         let d0 = ExternalDataNode {
+            document: None,
             full_name: "libfoo::count".to_owned(),
             data_type: MemoryDataType::I32,
             alias_name: None,
+            location: Location::default(),
         };
 
         assert_eq!(print(&d0), "external data libfoo::count type i32");
 
         let d1 = ExternalDataNode {
+            document: None,
             full_name: "libfoo::got".to_owned(),
             data_type: MemoryDataType::Bytes,
             alias_name: Some("global_offset_table".to_owned()),
+            location: Location::default(),
         };
 
         assert_eq!(
@@ -948,60 +1336,71 @@ mod tests {
         };
 
         let node0 = DataNode {
+            document: None,
             public: false,
             name: "foo".to_owned(),
             data_section: DataSection::ReadOnly(DataTypeValuePair {
                 data_type: DeclareDataType::I32,
                 value: DataValue::I32(123),
             }),
+            location: Location::default(),
         };
 
         assert_eq!(print(&node0), "readonly data foo:i32 = 123");
 
         // test byte array data type
         let node1 = DataNode {
+            document: None,
             public: true,
             name: "foo".to_owned(),
             data_section: DataSection::ReadOnly(DataTypeValuePair {
                 data_type: DeclareDataType::FixedBytes(32, None),
                 value: DataValue::String("hello".to_owned()),
             }),
+            location: Location::default(),
         };
 
         assert_eq!(print(&node1), "pub readonly data foo:byte[32] = \"hello\"");
 
         // test byte array data type with unspecific length
         let node2 = DataNode {
+            document: None,
             public: true,
             name: "foo".to_owned(),
             data_section: DataSection::ReadWrite(DataTypeValuePair {
                 data_type: DeclareDataType::Bytes(None),
                 value: DataValue::String("world".to_owned()),
             }),
+            location: Location::default(),
         };
 
         assert_eq!(print(&node2), "pub data foo:byte[] = \"world\"");
 
         // test uninit
         let node3 = DataNode {
+            document: None,
             public: false,
             name: "got".to_owned(),
             data_section: DataSection::Uninit(FixedDeclareDataType::FixedBytes(1024, None)),
+            location: Location::default(),
         };
 
         assert_eq!(print(&node3), "uninit data got:byte[1024]");
 
         // test byte array align
         let node4 = DataNode {
+            document: None,
             public: false,
             name: "foo".to_owned(),
             data_section: DataSection::Uninit(FixedDeclareDataType::FixedBytes(1024, Some(8))),
+            location: Location::default(),
         };
 
         assert_eq!(print(&node4), "uninit data foo:byte[1024, align=8]");
 
         // test hex byte data
         let node5 = DataNode {
+            document: None,
             public: true,
             name: "foo".to_owned(),
             data_section: DataSection::ReadWrite(DataTypeValuePair {
@@ -1011,6 +1410,7 @@ mod tests {
                     0x61, 0x67, 0x71, 0x73, 0x79,
                 ]),
             }),
+            location: Location::default(),
         };
 
         assert_eq!(
@@ -1022,6 +1422,7 @@ mod tests {
 
         // test data value list
         let node6 = DataNode {
+            document: None,
             public: false,
             name: "bar".to_owned(),
             data_section: DataSection::ReadWrite(DataTypeValuePair {
@@ -1036,6 +1437,7 @@ mod tests {
                     DataValue::List(vec![DataValue::I8(211), DataValue::I8(223)]),
                 ]),
             }),
+            location: Location::default(),
         };
 
         assert_eq!(
@@ -1054,6 +1456,40 @@ data bar:byte[align=4] = [
     ]
 ]"
         );
+
+        // test data value repetition
+        let node7 = DataNode {
+            document: None,
+            public: false,
+            name: "table".to_owned(),
+            data_section: DataSection::ReadOnly(DataTypeValuePair {
+                data_type: DeclareDataType::Bytes(None),
+                value: DataValue::Repetition(
+                    Box::new(DataValue::Repetition(Box::new(DataValue::I8(0)), 4)),
+                    16,
+                ),
+            }),
+            location: Location::default(),
+        };
+
+        assert_eq!(
+            print(&node7),
+            "readonly data table:byte[] = [[0_i8; 4]; 16]"
+        );
+
+        // test data value fill
+        let node8 = DataNode {
+            document: None,
+            public: false,
+            name: "buf".to_owned(),
+            data_section: DataSection::ReadWrite(DataTypeValuePair {
+                data_type: DeclareDataType::FixedBytes(4096, None),
+                value: DataValue::Fill(Box::new(DataValue::I8(0xcc))),
+            }),
+            location: Location::default(),
+        };
+
+        assert_eq!(print(&node8), "data buf:byte[4096] = fill(204_i8)");
     }
 
     #[test]
@@ -1065,6 +1501,7 @@ data bar:byte[align=4] = [
         };
 
         let node0 = FunctionNode {
+            document: None,
             public: false,
             name: "foo".to_owned(),
             params: vec![],
@@ -1072,7 +1509,10 @@ data bar:byte[align=4] = [
             locals: vec![],
             body: Box::new(ExpressionNode::Instruction(InstructionNode {
                 name: "local_load_i64".to_owned(),
-                positional_args: vec![ArgumentValue::Identifier("left".to_owned())],
+                positional_args: vec![ArgumentValue::Identifier(
+                    "left".to_owned(),
+                    Location::default(),
+                )],
                 named_args: vec![
                     NamedArgument {
                         name: "rindex".to_owned(),
@@ -1083,7 +1523,10 @@ data bar:byte[align=4] = [
                         value: ArgumentValue::LiteralNumber(LiteralNumber::I16(4)),
                     },
                 ],
+                location: Location::default(),
             })),
+            location: Location::default(),
+            statement_location: Location::default(),
         };
 
         assert_eq!(
@@ -1095,16 +1538,19 @@ fn foo() -> ()
 
         // test params, results
         let node1 = FunctionNode {
+            document: None,
             public: true,
             name: "add".to_owned(),
             params: vec![
                 NamedParameter {
                     name: "left".to_owned(),
                     data_type: OperandDataType::I32,
+                    location: Location::default(),
                 },
                 NamedParameter {
                     name: "right".to_owned(),
                     data_type: OperandDataType::I32,
+                    location: Location::default(),
                 },
             ],
             results: vec![OperandDataType::I32],
@@ -1115,8 +1561,12 @@ fn foo() -> ()
                     ArgumentValue::Expression(Box::new(ExpressionNode::Instruction(
                         InstructionNode {
                             name: "local_load_i32".to_owned(),
-                            positional_args: vec![ArgumentValue::Identifier("left".to_owned())],
+                            positional_args: vec![ArgumentValue::Identifier(
+                                "left".to_owned(),
+                                Location::default(),
+                            )],
                             named_args: vec![],
+                            location: Location::default(),
                         },
                     ))),
                     ArgumentValue::Expression(Box::new(ExpressionNode::Instruction(
@@ -1129,17 +1579,23 @@ fn foo() -> ()
                                         name: "local_load_i32".to_owned(),
                                         positional_args: vec![ArgumentValue::Identifier(
                                             "right".to_owned(),
+                                            Location::default(),
                                         )],
                                         named_args: vec![],
+                                        location: Location::default(),
                                     },
                                 ))),
                             ],
                             named_args: vec![],
+                            location: Location::default(),
                         },
                     ))),
                 ],
                 named_args: vec![],
+                location: Location::default(),
             })),
+            location: Location::default(),
+            statement_location: Location::default(),
         };
 
         assert_eq!(
@@ -1154,6 +1610,7 @@ pub fn add(left:i32, right:i32) -> i32
 
         // test multiple results and local variables
         let node2 = FunctionNode {
+            document: None,
             public: false,
             name: "hello".to_owned(),
             params: vec![],
@@ -1162,21 +1619,27 @@ pub fn add(left:i32, right:i32) -> i32
                 LocalVariable {
                     name: "foo".to_owned(),
                     data_type: FixedDeclareDataType::I32,
+                    location: Location::default(),
                 },
                 LocalVariable {
                     name: "bar".to_owned(),
                     data_type: FixedDeclareDataType::FixedBytes(8, None),
+                    location: Location::default(),
                 },
                 LocalVariable {
                     name: "baz".to_owned(),
                     data_type: FixedDeclareDataType::FixedBytes(24, Some(4)),
+                    location: Location::default(),
                 },
             ],
             body: Box::new(ExpressionNode::Instruction(InstructionNode {
                 name: "end".to_owned(),
                 positional_args: vec![],
                 named_args: vec![],
+                location: Location::default(),
             })),
+            location: Location::default(),
+            statement_location: Location::default(),
         };
 
         assert_eq!(
@@ -1197,6 +1660,7 @@ fn hello() -> (i32, i64)
         };
 
         let node0 = FunctionNode {
+            document: None,
             public: false,
             name: "foo".to_owned(),
             params: vec![],
@@ -1207,11 +1671,12 @@ fn hello() -> (i32, i64)
                     name: "nop".to_owned(),
                     positional_args: vec![],
                     named_args: vec![],
+                    location: Location::default(),
                 }),
                 ExpressionNode::Instruction(InstructionNode {
                     name: "local_store_i32".to_owned(),
                     positional_args: vec![
-                        ArgumentValue::Identifier("left".to_owned()),
+                        ArgumentValue::Identifier("left".to_owned(), Location::default()),
                         ArgumentValue::Expression(Box::new(ExpressionNode::Instruction(
                             InstructionNode {
                                 name: "imm_i32".to_owned(),
@@ -1219,15 +1684,17 @@ fn hello() -> (i32, i64)
                                     LiteralNumber::I32(123),
                                 )],
                                 named_args: vec![],
+                                location: Location::default(),
                             },
                         ))),
                     ],
                     named_args: vec![],
+                    location: Location::default(),
                 }),
                 ExpressionNode::Instruction(InstructionNode {
                     name: "local_store_i32".to_owned(),
                     positional_args: vec![
-                        ArgumentValue::Identifier("right".to_owned()),
+                        ArgumentValue::Identifier("right".to_owned(), Location::default()),
                         ArgumentValue::Expression(Box::new(ExpressionNode::Instruction(
                             InstructionNode {
                                 name: "add_i32".to_owned(),
@@ -1239,6 +1706,7 @@ fn hello() -> (i32, i64)
                                                 LiteralNumber::I32(123),
                                             )],
                                             named_args: vec![],
+                                            location: Location::default(),
                                         }),
                                     )),
                                     ArgumentValue::Expression(Box::new(
@@ -1248,16 +1716,21 @@ fn hello() -> (i32, i64)
                                                 LiteralNumber::I32(123),
                                             )],
                                             named_args: vec![],
+                                            location: Location::default(),
                                         }),
                                     )),
                                 ],
                                 named_args: vec![],
+                                location: Location::default(),
                             },
                         ))),
                     ],
                     named_args: vec![],
+                    location: Location::default(),
                 }),
             ])),
+            location: Location::default(),
+            statement_location: Location::default(),
         };
 
         assert_eq!(
@@ -1277,6 +1750,7 @@ fn foo() -> ()
 
         // test nested group
         let node1 = FunctionNode {
+            document: None,
             public: false,
             name: "foo".to_owned(),
             params: vec![],
@@ -1287,13 +1761,17 @@ fn foo() -> ()
                     name: "nop".to_owned(),
                     positional_args: vec![],
                     named_args: vec![],
+                    location: Location::default(),
                 }),
                 ExpressionNode::Group(vec![ExpressionNode::Instruction(InstructionNode {
                     name: "nop".to_owned(),
                     positional_args: vec![],
                     named_args: vec![],
+                    location: Location::default(),
                 })]),
             ])),
+            location: Location::default(),
+            statement_location: Location::default(),
         };
 
         assert_eq!(
@@ -1318,6 +1796,7 @@ fn foo() -> ()
         };
 
         let node0 = FunctionNode {
+            document: None,
             public: false,
             name: "foo".to_owned(),
             params: vec![],
@@ -1328,14 +1807,18 @@ fn foo() -> ()
                     name: "imm_i32".to_owned(),
                     positional_args: vec![ArgumentValue::LiteralNumber(LiteralNumber::I32(1))],
                     named_args: vec![],
+                    location: Location::default(),
                 })),
                 locals: vec![],
                 consequence: Box::new(ExpressionNode::Instruction(InstructionNode {
                     name: "nop".to_owned(),
                     positional_args: vec![],
                     named_args: vec![],
+                    location: Location::default(),
                 })),
             })),
+            location: Location::default(),
+            statement_location: Location::default(),
         };
 
         assert_eq!(
@@ -1349,6 +1832,7 @@ fn foo() -> ()
 
         // test `when` with multiple layers instructions
         let node1 = FunctionNode {
+            document: None,
             public: false,
             name: "foo".to_owned(),
             params: vec![],
@@ -1360,28 +1844,40 @@ fn foo() -> ()
                     positional_args: vec![ArgumentValue::Expression(Box::new(
                         ExpressionNode::Instruction(InstructionNode {
                             name: "local_load_i32".to_owned(),
-                            positional_args: vec![ArgumentValue::Identifier("a".to_owned())],
+                            positional_args: vec![ArgumentValue::Identifier(
+                                "a".to_owned(),
+                                Location::default(),
+                            )],
                             named_args: vec![],
+                            location: Location::default(),
                         }),
                     ))],
                     named_args: vec![],
+                    location: Location::default(),
                 })),
                 locals: vec![],
                 consequence: Box::new(ExpressionNode::Instruction(InstructionNode {
                     name: "data_store_i32".to_owned(),
                     positional_args: vec![
-                        ArgumentValue::Identifier("b".to_owned()),
+                        ArgumentValue::Identifier("b".to_owned(), Location::default()),
                         ArgumentValue::Expression(Box::new(ExpressionNode::Instruction(
                             InstructionNode {
                                 name: "local_load_i32".to_owned(),
-                                positional_args: vec![ArgumentValue::Identifier("a".to_owned())],
+                                positional_args: vec![ArgumentValue::Identifier(
+                                    "a".to_owned(),
+                                    Location::default(),
+                                )],
                                 named_args: vec![],
+                                location: Location::default(),
                             },
                         ))),
                     ],
                     named_args: vec![],
+                    location: Location::default(),
                 })),
             })),
+            location: Location::default(),
+            statement_location: Location::default(),
         };
 
         assert_eq!(
@@ -1397,6 +1893,7 @@ fn foo() -> ()
 
         // test `when` with local variables
         let node2 = FunctionNode {
+            document: None,
             public: false,
             name: "foo".to_owned(),
             params: vec![],
@@ -1407,27 +1904,34 @@ fn foo() -> ()
                     name: "imm_i32".to_owned(),
                     positional_args: vec![ArgumentValue::LiteralNumber(LiteralNumber::I32(1))],
                     named_args: vec![],
+                    location: Location::default(),
                 })),
                 locals: vec![
                     LocalVariable {
                         name: "foo".to_owned(),
                         data_type: FixedDeclareDataType::I32,
+                        location: Location::default(),
                     },
                     LocalVariable {
                         name: "bar".to_owned(),
                         data_type: FixedDeclareDataType::FixedBytes(8, None),
+                        location: Location::default(),
                     },
                     LocalVariable {
                         name: "baz".to_owned(),
                         data_type: FixedDeclareDataType::FixedBytes(24, Some(4)),
+                        location: Location::default(),
                     },
                 ],
                 consequence: Box::new(ExpressionNode::Instruction(InstructionNode {
                     name: "nop".to_owned(),
                     positional_args: vec![],
                     named_args: vec![],
+                    location: Location::default(),
                 })),
             })),
+            location: Location::default(),
+            statement_location: Location::default(),
         };
 
         assert_eq!(
@@ -1442,6 +1946,7 @@ fn foo() -> ()
 
         // test 'when' with 'group'
         let node3 = FunctionNode {
+            document: None,
             public: false,
             name: "foo".to_owned(),
             params: vec![],
@@ -1452,6 +1957,7 @@ fn foo() -> ()
                     name: "imm_i32".to_owned(),
                     positional_args: vec![ArgumentValue::LiteralNumber(LiteralNumber::I32(1))],
                     named_args: vec![],
+                    location: Location::default(),
                 })),
                 locals: vec![],
                 consequence: Box::new(ExpressionNode::Group(vec![
@@ -1459,25 +1965,31 @@ fn foo() -> ()
                         name: "nop".to_owned(),
                         positional_args: vec![],
                         named_args: vec![],
+                        location: Location::default(),
                     }),
                     ExpressionNode::Instruction(InstructionNode {
                         name: "local_store_i32".to_owned(),
                         positional_args: vec![
-                            ArgumentValue::Identifier("left".to_owned()),
+                            ArgumentValue::Identifier("left".to_owned(), Location::default()),
                             ArgumentValue::Expression(Box::new(ExpressionNode::Instruction(
                                 InstructionNode {
                                     name: "local_load_i32".to_owned(),
                                     positional_args: vec![ArgumentValue::Identifier(
                                         "right".to_owned(),
+                                        Location::default(),
                                     )],
                                     named_args: vec![],
+                                    location: Location::default(),
                                 },
                             ))),
                         ],
                         named_args: vec![],
+                        location: Location::default(),
                     }),
                 ])),
             })),
+            location: Location::default(),
+            statement_location: Location::default(),
         };
 
         assert_eq!(
@@ -1503,6 +2015,7 @@ fn foo() -> ()
         };
 
         let node0 = FunctionNode {
+            document: None,
             public: false,
             name: "foo".to_owned(),
             params: vec![],
@@ -1516,16 +2029,21 @@ fn foo() -> ()
                     positional_args: vec![ArgumentValue::Expression(Box::new(
                         ExpressionNode::Instruction(InstructionNode {
                             name: "local_load_i32".to_owned(),
-                            positional_args: vec![ArgumentValue::Identifier("in".to_owned())],
+                            positional_args: vec![ArgumentValue::Identifier(
+                                "in".to_owned(),
+                                Location::default(),
+                            )],
                             named_args: vec![],
+                            location: Location::default(),
                         }),
                     ))],
                     named_args: vec![],
+                    location: Location::default(),
                 })),
                 consequence: Box::new(ExpressionNode::Instruction(InstructionNode {
                     name: "local_store_i32".to_owned(),
                     positional_args: vec![
-                        ArgumentValue::Identifier("out".to_owned()),
+                        ArgumentValue::Identifier("out".to_owned(), Location::default()),
                         ArgumentValue::Expression(Box::new(ExpressionNode::Instruction(
                             InstructionNode {
                                 name: "imm_i32".to_owned(),
@@ -1533,15 +2051,17 @@ fn foo() -> ()
                                     LiteralNumber::I32(11),
                                 )],
                                 named_args: vec![],
+                                location: Location::default(),
                             },
                         ))),
                     ],
                     named_args: vec![],
+                    location: Location::default(),
                 })),
                 alternative: Box::new(ExpressionNode::Instruction(InstructionNode {
                     name: "local_store_i32".to_owned(),
                     positional_args: vec![
-                        ArgumentValue::Identifier("out".to_owned()),
+                        ArgumentValue::Identifier("out".to_owned(), Location::default()),
                         ArgumentValue::Expression(Box::new(ExpressionNode::Instruction(
                             InstructionNode {
                                 name: "imm_i32".to_owned(),
@@ -1549,12 +2069,16 @@ fn foo() -> ()
                                     LiteralNumber::I32(13),
                                 )],
                                 named_args: vec![],
+                                location: Location::default(),
                             },
                         ))),
                     ],
                     named_args: vec![],
+                    location: Location::default(),
                 })),
             })),
+            location: Location::default(),
+            statement_location: Location::default(),
         };
 
         assert_eq!(
@@ -1572,6 +2096,7 @@ fn foo() -> ()
 
         // test `if` with params and return value
         let node1 = FunctionNode {
+            document: None,
             public: false,
             name: "foo".to_owned(),
             params: vec![],
@@ -1593,18 +2118,23 @@ fn foo() -> ()
                     name: "imm_i32".to_owned(),
                     positional_args: vec![ArgumentValue::LiteralNumber(LiteralNumber::I32(11))],
                     named_args: vec![],
+                    location: Location::default(),
                 })),
                 consequence: Box::new(ExpressionNode::Instruction(InstructionNode {
                     name: "imm_i32".to_owned(),
                     positional_args: vec![ArgumentValue::LiteralNumber(LiteralNumber::I32(13))],
                     named_args: vec![],
+                    location: Location::default(),
                 })),
                 alternative: Box::new(ExpressionNode::Instruction(InstructionNode {
                     name: "imm_i32".to_owned(),
                     positional_args: vec![ArgumentValue::LiteralNumber(LiteralNumber::I32(17))],
                     named_args: vec![],
+                    location: Location::default(),
                 })),
             })),
+            location: Location::default(),
+            statement_location: Location::default(),
         };
 
         assert_eq!(
@@ -1619,6 +2149,7 @@ fn foo() -> ()
 
         // test `if` with multiple return multiple values
         let node2 = FunctionNode {
+            document: None,
             public: false,
             name: "foo".to_owned(),
             params: vec![],
@@ -1631,18 +2162,23 @@ fn foo() -> ()
                     name: "imm_i32".to_owned(),
                     positional_args: vec![ArgumentValue::LiteralNumber(LiteralNumber::I32(11))],
                     named_args: vec![],
+                    location: Location::default(),
                 })),
                 consequence: Box::new(ExpressionNode::Instruction(InstructionNode {
                     name: "nop".to_owned(),
                     positional_args: vec![],
                     named_args: vec![],
+                    location: Location::default(),
                 })),
                 alternative: Box::new(ExpressionNode::Instruction(InstructionNode {
                     name: "nop".to_owned(),
                     positional_args: vec![],
                     named_args: vec![],
+                    location: Location::default(),
                 })),
             })),
+            location: Location::default(),
+            statement_location: Location::default(),
         };
 
         assert_eq!(
@@ -1818,6 +2354,7 @@ fn foo() -> ()
         };
 
         let node0 = FunctionNode {
+            document: None,
             public: false,
             name: "foo".to_owned(),
             params: vec![],
@@ -1830,7 +2367,7 @@ fn foo() -> ()
                 body: Box::new(ExpressionNode::Instruction(InstructionNode {
                     name: "local_store_i32".to_owned(),
                     positional_args: vec![
-                        ArgumentValue::Identifier("out".to_owned()),
+                        ArgumentValue::Identifier("out".to_owned(), Location::default()),
                         ArgumentValue::Expression(Box::new(ExpressionNode::Instruction(
                             InstructionNode {
                                 name: "imm_i32".to_owned(),
@@ -1838,12 +2375,16 @@ fn foo() -> ()
                                     LiteralNumber::I32(11),
                                 )],
                                 named_args: vec![],
+                                location: Location::default(),
                             },
                         ))),
                     ],
                     named_args: vec![],
+                    location: Location::default(),
                 })),
             })),
+            location: Location::default(),
+            statement_location: Location::default(),
         };
 
         assert_eq!(
@@ -1857,6 +2398,7 @@ fn foo() -> ()
 
         // test 'block' with params, results and local variablers
         let node1 = FunctionNode {
+            document: None,
             public: false,
             name: "foo".to_owned(),
             params: vec![],
@@ -1873,7 +2415,9 @@ fn foo() -> ()
                                 LiteralNumber::I32(11),
                             )],
                             named_args: vec![],
+                            location: Location::default(),
                         })),
+                        location: Location::default(),
                     },
                     NameValueParameter {
                         name: "right".to_owned(),
@@ -1884,7 +2428,9 @@ fn foo() -> ()
                                 LiteralNumber::I32(13),
                             )],
                             named_args: vec![],
+                            location: Location::default(),
                         })),
+                        location: Location::default(),
                     },
                 ],
                 results: vec![OperandDataType::I32],
@@ -1892,18 +2438,23 @@ fn foo() -> ()
                     LocalVariable {
                         name: "abc".to_owned(),
                         data_type: FixedDeclareDataType::I32,
+                        location: Location::default(),
                     },
                     LocalVariable {
                         name: "def".to_owned(),
                         data_type: FixedDeclareDataType::FixedBytes(32, None),
+                        location: Location::default(),
                     },
                 ],
                 body: Box::new(ExpressionNode::Instruction(InstructionNode {
                     name: "nop".to_owned(),
                     positional_args: vec![],
                     named_args: vec![],
+                    location: Location::default(),
                 })),
             })),
+            location: Location::default(),
+            statement_location: Location::default(),
         };
 
         assert_eq!(
@@ -1917,6 +2468,7 @@ fn foo() -> ()
 
         // test 'block' with 'group' and complex param value
         let node2 = FunctionNode {
+            document: None,
             public: false,
             name: "foo".to_owned(),
             params: vec![],
@@ -1929,30 +2481,35 @@ fn foo() -> ()
                     value: Box::new(ExpressionNode::Instruction(InstructionNode {
                         name: "data_load_extend_i32_s".to_owned(),
                         positional_args: vec![
-                            ArgumentValue::Identifier("buf".to_owned()),
+                            ArgumentValue::Identifier("buf".to_owned(), Location::default()),
                             ArgumentValue::Expression(Box::new(ExpressionNode::Instruction(
                                 InstructionNode {
                                     name: "local_load_i32_s".to_owned(),
                                     positional_args: vec![ArgumentValue::Identifier(
                                         "off".to_owned(),
+                                        Location::default(),
                                     )],
                                     named_args: vec![],
+                                    location: Location::default(),
                                 },
                             ))),
                         ],
                         named_args: vec![],
+                        location: Location::default(),
                     })),
+                    location: Location::default(),
                 }],
                 results: vec![],
                 locals: vec![LocalVariable {
                     name: "temp".to_owned(),
                     data_type: FixedDeclareDataType::I32,
+                    location: Location::default(),
                 }],
                 body: Box::new(ExpressionNode::Group(vec![
                     ExpressionNode::Instruction(InstructionNode {
                         name: "local_store_i32".to_owned(),
                         positional_args: vec![
-                            ArgumentValue::Identifier("abc".to_owned()),
+                            ArgumentValue::Identifier("abc".to_owned(), Location::default()),
                             ArgumentValue::Expression(Box::new(ExpressionNode::Instruction(
                                 InstructionNode {
                                     name: "imm_i32".to_owned(),
@@ -1960,15 +2517,17 @@ fn foo() -> ()
                                         LiteralNumber::I32(11),
                                     )],
                                     named_args: vec![],
+                                    location: Location::default(),
                                 },
                             ))),
                         ],
                         named_args: vec![],
+                        location: Location::default(),
                     }),
                     ExpressionNode::Instruction(InstructionNode {
                         name: "local_store_i32".to_owned(),
                         positional_args: vec![
-                            ArgumentValue::Identifier("def".to_owned()),
+                            ArgumentValue::Identifier("def".to_owned(), Location::default()),
                             ArgumentValue::Expression(Box::new(ExpressionNode::Instruction(
                                 InstructionNode {
                                     name: "imm_i32".to_owned(),
@@ -1976,13 +2535,17 @@ fn foo() -> ()
                                         LiteralNumber::I32(31),
                                     )],
                                     named_args: vec![],
+                                    location: Location::default(),
                                 },
                             ))),
                         ],
                         named_args: vec![],
+                        location: Location::default(),
                     }),
                 ])),
             })),
+            location: Location::default(),
+            statement_location: Location::default(),
         };
 
         assert_eq!(
@@ -2010,6 +2573,7 @@ fn foo() -> ()
         };
 
         let node0 = FunctionNode {
+            document: None,
             public: false,
             name: "foo".to_owned(),
             params: vec![],
@@ -2021,11 +2585,13 @@ fn foo() -> ()
                         name: "imm_i32".to_owned(),
                         positional_args: vec![ArgumentValue::LiteralNumber(LiteralNumber::I32(11))],
                         named_args: vec![],
+                        location: Location::default(),
                     }),
                     ExpressionNode::Instruction(InstructionNode {
                         name: "imm_i32".to_owned(),
                         positional_args: vec![ArgumentValue::LiteralNumber(LiteralNumber::I32(13))],
                         named_args: vec![],
+                        location: Location::default(),
                     }),
                 ])),
                 ExpressionNode::Break(BreakNode::BreakFn(vec![
@@ -2033,14 +2599,18 @@ fn foo() -> ()
                         name: "imm_i32".to_owned(),
                         positional_args: vec![ArgumentValue::LiteralNumber(LiteralNumber::I32(23))],
                         named_args: vec![],
+                        location: Location::default(),
                     }),
                     ExpressionNode::Instruction(InstructionNode {
                         name: "imm_i32".to_owned(),
                         positional_args: vec![ArgumentValue::LiteralNumber(LiteralNumber::I32(29))],
                         named_args: vec![],
+                        location: Location::default(),
                     }),
                 ])),
             ])),
+            location: Location::default(),
+            statement_location: Location::default(),
         };
 
         assert_eq!(
@@ -2061,6 +2631,7 @@ fn foo() -> ()
 
         // test 'recur'
         let node1 = FunctionNode {
+            document: None,
             public: false,
             name: "foo".to_owned(),
             params: vec![],
@@ -2072,11 +2643,13 @@ fn foo() -> ()
                         name: "imm_i32".to_owned(),
                         positional_args: vec![ArgumentValue::LiteralNumber(LiteralNumber::I32(11))],
                         named_args: vec![],
+                        location: Location::default(),
                     }),
                     ExpressionNode::Instruction(InstructionNode {
                         name: "imm_i32".to_owned(),
                         positional_args: vec![ArgumentValue::LiteralNumber(LiteralNumber::I32(13))],
                         named_args: vec![],
+                        location: Location::default(),
                     }),
                 ])),
                 ExpressionNode::Recur(BreakNode::BreakFn(vec![
@@ -2084,14 +2657,18 @@ fn foo() -> ()
                         name: "imm_i32".to_owned(),
                         positional_args: vec![ArgumentValue::LiteralNumber(LiteralNumber::I32(23))],
                         named_args: vec![],
+                        location: Location::default(),
                     }),
                     ExpressionNode::Instruction(InstructionNode {
                         name: "imm_i32".to_owned(),
                         positional_args: vec![ArgumentValue::LiteralNumber(LiteralNumber::I32(29))],
                         named_args: vec![],
+                        location: Location::default(),
                     }),
                 ])),
             ])),
+            location: Location::default(),
+            statement_location: Location::default(),
         };
 
         assert_eq!(
@@ -2114,6 +2691,7 @@ fn foo() -> ()
     #[test]
     fn test_print_module_node() {
         let node = ModuleNode {
+            document: None,
             // full_name: "foo".to_owned(),
             // uses: vec![
             //     UseNode {
@@ -2127,43 +2705,58 @@ fn foo() -> ()
             // ],
             imports: vec![
                 ImportNode::Function(ImportFunctionNode {
+                    document: None,
+                    public: false,
                     full_name: "std::abc".to_owned(),
-                    params: vec![OperandDataType::I32, OperandDataType::I64],
-                    results: vec![OperandDataType::I64],
+                    signature: Some((
+                        vec![OperandDataType::I32, OperandDataType::I64],
+                        vec![OperandDataType::I64],
+                    )),
                     alias_name: None,
                     from: None,
+                    location: Location::default(),
                 }),
                 ImportNode::Data(ImportDataNode {
-                    data_section_type: DataSectionType::ReadOnly,
+                    document: None,
+                    public: false,
+                    data_section_type: Some(DataSectionType::ReadOnly),
                     full_name: "std::def".to_owned(),
-                    data_type: MemoryDataType::I32,
+                    data_type: Some(MemoryDataType::I32),
                     alias_name: Some("xyz".to_owned()),
                     from: Some("mymod".to_owned()),
+                    location: Location::default(),
                 }),
             ],
             externals: vec![
                 ExternalNode::Function(ExternalFunctionNode {
+                    document: None,
                     full_name: "liba::abc".to_owned(),
                     params: vec![OperandDataType::I32, OperandDataType::I64],
                     result: Some(OperandDataType::I64),
                     alias_name: None,
+                    location: Location::default(),
                 }),
                 ExternalNode::Data(ExternalDataNode {
+                    document: None,
                     full_name: "libb::def".to_owned(),
                     data_type: MemoryDataType::I32,
                     alias_name: Some("xyz".to_owned()),
+                    location: Location::default(),
                 }),
             ],
             datas: vec![
                 DataNode {
+                    document: None,
                     public: false,
                     name: "count".to_owned(),
                     data_section: DataSection::ReadWrite(DataTypeValuePair {
                         data_type: DeclareDataType::I32,
                         value: DataValue::I32(37),
                     }),
+                    location: Location::default(),
                 },
                 DataNode {
+                    document: None,
                     public: true,
                     name: "plt".to_owned(),
                     data_section: DataSection::ReadOnly(DataTypeValuePair {
@@ -2175,20 +2768,24 @@ fn foo() -> ()
                             DataValue::I8(53),
                         ]),
                     }),
+                    location: Location::default(),
                 },
             ],
             functions: vec![
                 FunctionNode {
+                    document: None,
                     public: false,
                     name: "add".to_owned(),
                     params: vec![
                         NamedParameter {
                             name: "left".to_owned(),
                             data_type: OperandDataType::I32,
+                            location: Location::default(),
                         },
                         NamedParameter {
                             name: "right".to_owned(),
                             data_type: OperandDataType::I32,
+                            location: Location::default(),
                         },
                     ],
                     results: vec![OperandDataType::I32],
@@ -2197,9 +2794,13 @@ fn foo() -> ()
                         name: "nop".to_owned(),
                         positional_args: vec![],
                         named_args: vec![],
+                        location: Location::default(),
                     })),
+                    location: Location::default(),
+                    statement_location: Location::default(),
                 },
                 FunctionNode {
+                    document: None,
                     public: true,
                     name: "entry".to_owned(),
                     params: vec![],
@@ -2207,12 +2808,16 @@ fn foo() -> ()
                     locals: vec![LocalVariable {
                         name: "temp".to_owned(),
                         data_type: FixedDeclareDataType::I32,
+                        location: Location::default(),
                     }],
                     body: Box::new(ExpressionNode::Instruction(InstructionNode {
                         name: "nop".to_owned(),
                         positional_args: vec![],
                         named_args: vec![],
+                        location: Location::default(),
                     })),
+                    location: Location::default(),
+                    statement_location: Location::default(),
                 },
             ],
         };
@@ -0,0 +1,792 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the symbol analysis of a source file.
+//
+// the declarations and references are collected from the AST, the nodes
+// carry the locations of the identifiers. when the source file contains
+// syntax errors, they are collected from the tokens instead, see the
+// module `fallback`.
+//
+// - the declarations: the functions, data, imported/external functions and
+//   data, import modules, external libraries and local variables.
+// - the references: the identifier arguments of the instructions, e.g.
+//   `call(foo, ...)`, `data_load_i32_u(bar)` and `local_load_i64(baz)`.
+
+use anc_assembly::{
+    ast::{
+        ArgumentValue, BreakNode, DataNode, DataSection, ExpressionNode, ExternalNode,
+        FunctionNode, ImportNode, LocalVariable, ModuleNode,
+    },
+    location::Location,
+    printer::{format_dependency_source, format_import_params, format_params, format_results},
+};
+use anc_isa::DataSectionType;
+use anc_parser_asm::{parser::parse_from_str, NAME_PATH_SEPARATOR};
+
+use crate::fallback::analyse_tokens;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SymbolKind {
+    Function,
+    ImportFunction,
+    ExternalFunction,
+    Data,
+    ImportData,
+    ExternalData,
+    LocalVariable,
+    ImportModule,
+    ExternalLibrary,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Symbol {
+    pub name: String,
+    pub kind: SymbolKind,
+
+    // the range of the identifier in the declaration.
+    pub range: Location,
+
+    // the declaration without the body, e.g.
+    // - "pub fn add(left:i32, right:i32) -> i32"
+    // - "import fn std::memory::copy(i64, i64, i64)"
+    // - "left:i32"
+    pub signature: String,
+
+    // the `///...` lines above the declaration.
+    pub document: Option<String>,
+
+    // the index of the function which the local variable belongs to,
+    // it is `None` for the other symbols.
+    pub function_index: Option<usize>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Reference {
+    pub range: Location,
+    pub symbol_index: usize,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum Namespace {
+    Function,
+    ExternalFunction,
+    Data,
+    LocalVariable,
+}
+
+pub(crate) struct PendingReference {
+    pub range: Location,
+    pub name: String,
+    pub namespace: Namespace,
+    pub function_index: usize,
+}
+
+#[derive(Debug, PartialEq, Default)]
+pub struct Analysis {
+    pub symbols: Vec<Symbol>,
+    pub references: Vec<Reference>,
+
+    // the instruction names in the function bodies, e.g. `add_i32` in `add_i32(...)`.
+    pub instructions: Vec<(Location, String)>,
+
+    // the char index ranges `(start, end)` of the function statements.
+    pub function_spans: Vec<(usize, usize)>,
+}
+
+impl Analysis {
+    /// analyse the source code, the declarations and references are collected
+    /// from the tokens if the source code can not be parsed, and an empty
+    /// analysis is returned if it can not be lexed either.
+    pub fn from_source(source_code: &str) -> Self {
+        match parse_from_str(source_code) {
+            Ok(module_node) => Analyser::default().analyse(&module_node),
+            Err(_) => analyse_tokens(source_code),
+        }
+    }
+
+    /// find the symbol of the declaration or reference at the specified char index.
+    pub fn find_symbol(&self, char_index: usize) -> Option<usize> {
+        if let Some(symbol_index) = self
+            .symbols
+            .iter()
+            .position(|symbol| contains_index(&symbol.range, char_index))
+        {
+            return Some(symbol_index);
+        }
+
+        self.references
+            .iter()
+            .find(|reference| contains_index(&reference.range, char_index))
+            .map(|reference| reference.symbol_index)
+    }
+
+    /// find the instruction name at the specified char index.
+    pub fn find_instruction(&self, char_index: usize) -> Option<&str> {
+        self.instructions
+            .iter()
+            .find(|(range, _)| contains_index(range, char_index))
+            .map(|(_, name)| name.as_str())
+    }
+
+    /// get the ranges of the references of the specified symbol.
+    pub fn get_reference_ranges(
+        &self,
+        symbol_index: usize,
+        include_declaration: bool,
+    ) -> Vec<Location> {
+        let mut ranges = vec![];
+
+        if include_declaration {
+            ranges.push(self.symbols[symbol_index].range);
+        }
+
+        ranges.extend(
+            self.references
+                .iter()
+                .filter(|reference| reference.symbol_index == symbol_index)
+                .map(|reference| reference.range),
+        );
+
+        ranges
+    }
+
+    /// get the symbols which are visible at the specified char index, i.e. all
+    /// the symbols except the local variables of the other functions.
+    pub fn get_visible_symbols(&self, char_index: usize) -> Vec<&Symbol> {
+        let current_function_index = self
+            .function_spans
+            .iter()
+            .position(|(start, end)| *start <= char_index && char_index <= *end);
+
+        self.symbols
+            .iter()
+            .filter(|symbol| {
+                symbol.function_index.is_none() || symbol.function_index == current_function_index
+            })
+            .collect()
+    }
+}
+
+// the end of range is included, so that the identifier can be found
+// when the cursor is placed right after it.
+fn contains_index(range: &Location, char_index: usize) -> bool {
+    range.index <= char_index && char_index <= range.index + range.length
+}
+
+#[derive(Default)]
+struct Analyser {
+    analysis: Analysis,
+    pending_references: Vec<PendingReference>,
+}
+
+impl Analyser {
+    fn analyse(mut self, module_node: &ModuleNode) -> Analysis {
+        for import_node in &module_node.imports {
+            self.analyse_import_node(import_node);
+        }
+
+        for external_node in &module_node.externals {
+            self.analyse_external_node(external_node);
+        }
+
+        for data_node in &module_node.datas {
+            self.analyse_data_node(data_node);
+        }
+
+        for function_node in &module_node.functions {
+            self.analyse_function_node(function_node);
+        }
+
+        self.analysis.references =
+            resolve_references(&self.analysis.symbols, &self.pending_references);
+        self.analysis
+    }
+
+    fn add_symbol(
+        &mut self,
+        name: &str,
+        kind: SymbolKind,
+        range: Location,
+        signature: String,
+        document: &Option<String>,
+        function_index: Option<usize>,
+    ) {
+        self.analysis.symbols.push(Symbol {
+            name: name.to_owned(),
+            kind,
+            range,
+            signature,
+            document: document.clone(),
+            function_index,
+        });
+    }
+
+    fn analyse_import_node(&mut self, import_node: &ImportNode) {
+        match import_node {
+            ImportNode::Function(node) => {
+                let mut signature = format!(
+                    "{}import fn {}",
+                    get_public_prefix(node.public),
+                    node.full_name
+                );
+                if let Some((params, results)) = &node.signature {
+                    signature.push_str(&format!(
+                        "{} -> {}",
+                        format_import_params(params),
+                        format_results(results)
+                    ));
+                }
+                signature.push_str(&format_alias_and_from(&node.alias_name, &node.from));
+
+                self.add_symbol(
+                    get_identifier(&node.full_name, &node.alias_name),
+                    SymbolKind::ImportFunction,
+                    node.location,
+                    signature,
+                    &node.document,
+                    None,
+                );
+            }
+            ImportNode::Data(node) => {
+                let section = match node.data_section_type {
+                    Some(DataSectionType::ReadOnly) => "readonly ",
+                    Some(DataSectionType::Uninit) => "uninit ",
+                    Some(DataSectionType::ReadWrite) | None => "",
+                };
+
+                let mut signature = format!(
+                    "{}import {}data {}",
+                    get_public_prefix(node.public),
+                    section,
+                    node.full_name
+                );
+                if let Some(data_type) = &node.data_type {
+                    signature.push_str(&format!(" type {}", data_type));
+                }
+                signature.push_str(&format_alias_and_from(&node.alias_name, &node.from));
+
+                self.add_symbol(
+                    get_identifier(&node.full_name, &node.alias_name),
+                    SymbolKind::ImportData,
+                    node.location,
+                    signature,
+                    &node.document,
+                    None,
+                );
+            }
+            ImportNode::Module(node) => {
+                self.add_symbol(
+                    &node.name,
+                    SymbolKind::ImportModule,
+                    node.location,
+                    format!(
+                        "import module {} {}",
+                        node.name,
+                        format_dependency_source(&node.source)
+                    ),
+                    &node.document,
+                    None,
+                );
+            }
+            ImportNode::Glob(_) => {
+                // the items are unknown until the dependency module is read.
+            }
+        }
+    }
+
+    fn analyse_external_node(&mut self, external_node: &ExternalNode) {
+        match external_node {
+            ExternalNode::Function(node) => {
+                let result = match &node.result {
+                    Some(data_type) => data_type.to_string(),
+                    None => "()".to_owned(),
+                };
+
+                self.add_symbol(
+                    get_identifier(&node.full_name, &node.alias_name),
+                    SymbolKind::ExternalFunction,
+                    node.location,
+                    format!(
+                        "external fn {}{} -> {}{}",
+                        node.full_name,
+                        format_import_params(&node.params),
+                        result,
+                        format_alias_and_from(&node.alias_name, &None)
+                    ),
+                    &node.document,
+                    None,
+                );
+            }
+            ExternalNode::Data(node) => {
+                self.add_symbol(
+                    get_identifier(&node.full_name, &node.alias_name),
+                    SymbolKind::ExternalData,
+                    node.location,
+                    format!(
+                        "external data {} type {}{}",
+                        node.full_name,
+                        node.data_type,
+                        format_alias_and_from(&node.alias_name, &None)
+                    ),
+                    &node.document,
+                    None,
+                );
+            }
+            ExternalNode::Library(node) => {
+                self.add_symbol(
+                    &node.name,
+                    SymbolKind::ExternalLibrary,
+                    node.location,
+                    format!(
+                        "external library {} {}",
+                        node.name,
+                        format_dependency_source(&node.source)
+                    ),
+                    &node.document,
+                    None,
+                );
+            }
+        }
+    }
+
+    fn analyse_data_node(&mut self, data_node: &DataNode) {
+        // the signature ends with the data type, e.g. "byte[16, align=8]"
+        let signature = match &data_node.data_section {
+            DataSection::ReadOnly(pair) => {
+                format!("readonly data {}:{}", data_node.name, pair.data_type)
+            }
+            DataSection::ReadWrite(pair) => format!("data {}:{}", data_node.name, pair.data_type),
+            DataSection::Uninit(data_type) => {
+                format!("uninit data {}:{}", data_node.name, data_type)
+            }
+        };
+
+        self.add_symbol(
+            &data_node.name,
+            SymbolKind::Data,
+            data_node.location,
+            format!("{}{}", get_public_prefix(data_node.public), signature),
+            &data_node.document,
+            None,
+        );
+    }
+
+    fn analyse_function_node(&mut self, function_node: &FunctionNode) {
+        let function_index = self.analysis.function_spans.len();
+        let statement_location = &function_node.statement_location;
+        self.analysis.function_spans.push((
+            statement_location.index,
+            statement_location.index + statement_location.length,
+        ));
+
+        // the signature ends with the results.
+        self.add_symbol(
+            &function_node.name,
+            SymbolKind::Function,
+            function_node.location,
+            format!(
+                "{}fn {}{} -> {}",
+                get_public_prefix(function_node.public),
+                function_node.name,
+                format_params(&function_node.params),
+                format_results(&function_node.results)
+            ),
+            &function_node.document,
+            None,
+        );
+
+        // the local variables include the parameters.
+        for param in &function_node.params {
+            self.add_symbol(
+                &param.name,
+                SymbolKind::LocalVariable,
+                param.location,
+                format!("{}:{}", param.name, param.data_type),
+                &None,
+                Some(function_index),
+            );
+        }
+
+        self.analyse_local_variables(&function_node.locals, function_index);
+        self.analyse_expression_node(&function_node.body, function_index);
+    }
+
+    fn analyse_local_variables(&mut self, locals: &[LocalVariable], function_index: usize) {
+        for local in locals {
+            self.add_symbol(
+                &local.name,
+                SymbolKind::LocalVariable,
+                local.location,
+                format!("{}:{}", local.name, local.data_type),
+                &None,
+                Some(function_index),
+            );
+        }
+    }
+
+    // the nodes are visited in the order of the source code, e.g. the local
+    // variables of a block are visited before its body.
+    fn analyse_expression_node(&mut self, expression_node: &ExpressionNode, function_index: usize) {
+        match expression_node {
+            ExpressionNode::Group(expression_nodes) => {
+                for node in expression_nodes {
+                    self.analyse_expression_node(node, function_index);
+                }
+            }
+            ExpressionNode::Instruction(instruction_node) => {
+                self.analysis
+                    .instructions
+                    .push((instruction_node.location, instruction_node.name.to_owned()));
+
+                // the identifier is the first argument, e.g. `call(foo, ...)`
+                if let (Some(ArgumentValue::Identifier(name, location)), Some(namespace)) = (
+                    instruction_node.positional_args.first(),
+                    get_argument_namespace(&instruction_node.name),
+                ) {
+                    self.pending_references.push(PendingReference {
+                        range: *location,
+                        name: name.to_owned(),
+                        namespace,
+                        function_index,
+                    });
+                }
+
+                let argument_values = instruction_node
+                    .positional_args
+                    .iter()
+                    .chain(instruction_node.named_args.iter().map(|arg| &arg.value));
+                for argument_value in argument_values {
+                    if let ArgumentValue::Expression(node) = argument_value {
+                        self.analyse_expression_node(node, function_index);
+                    }
+                }
+            }
+            ExpressionNode::When(when_node) => {
+                self.analyse_local_variables(&when_node.locals, function_index);
+                self.analyse_expression_node(&when_node.testing, function_index);
+                self.analyse_expression_node(&when_node.consequence, function_index);
+            }
+            ExpressionNode::If(if_node) => {
+                self.analyse_expression_node(&if_node.testing, function_index);
+                self.analyse_expression_node(&if_node.consequence, function_index);
+                self.analyse_expression_node(&if_node.alternative, function_index);
+            }
+            ExpressionNode::Block(block_node) => {
+                for param_value in &block_node.param_values {
+                    self.add_symbol(
+                        &param_value.name,
+                        SymbolKind::LocalVariable,
+                        param_value.location,
+                        format!("{}:{}", param_value.name, param_value.data_type),
+                        &None,
+                        Some(function_index),
+                    );
+                    self.analyse_expression_node(&param_value.value, function_index);
+                }
+
+                self.analyse_local_variables(&block_node.locals, function_index);
+                self.analyse_expression_node(&block_node.body, function_index);
+            }
+            ExpressionNode::Break(break_node) | ExpressionNode::Recur(break_node) => {
+                let (BreakNode::Break(nodes) | BreakNode::BreakFn(nodes)) = break_node;
+                for node in nodes {
+                    self.analyse_expression_node(node, function_index);
+                }
+            }
+        }
+    }
+}
+
+fn get_public_prefix(public: bool) -> &'static str {
+    if public {
+        "pub "
+    } else {
+        ""
+    }
+}
+
+fn format_alias_and_from(alias_name: &Option<String>, from: &Option<String>) -> String {
+    let mut text = String::new();
+
+    if let Some(alias_name) = alias_name {
+        text.push_str(&format!(" as {}", alias_name));
+    }
+
+    if let Some(from) = from {
+        text.push_str(&format!(" from {}", from));
+    }
+
+    text
+}
+
+// the identifier of the imported or external item, i.e. the alias name
+// if it presents, otherwise the last segment of the full name.
+fn get_identifier<'a>(full_name: &'a str, alias_name: &'a Option<String>) -> &'a str {
+    match alias_name {
+        Some(alias_name) => alias_name,
+        None => full_name
+            .rsplit_once(NAME_PATH_SEPARATOR)
+            .map(|(_, identifier)| identifier)
+            .unwrap_or(full_name),
+    }
+}
+
+// the local variables of the nested blocks may have the same name,
+// the nearest declaration before the reference is preferred.
+pub(crate) fn resolve_references(
+    symbols: &[Symbol],
+    pending_references: &[PendingReference],
+) -> Vec<Reference> {
+    let mut references = vec![];
+
+    for pending_reference in pending_references {
+        let is_candidate = |symbol: &Symbol| {
+            symbol.name == pending_reference.name
+                && match pending_reference.namespace {
+                    Namespace::Function => matches!(
+                        symbol.kind,
+                        SymbolKind::Function | SymbolKind::ImportFunction
+                    ),
+                    Namespace::ExternalFunction => symbol.kind == SymbolKind::ExternalFunction,
+                    Namespace::Data => matches!(
+                        symbol.kind,
+                        SymbolKind::Data | SymbolKind::ImportData | SymbolKind::ExternalData
+                    ),
+                    Namespace::LocalVariable => {
+                        symbol.kind == SymbolKind::LocalVariable
+                            && symbol.function_index == Some(pending_reference.function_index)
+                    }
+                }
+        };
+
+        let symbol_index = (0..symbols.len())
+            .rev()
+            .find(|index| {
+                is_candidate(&symbols[*index])
+                    && symbols[*index].range.index < pending_reference.range.index
+            })
+            .or_else(|| symbols.iter().position(is_candidate));
+
+        if let Some(symbol_index) = symbol_index {
+            references.push(Reference {
+                range: pending_reference.range,
+                symbol_index,
+            });
+        }
+    }
+
+    references
+}
+
+// the namespace of the first argument (i.e. the identifier) of the instruction.
+pub(crate) fn get_argument_namespace(instruction_name: &str) -> Option<Namespace> {
+    match instruction_name {
+        "call" | "get_function" | "host_addr_function" => Some(Namespace::Function),
+        "extcall" => Some(Namespace::ExternalFunction),
+        _ if instruction_name.starts_with("data_")
+            || instruction_name.starts_with("host_addr_data") =>
+        {
+            Some(Namespace::Data)
+        }
+        _ if instruction_name.starts_with("local_")
+            || instruction_name.starts_with("host_addr_local") =>
+        {
+            Some(Namespace::LocalVariable)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{Analysis, SymbolKind};
+
+    const SOURCE: &str = r#"import fn std::memory::copy(i64, i64, i64) as mem_copy
+import data foo::count type i32
+external fn libc::getuid() -> i32
+
+/// The message.
+readonly data msg:byte[] = "hello"
+
+/// Adds two numbers.
+/// The result is a i32.
+pub fn add(left:i32, right:i32) -> i32
+    add_i32(local_load_i32_s(left), local_load_i32_s(right))
+
+fn test() -> i32 [sum:i32] {
+    local_store_i32(sum, call(add, data_load_i32_u(count), extcall(getuid)))
+    call(mem_copy, host_addr_data(msg), imm_i64(0), imm_i64(5))
+    local_load_i32_s(sum)
+}
+"#;
+
+    fn find_symbol_by_name(analysis: &Analysis, name: &str) -> usize {
+        analysis
+            .symbols
+            .iter()
+            .position(|symbol| symbol.name == name)
+            .unwrap()
+    }
+
+    fn get_text_by_index(index: usize, length: usize) -> String {
+        SOURCE.chars().skip(index).take(length).collect()
+    }
+
+    #[test]
+    fn test_analyse_declarations() {
+        let analysis = Analysis::from_source(SOURCE);
+
+        let symbols: Vec<(&str, SymbolKind, &str)> = analysis
+            .symbols
+            .iter()
+            .map(|symbol| (symbol.name.as_str(), symbol.kind, symbol.signature.as_str()))
+            .collect();
+
+        assert_eq!(
+            symbols,
+            vec![
+                (
+                    "mem_copy",
+                    SymbolKind::ImportFunction,
+                    "import fn std::memory::copy(i64, i64, i64) -> () as mem_copy"
+                ),
+                (
+                    "count",
+                    SymbolKind::ImportData,
+                    "import data foo::count type i32"
+                ),
+                (
+                    "getuid",
+                    SymbolKind::ExternalFunction,
+                    "external fn libc::getuid() -> i32"
+                ),
+                ("msg", SymbolKind::Data, "readonly data msg:byte[]"),
+                (
+                    "add",
+                    SymbolKind::Function,
+                    "pub fn add(left:i32, right:i32) -> i32"
+                ),
+                ("left", SymbolKind::LocalVariable, "left:i32"),
+                ("right", SymbolKind::LocalVariable, "right:i32"),
+                ("test", SymbolKind::Function, "fn test() -> i32"),
+                ("sum", SymbolKind::LocalVariable, "sum:i32"),
+            ]
+        );
+
+        // the range of the identifier which is a part of the full name
+        let count_symbol = &analysis.symbols[find_symbol_by_name(&analysis, "count")];
+        assert_eq!(
+            get_text_by_index(count_symbol.range.index, count_symbol.range.length),
+            "count"
+        );
+
+        // documents
+        assert_eq!(
+            analysis.symbols[find_symbol_by_name(&analysis, "add")].document,
+            Some("Adds two numbers.\nThe result is a i32.".to_owned())
+        );
+        assert_eq!(
+            analysis.symbols[find_symbol_by_name(&analysis, "msg")].document,
+            Some("The message.".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_analyse_references() {
+        let analysis = Analysis::from_source(SOURCE);
+
+        let get_reference_texts = |name: &str| -> Vec<String> {
+            let symbol_index = find_symbol_by_name(&analysis, name);
+            analysis
+                .get_reference_ranges(symbol_index, false)
+                .iter()
+                .map(|range| {
+                    format!(
+                        "{}:{}",
+                        range.line,
+                        get_text_by_index(range.index, range.length)
+                    )
+                })
+                .collect()
+        };
+
+        assert_eq!(get_reference_texts("add"), vec!["13:add"]);
+        assert_eq!(get_reference_texts("count"), vec!["13:count"]);
+        assert_eq!(get_reference_texts("getuid"), vec!["13:getuid"]);
+        assert_eq!(get_reference_texts("mem_copy"), vec!["14:mem_copy"]);
+        assert_eq!(get_reference_texts("msg"), vec!["14:msg"]);
+        assert_eq!(get_reference_texts("left"), vec!["10:left"]);
+        assert_eq!(get_reference_texts("sum"), vec!["13:sum", "15:sum"]);
+
+        // find symbol by the reference
+        let reference_index = SOURCE.find("call(add").unwrap() + 5;
+        assert_eq!(
+            analysis.find_symbol(reference_index),
+            Some(find_symbol_by_name(&analysis, "add"))
+        );
+
+        // find instruction
+        let instruction_index = SOURCE.find("add_i32").unwrap();
+        assert_eq!(
+            analysis.find_instruction(instruction_index),
+            Some("add_i32")
+        );
+    }
+
+    #[test]
+    fn test_get_visible_symbols() {
+        let analysis = Analysis::from_source(SOURCE);
+
+        let get_visible_names = |char_index: usize| -> Vec<&str> {
+            analysis
+                .get_visible_symbols(char_index)
+                .iter()
+                .map(|symbol| symbol.name.as_str())
+                .collect()
+        };
+
+        let index_in_add = SOURCE.find("add_i32").unwrap();
+        assert_eq!(
+            get_visible_names(index_in_add),
+            vec!["mem_copy", "count", "getuid", "msg", "add", "left", "right", "test"]
+        );
+
+        let index_in_test = SOURCE.find("local_store_i32").unwrap();
+        assert_eq!(
+            get_visible_names(index_in_test),
+            vec!["mem_copy", "count", "getuid", "msg", "add", "test", "sum"]
+        );
+    }
+
+    #[test]
+    fn test_analyse_source_with_syntax_error() {
+        // the closing parenthesis of the last instruction is missing,
+        // the symbols are collected from the tokens.
+        let source_code = "\
+fn inc(n:i32) -> i32
+    add_i32(local_load_i32_s(n), imm_i32(1)
+";
+        let analysis = Analysis::from_source(source_code);
+
+        let symbols: Vec<(&str, SymbolKind)> = analysis
+            .symbols
+            .iter()
+            .map(|symbol| (symbol.name.as_str(), symbol.kind))
+            .collect();
+
+        assert_eq!(
+            symbols,
+            vec![
+                ("inc", SymbolKind::Function),
+                ("n", SymbolKind::LocalVariable)
+            ]
+        );
+        assert_eq!(analysis.get_reference_ranges(1, false).len(), 1);
+    }
+}
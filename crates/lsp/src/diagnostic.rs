@@ -0,0 +1,95 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+use std::path::Path;
+
+use anc_assembler::checker;
+use anc_parser_asm::diagnostic::{Diagnostic, LabelStyle};
+
+/// the diagnostic in chars, i.e. the same unit as `Location`.
+#[derive(Debug, PartialEq)]
+pub struct SourceDiagnostic {
+    pub index: usize,
+    pub length: usize,
+    pub code: Option<String>,
+    pub message: String,
+}
+
+/// check the source code, returns the first error of the parser or the assembler.
+///
+/// `source_file_path` is used to locate the module which the source file belongs to.
+pub fn check_source(source_code: &str, source_file_path: Option<&Path>) -> Vec<SourceDiagnostic> {
+    checker::check_source(source_code, source_file_path)
+        .iter()
+        .map(convert_diagnostic)
+        .collect()
+}
+
+// the diagnostic of LSP has only one range, so the messages of the primary
+// label, notes and suggestions are appended to the message.
+fn convert_diagnostic(diagnostic: &Diagnostic) -> SourceDiagnostic {
+    let (index, length) = diagnostic
+        .get_primary_label()
+        .map(|label| (label.location.index, label.location.length))
+        .unwrap_or((0, 0));
+
+    let mut message = diagnostic.message.clone();
+    for label in &diagnostic.labels {
+        if label.style == LabelStyle::Primary && !label.message.is_empty() {
+            message.push(' ');
+            message.push_str(&label.message);
+        }
+    }
+
+    for note in &diagnostic.notes {
+        message.push_str(&format!("\nnote: {}", note));
+    }
+
+    for suggestion in &diagnostic.suggestions {
+        message.push_str(&format!("\nhelp: {}", suggestion));
+    }
+
+    SourceDiagnostic {
+        index,
+        length,
+        code: diagnostic.code.clone(),
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{check_source, SourceDiagnostic};
+
+    #[test]
+    fn test_check_source_with_parser_error() {
+        assert_eq!(
+            check_source("fn 文foo@bar() nop()", None),
+            vec![SourceDiagnostic {
+                index: 7,
+                length: 0,
+                code: Some("P0002".to_owned()),
+                message: "Invalid char '@' for identifier.".to_owned()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_check_source_with_assembler_error() {
+        // the range is the location of the identifier argument.
+        assert_eq!(
+            check_source("fn foo() call(bar)", None),
+            vec![SourceDiagnostic {
+                index: 14,
+                length: 3,
+                code: Some("A0003".to_owned()),
+                message: "Can not find the function \"bar\".".to_owned()
+            }]
+        );
+    }
+}
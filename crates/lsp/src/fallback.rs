@@ -0,0 +1,691 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the symbol analysis by tokens, it is the fallback of the analysis by
+// the AST when the source file contains syntax errors, as long as the
+// source file can be lexed, the declarations and references are collected
+// from the tokens (which carry the ranges).
+
+use anc_parser_asm::{
+    lexer::lex_from_str,
+    location::Location,
+    token::{Comment, Token, TokenWithRange},
+    NAME_PATH_SEPARATOR,
+};
+
+use crate::analysis::{
+    get_argument_namespace, resolve_references, Analysis, PendingReference, Symbol, SymbolKind,
+};
+
+/// analyse the source code by tokens, returns an empty analysis if the
+/// source code can not be lexed.
+pub fn analyse_tokens(source_code: &str) -> Analysis {
+    match lex_from_str(source_code) {
+        Ok(tokens) => Analyser::new(source_code, tokens).analyse(),
+        Err(_) => Analysis::default(),
+    }
+}
+
+fn get_token_end(token_with_range: &TokenWithRange) -> usize {
+    token_with_range.range.index + token_with_range.range.length
+}
+
+struct Analyser {
+    source_chars: Vec<char>,
+
+    // the tokens without comments.
+    tokens: Vec<TokenWithRange>,
+
+    // the documents (i.e., the `///...` lines) before the tokens,
+    // the key is the index of the token.
+    documents: Vec<(usize, String)>,
+
+    analysis: Analysis,
+    pending_references: Vec<PendingReference>,
+}
+
+impl Analyser {
+    fn new(source_code: &str, raw_tokens: Vec<TokenWithRange>) -> Self {
+        let mut tokens = vec![];
+        let mut documents = vec![];
+        let mut document_lines: Vec<String> = vec![];
+
+        for token_with_range in raw_tokens {
+            match &token_with_range.token {
+                Token::Comment(Comment::Document(line)) => {
+                    // strip the leading space, the same as the parser.
+                    document_lines.push(line.strip_prefix(' ').unwrap_or(line).to_owned());
+                }
+                Token::Comment(_) => {
+                    // discard
+                }
+                Token::NewLine => {
+                    tokens.push(token_with_range);
+                }
+                _ => {
+                    if !document_lines.is_empty() {
+                        documents.push((tokens.len(), document_lines.join("\n")));
+                        document_lines.clear();
+                    }
+                    tokens.push(token_with_range);
+                }
+            }
+        }
+
+        Self {
+            source_chars: source_code.chars().collect(),
+            tokens,
+            documents,
+            analysis: Analysis::default(),
+            pending_references: vec![],
+        }
+    }
+
+    fn analyse(mut self) -> Analysis {
+        for (start, end) in self.split_statements() {
+            self.analyse_statement(start, end);
+        }
+
+        self.analysis.references =
+            resolve_references(&self.analysis.symbols, &self.pending_references);
+        self.analysis
+    }
+
+    fn is_keyword(&self, token_index: usize, keyword: &str) -> bool {
+        matches!(self.tokens.get(token_index), Some(TokenWithRange{token: Token::Keyword(k), ..}) if k == keyword)
+    }
+
+    fn is_token(&self, token_index: usize, token: &Token) -> bool {
+        self.tokens
+            .get(token_index)
+            .is_some_and(|token_with_range| &token_with_range.token == token)
+    }
+
+    fn get_name(&self, token_index: usize) -> Option<&str> {
+        match self.tokens.get(token_index) {
+            Some(TokenWithRange {
+                token: Token::Name(name),
+                ..
+            }) => Some(name),
+            _ => None,
+        }
+    }
+
+    fn skip_new_lines(&self, mut token_index: usize, end: usize) -> usize {
+        while token_index < end && self.is_token(token_index, &Token::NewLine) {
+            token_index += 1;
+        }
+        token_index
+    }
+
+    // find the index of the matching closing token, e.g. the ')' of '('.
+    fn find_closing_token(&self, open_index: usize, end: usize) -> Option<usize> {
+        let mut depth = 0usize;
+        for token_index in open_index..end {
+            match self.tokens[token_index].token {
+                Token::LeftParen | Token::LeftBracket | Token::LeftBrace => depth += 1,
+                Token::RightParen | Token::RightBracket | Token::RightBrace => {
+                    depth = depth.saturating_sub(1);
+                    if depth == 0 {
+                        return Some(token_index);
+                    }
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    // the text of the tokens, the line breaks and continuous
+    // spaces are replaced with a single space.
+    fn get_tokens_text(&self, start: usize, end_included: usize) -> String {
+        let start_index = self.tokens[start].range.index;
+        let end_index = get_token_end(&self.tokens[end_included]).min(self.source_chars.len());
+
+        self.source_chars[start_index..end_index]
+            .iter()
+            .collect::<String>()
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .join(" ")
+    }
+
+    fn get_document(&self, token_index: usize) -> Option<String> {
+        self.documents
+            .iter()
+            .find(|(index, _)| *index == token_index)
+            .map(|(_, document)| document.to_owned())
+    }
+
+    // a statement starts with one of the keywords "import", "external", "fn",
+    // "data", "pub", "readonly" and "uninit" at the top level, the keywords
+    // which follow the modifiers (e.g. the "fn" in `pub fn`) do not start
+    // a new statement.
+    //
+    // returns the token index ranges `(start, end)` of statements.
+    fn split_statements(&self) -> Vec<(usize, usize)> {
+        let mut starts = vec![];
+        let mut depth = 0usize;
+        let mut last_keyword: Option<&str> = None;
+
+        for (token_index, token_with_range) in self.tokens.iter().enumerate() {
+            match &token_with_range.token {
+                Token::LeftParen | Token::LeftBracket | Token::LeftBrace => {
+                    depth += 1;
+                    last_keyword = None;
+                }
+                Token::RightParen | Token::RightBracket | Token::RightBrace => {
+                    depth = depth.saturating_sub(1);
+                    last_keyword = None;
+                }
+                Token::NewLine => {
+                    // keep the last keyword, e.g. `pub\nfn foo()`
+                }
+                Token::Keyword(keyword) => {
+                    let is_statement_keyword = matches!(
+                        keyword.as_str(),
+                        "import" | "external" | "fn" | "data" | "pub" | "readonly" | "uninit"
+                    );
+
+                    let follows_modifier = matches!(
+                        last_keyword,
+                        Some("import" | "external" | "pub" | "readonly" | "uninit")
+                    );
+
+                    if depth == 0 && is_statement_keyword && !follows_modifier {
+                        starts.push(token_index);
+                    }
+
+                    last_keyword = Some(keyword.as_str());
+                }
+                _ => {
+                    last_keyword = None;
+                }
+            }
+        }
+
+        let mut statements = vec![];
+        for (idx, start) in starts.iter().enumerate() {
+            let end = starts.get(idx + 1).copied().unwrap_or(self.tokens.len());
+            statements.push((*start, end));
+        }
+
+        statements
+    }
+
+    fn analyse_statement(&mut self, start: usize, end: usize) {
+        // skip the modifiers
+        let mut token_index = start;
+        while token_index < end
+            && (self.is_keyword(token_index, "pub")
+                || self.is_keyword(token_index, "readonly")
+                || self.is_keyword(token_index, "uninit")
+                || self.is_token(token_index, &Token::NewLine))
+        {
+            token_index += 1;
+        }
+
+        let document = self.get_document(start);
+
+        if self.is_keyword(token_index, "fn") {
+            self.analyse_function(start, token_index + 1, end, document);
+        } else if self.is_keyword(token_index, "data") {
+            self.analyse_data(start, token_index + 1, end, document);
+        } else if self.is_keyword(token_index, "import") || self.is_keyword(token_index, "external")
+        {
+            let is_import = self.is_keyword(token_index, "import");
+
+            // skip the modifiers after "import", e.g. `import readonly data ...`
+            let mut item_index = token_index + 1;
+            while self.is_keyword(item_index, "readonly") || self.is_keyword(item_index, "uninit") {
+                item_index += 1;
+            }
+
+            let kind = if self.is_keyword(item_index, "fn") {
+                Some(if is_import {
+                    SymbolKind::ImportFunction
+                } else {
+                    SymbolKind::ExternalFunction
+                })
+            } else if self.is_keyword(item_index, "data") {
+                Some(if is_import {
+                    SymbolKind::ImportData
+                } else {
+                    SymbolKind::ExternalData
+                })
+            } else {
+                None
+            };
+
+            match kind {
+                Some(kind) => self.analyse_import_item(start, item_index + 1, end, kind, document),
+                None => {
+                    // `import module name ...` and `external library name ...`
+                    let kind = match self.get_name(item_index) {
+                        Some("module") if is_import => SymbolKind::ImportModule,
+                        Some("library") if !is_import => SymbolKind::ExternalLibrary,
+                        _ => return,
+                    };
+                    self.add_symbol_by_name_token(item_index + 1, kind, start, end, document, None);
+                }
+            }
+        }
+    }
+
+    fn add_symbol_by_name_token(
+        &mut self,
+        name_index: usize,
+        kind: SymbolKind,
+        signature_start: usize,
+        signature_end: usize,
+        document: Option<String>,
+        function_index: Option<usize>,
+    ) {
+        let Some(name) = self.get_name(name_index) else {
+            return;
+        };
+
+        let name = name.to_owned();
+        let signature = self.get_tokens_text(signature_start, signature_end - 1);
+
+        self.analysis.symbols.push(Symbol {
+            name,
+            kind,
+            range: self.tokens[name_index].range,
+            signature,
+            document,
+            function_index,
+        });
+    }
+
+    // the statement tokens after "fn" or "data" of `import fn ...`, `import data ...`,
+    // `external fn ...` and `external data ...`.
+    fn analyse_import_item(
+        &mut self,
+        start: usize,
+        full_name_index: usize,
+        end: usize,
+        kind: SymbolKind,
+        document: Option<String>,
+    ) {
+        let full_name_index = self.skip_new_lines(full_name_index, end);
+        let Some(TokenWithRange {
+            token: Token::FullName(full_name),
+            range,
+        }) = self.tokens.get(full_name_index)
+        else {
+            return;
+        };
+
+        let full_name = full_name.to_owned();
+        let range = *range;
+
+        // the signature is the whole statement.
+        let mut last_index = end - 1;
+        while last_index > start && self.is_token(last_index, &Token::NewLine) {
+            last_index -= 1;
+        }
+        let signature = self.get_tokens_text(start, last_index);
+
+        if full_name.ends_with("::*") {
+            // glob import, the items are unknown.
+            return;
+        }
+
+        let open_index = self.skip_new_lines(full_name_index + 1, end);
+        if full_name.ends_with(NAME_PATH_SEPARATOR) && self.is_token(open_index, &Token::LeftBrace)
+        {
+            // grouped import, e.g. `import fn foo::{bar, baz as qux}`
+            let Some(close_index) = self.find_closing_token(open_index, end) else {
+                return;
+            };
+
+            let mut item_start = open_index + 1;
+            while item_start < close_index {
+                // find the end of item, i.e. the separator at the top level of the group.
+                let mut item_end = item_start;
+                let mut depth = 0usize;
+                while item_end < close_index {
+                    match self.tokens[item_end].token {
+                        Token::LeftParen | Token::LeftBracket => depth += 1,
+                        Token::RightParen | Token::RightBracket => depth = depth.saturating_sub(1),
+                        Token::Comma | Token::NewLine if depth == 0 => break,
+                        _ => {}
+                    }
+                    item_end += 1;
+                }
+
+                if self.get_name(item_start).is_some() {
+                    let name_index = self
+                        .find_alias_name_index(item_start, item_end)
+                        .unwrap_or(item_start);
+                    // the signature is the whole statement.
+                    self.add_symbol_by_name_token(
+                        name_index,
+                        kind,
+                        start,
+                        last_index + 1,
+                        document.clone(),
+                        None,
+                    );
+                }
+
+                item_start = item_end + 1;
+            }
+            return;
+        }
+
+        if let Some(alias_index) = self.find_alias_name_index(full_name_index + 1, end) {
+            self.add_symbol_by_name_token(alias_index, kind, start, last_index + 1, document, None);
+        } else {
+            // the identifier is the last segment of the full name.
+            let identifier = full_name
+                .rsplit_once(NAME_PATH_SEPARATOR)
+                .map(|(_, identifier)| identifier)
+                .unwrap_or(&full_name);
+
+            let identifier_length = identifier.chars().count();
+            let offset = full_name.chars().count() - identifier_length;
+
+            self.analysis.symbols.push(Symbol {
+                name: identifier.to_owned(),
+                kind,
+                range: Location::new_range(
+                    range.index + offset,
+                    range.line,
+                    range.column + offset,
+                    identifier_length,
+                ),
+                signature,
+                document,
+                function_index: None,
+            });
+        }
+    }
+
+    // find the name after the keyword "as" at the top level.
+    fn find_alias_name_index(&self, start: usize, end: usize) -> Option<usize> {
+        let mut depth = 0usize;
+        for token_index in start..end {
+            match &self.tokens[token_index].token {
+                Token::LeftParen | Token::LeftBracket | Token::LeftBrace => depth += 1,
+                Token::RightParen | Token::RightBracket | Token::RightBrace => {
+                    depth = depth.saturating_sub(1)
+                }
+                Token::Keyword(keyword) if keyword == "as" && depth == 0 => {
+                    let name_index = self.skip_new_lines(token_index + 1, end);
+                    return self.get_name(name_index).map(|_| name_index);
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    // `data name:type = value`
+    fn analyse_data(
+        &mut self,
+        start: usize,
+        name_index: usize,
+        end: usize,
+        document: Option<String>,
+    ) {
+        let name_index = self.skip_new_lines(name_index, end);
+
+        // the signature ends with the data type, e.g. "byte[16, align=8]"
+        let mut signature_end = name_index + 1;
+        if self.is_token(signature_end, &Token::Colon) {
+            signature_end += 1;
+            if signature_end < end
+                && matches!(self.tokens[signature_end].token, Token::DataTypeName(_))
+            {
+                signature_end += 1;
+                if self.is_token(signature_end, &Token::LeftBracket) {
+                    if let Some(close_index) = self.find_closing_token(signature_end, end) {
+                        signature_end = close_index + 1;
+                    }
+                }
+            }
+        }
+
+        self.add_symbol_by_name_token(
+            name_index,
+            SymbolKind::Data,
+            start,
+            signature_end.min(end),
+            document,
+            None,
+        );
+    }
+
+    // `fn name(params) -> results [local variables] body`
+    fn analyse_function(
+        &mut self,
+        start: usize,
+        name_index: usize,
+        end: usize,
+        document: Option<String>,
+    ) {
+        let name_index = self.skip_new_lines(name_index, end);
+        if self.get_name(name_index).is_none() {
+            return;
+        }
+
+        // the signature ends with the results.
+        let mut signature_end = name_index + 1;
+        if self.is_token(signature_end, &Token::LeftParen) {
+            if let Some(close_index) = self.find_closing_token(signature_end, end) {
+                signature_end = close_index + 1;
+            }
+        }
+
+        let arrow_index = self.skip_new_lines(signature_end, end);
+        if self.is_token(arrow_index, &Token::RightArrow) {
+            let results_index = self.skip_new_lines(arrow_index + 1, end);
+            if self.is_token(results_index, &Token::LeftParen) {
+                if let Some(close_index) = self.find_closing_token(results_index, end) {
+                    signature_end = close_index + 1;
+                }
+            } else if results_index < end {
+                signature_end = results_index + 1;
+            }
+        }
+
+        let function_index = self.analysis.function_spans.len();
+        self.analysis.function_spans.push((
+            self.tokens[start].range.index,
+            get_token_end(&self.tokens[end - 1]),
+        ));
+
+        self.add_symbol_by_name_token(
+            name_index,
+            SymbolKind::Function,
+            start,
+            signature_end.min(end),
+            document,
+            None,
+        );
+
+        for token_index in (name_index + 1)..end {
+            let Some(name) = self.get_name(token_index) else {
+                continue;
+            };
+
+            if self.is_token(token_index + 1, &Token::Colon) {
+                // the local variable (includes the parameter), e.g. `left:i32`,
+                // `buf:byte[16]`
+                let mut local_end = token_index + 2;
+                if local_end < end && matches!(self.tokens[local_end].token, Token::DataTypeName(_))
+                {
+                    local_end += 1;
+                    if self.is_token(local_end, &Token::LeftBracket) {
+                        if let Some(close_index) = self.find_closing_token(local_end, end) {
+                            local_end = close_index + 1;
+                        }
+                    }
+                }
+
+                self.add_symbol_by_name_token(
+                    token_index,
+                    SymbolKind::LocalVariable,
+                    token_index,
+                    local_end.min(end),
+                    None,
+                    Some(function_index),
+                );
+            } else if self.is_token(token_index + 1, &Token::LeftParen) {
+                // the instruction, e.g. `call(foo, ...)`
+                let instruction_name = name.to_owned();
+                self.analysis
+                    .instructions
+                    .push((self.tokens[token_index].range, instruction_name.clone()));
+
+                let argument_index = self.skip_new_lines(token_index + 2, end);
+                let Some(argument_name) = self.get_name(argument_index).map(|name| name.to_owned())
+                else {
+                    continue;
+                };
+
+                // the identifier argument must be followed by a separator or ')'
+                let next_index = argument_index + 1;
+                if !(self.is_token(next_index, &Token::Comma)
+                    || self.is_token(next_index, &Token::NewLine)
+                    || self.is_token(next_index, &Token::RightParen))
+                {
+                    continue;
+                }
+
+                let Some(namespace) = get_argument_namespace(&instruction_name) else {
+                    continue;
+                };
+
+                self.pending_references.push(PendingReference {
+                    range: self.tokens[argument_index].range,
+                    name: argument_name,
+                    namespace,
+                    function_index,
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use anc_parser_asm::location::Location;
+
+    use crate::analysis::{Analysis, SymbolKind};
+
+    use super::analyse_tokens;
+
+    const SOURCE: &str = r#"import fn std::memory::copy(i64, i64, i64) as mem_copy
+import data foo::count type i32
+external fn libc::getuid() -> i32
+
+/// The message.
+readonly data msg:byte[] = "hello"
+
+/// Adds two numbers.
+pub fn add(left:i32, right:i32) -> i32
+    add_i32(local_load_i32_s(left), local_load_i32_s(right))
+
+fn test() -> i32 [sum:i32] {
+    local_store_i32(sum, call(add, data_load_i32_u(count), extcall(getuid)))
+    call(mem_copy, host_addr_data(msg), imm_i64(0), imm_i64(5))
+    local_load_i32_s(sum)
+}
+"#;
+
+    const SOURCE_WITH_NESTED_ITEMS: &str = r#"//! The module.
+import module mymod from "modules/mymod"
+external library libfoo from "lib/libfoo.so"
+
+/// The grouped functions.
+import fn mymod::utils::{inc, dec as decrease}
+import readonly data foo::{PI type f32, msg as message} from mymod
+import fn std::memory::*
+external data libfoo::version type i32 as libfoo_version
+
+/// The buffer.
+pub uninit data buf:byte[16, align=8]
+
+/// Sums the numbers.
+pub fn sum(n:i32) -> i32 [total:i64]
+    block (i:i32=local_load_i32_s(n)) -> i32 [acc:i64]
+        when [flag:i32]
+            eqz_i32(local_load_i32_s(i))
+            local_store_i32(flag, imm_i32(1))
+"#;
+
+    // the symbols and references (except the signatures) which are collected
+    // from the tokens should be the same as the ones collected from the AST.
+    fn assert_tokens_match_ast(source_code: &str) {
+        type SymbolItem = (String, SymbolKind, Location, Option<String>, Option<usize>);
+
+        let get_symbol_items = |analysis: &Analysis| -> Vec<SymbolItem> {
+            // the AST groups the statements by kind, so sort the symbols by location.
+            let mut items: Vec<SymbolItem> = analysis
+                .symbols
+                .iter()
+                .map(|symbol| {
+                    (
+                        symbol.name.to_owned(),
+                        symbol.kind,
+                        symbol.range,
+                        symbol.document.clone(),
+                        symbol.function_index,
+                    )
+                })
+                .collect();
+            items.sort_by_key(|(_, _, range, _, _)| range.index);
+            items
+        };
+
+        let get_reference_items = |analysis: &Analysis| -> Vec<(Location, String)> {
+            analysis
+                .references
+                .iter()
+                .map(|reference| {
+                    (
+                        reference.range,
+                        analysis.symbols[reference.symbol_index].name.to_owned(),
+                    )
+                })
+                .collect()
+        };
+
+        let token_analysis = analyse_tokens(source_code);
+        let ast_analysis = Analysis::from_source(source_code);
+
+        assert_eq!(
+            get_symbol_items(&token_analysis),
+            get_symbol_items(&ast_analysis)
+        );
+        assert_eq!(
+            get_reference_items(&token_analysis),
+            get_reference_items(&ast_analysis)
+        );
+        assert_eq!(token_analysis.instructions, ast_analysis.instructions);
+
+        // the ranges of symbols point to the identifiers.
+        let source_chars: Vec<char> = source_code.chars().collect();
+        for symbol in &token_analysis.symbols {
+            let text: String = source_chars
+                [symbol.range.index..symbol.range.index + symbol.range.length]
+                .iter()
+                .collect();
+            assert_eq!(text, symbol.name);
+        }
+    }
+
+    #[test]
+    fn test_analyse_tokens_match_ast() {
+        assert_tokens_match_ast(SOURCE);
+        assert_tokens_match_ast(SOURCE_WITH_NESTED_ITEMS);
+    }
+}
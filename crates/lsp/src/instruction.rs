@@ -0,0 +1,212 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the documents of instructions, they are extracted from the
+// "Instructions" chapter of the file "docs/instructions.md", so that
+// the hover and completion are always consistent with the documentation.
+
+use std::sync::OnceLock;
+
+const INSTRUCTIONS_DOCUMENT: &str = include_str!("../../../docs/instructions.md");
+const INSTRUCTIONS_CHAPTER_TITLE: &str = "## Instructions";
+
+#[derive(Debug, PartialEq)]
+pub struct InstructionDocument {
+    pub name: String,
+
+    // e.g. "local_load_i64(identifier, offset=imm_i16) -> i64"
+    pub signature: String,
+
+    // the title of the section, e.g. "Local Loading/Storing"
+    pub category: String,
+
+    pub description: Option<String>,
+}
+
+pub fn get_instruction_documents() -> &'static [InstructionDocument] {
+    static INSTRUCTION_DOCUMENTS: OnceLock<Vec<InstructionDocument>> = OnceLock::new();
+    INSTRUCTION_DOCUMENTS.get_or_init(|| parse_instruction_documents(INSTRUCTIONS_DOCUMENT))
+}
+
+pub fn find_instruction_document(name: &str) -> Option<&'static InstructionDocument> {
+    get_instruction_documents()
+        .iter()
+        .find(|document| document.name == name)
+}
+
+// the instructions are listed in two forms:
+//
+// 1. the lines in the code blocks, e.g.
+//
+//    ```rust
+//    imm_i32(imm_i32) -> i32
+//    ```
+//
+// 2. the list items with descriptions, e.g.
+//
+//    - `call(identifier, value0, value1, ...) -> (values)`
+//       call a function
+fn parse_instruction_documents(text: &str) -> Vec<InstructionDocument> {
+    let mut documents: Vec<InstructionDocument> = vec![];
+
+    let Some((_, chapter)) = text.split_once(INSTRUCTIONS_CHAPTER_TITLE) else {
+        return documents;
+    };
+
+    let mut category = String::new();
+    let mut in_code_block = false;
+    let mut lines = chapter.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        let trimmed_line = line.trim();
+
+        if trimmed_line.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        let (signature, description) = if in_code_block {
+            (trimmed_line, None)
+        } else if let Some(title) = trimmed_line.strip_prefix("### ") {
+            title.trim().clone_into(&mut category);
+            continue;
+        } else if let Some(signature) = trimmed_line
+            .strip_prefix("- `")
+            .and_then(|item| item.strip_suffix('`'))
+        {
+            // the description is the following indented line.
+            let description = match lines.peek() {
+                Some(next_line) if next_line.starts_with(' ') && !next_line.trim().is_empty() => {
+                    Some(next_line.trim().to_owned())
+                }
+                _ => None,
+            };
+            (signature, description)
+        } else {
+            continue;
+        };
+
+        let Some(name) = get_instruction_name(signature) else {
+            continue;
+        };
+
+        if documents.iter().any(|document| document.name == name) {
+            continue;
+        }
+
+        documents.push(InstructionDocument {
+            name: name.to_owned(),
+            signature: normalize_signature(signature),
+            category: category.clone(),
+            description,
+        });
+    }
+
+    documents
+}
+
+// the instruction name is the part before the '(', which
+// consists of lowercase letters, digits and underscores.
+fn get_instruction_name(signature: &str) -> Option<&str> {
+    let (name, _) = signature.split_once('(')?;
+
+    if !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    {
+        Some(name)
+    } else {
+        None
+    }
+}
+
+// remove the padding spaces which are used for aligning the columns, e.g.
+// "local_load_i8_s( identifier, offset=imm_i16)" -> "local_load_i8_s(identifier, offset=imm_i16)"
+fn normalize_signature(signature: &str) -> String {
+    signature
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+        .replace("( ", "(")
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{find_instruction_document, parse_instruction_documents, InstructionDocument};
+
+    #[test]
+    fn test_parse_instruction_documents() {
+        let text = "\
+# Title
+
+```rust
+ignored_example(a)
+```
+
+## Instructions
+
+### Base
+
+```rust
+nop() -> ()
+local_load_i8_s( identifier, offset=imm_i16) -> i8
+```
+
+### Calling
+
+- `call(identifier, value0, value1, ...) -> (values)`
+   call a function
+
+- `envcall(number:i32) -> (values)`
+";
+
+        assert_eq!(
+            parse_instruction_documents(text),
+            vec![
+                InstructionDocument {
+                    name: "nop".to_owned(),
+                    signature: "nop() -> ()".to_owned(),
+                    category: "Base".to_owned(),
+                    description: None
+                },
+                InstructionDocument {
+                    name: "local_load_i8_s".to_owned(),
+                    signature: "local_load_i8_s(identifier, offset=imm_i16) -> i8".to_owned(),
+                    category: "Base".to_owned(),
+                    description: None
+                },
+                InstructionDocument {
+                    name: "call".to_owned(),
+                    signature: "call(identifier, value0, value1, ...) -> (values)".to_owned(),
+                    category: "Calling".to_owned(),
+                    description: Some("call a function".to_owned())
+                },
+                InstructionDocument {
+                    name: "envcall".to_owned(),
+                    signature: "envcall(number:i32) -> (values)".to_owned(),
+                    category: "Calling".to_owned(),
+                    description: None
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_instruction_document() {
+        let document = find_instruction_document("local_load_i64").unwrap();
+        assert_eq!(
+            document.signature,
+            "local_load_i64(identifier, offset=imm_i16) -> i64"
+        );
+        assert_eq!(document.category, "Local Loading/Storing");
+
+        assert!(find_instruction_document("add_i32").is_some());
+        assert!(find_instruction_document("foo").is_none());
+    }
+}
@@ -0,0 +1,33 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the language server for the XiaoXuan Core assembly (*.ancasm) files,
+// it communicates with the editor through the stdio.
+
+mod analysis;
+mod diagnostic;
+mod fallback;
+mod instruction;
+mod position;
+mod server;
+
+use std::error::Error;
+
+use lsp_server::Connection;
+
+use crate::server::{get_server_capabilities, Server};
+
+fn main() -> Result<(), Box<dyn Error + Sync + Send>> {
+    let (connection, io_threads) = Connection::stdio();
+
+    let server_capabilities = serde_json::to_value(get_server_capabilities())?;
+    connection.initialize(server_capabilities)?;
+
+    Server::new(connection).run()?;
+    io_threads.join()?;
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the conversion between the character index of `Location` and
+// the LSP position.
+//
+// the `Location` (produced by the lexer) counts in Unicode chars, while the
+// column of the LSP position counts in UTF-16 code units by default, e.g. the
+// column of 'b' in "文b" is 1 in `Location` and is also 1 in LSP, but the
+// column of 'b' in "😀b" is 1 in `Location` and is 2 in LSP.
+
+use anc_parser_asm::sourcemap::SourceMap;
+use lsp_types::{Position, Range};
+
+pub struct TextIndex {
+    source_map: SourceMap,
+}
+
+impl TextIndex {
+    pub fn new(text: &str) -> Self {
+        Self {
+            source_map: SourceMap::new(text),
+        }
+    }
+
+    /// the total number of chars.
+    pub fn len(&self) -> usize {
+        self.source_map.len()
+    }
+
+    /// convert the character index to the LSP position.
+    ///
+    /// the index which exceeds the end of text is treated as the end of text.
+    pub fn get_position(&self, char_index: usize) -> Position {
+        let (line, character) = self.source_map.get_line_and_utf16_column(char_index);
+        Position::new(line as u32, character as u32)
+    }
+
+    /// convert the char range (i.e. the "index" and "length" of `Location`)
+    /// to the LSP range.
+    pub fn get_range(&self, char_index: usize, length: usize) -> Range {
+        Range::new(
+            self.get_position(char_index),
+            self.get_position(char_index + length),
+        )
+    }
+
+    /// convert the LSP position to the character index.
+    ///
+    /// the position which exceeds the end of line is treated as the end of line,
+    /// and the line which exceeds the last line is treated as the end of text.
+    pub fn get_char_index(&self, position: &Position) -> usize {
+        self.source_map.get_char_index_by_line_and_utf16_column(
+            position.line as usize,
+            position.character as usize,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::Position;
+    use pretty_assertions::assert_eq;
+
+    use super::TextIndex;
+
+    #[test]
+    fn test_convert_position() {
+        let text_index = TextIndex::new("ab\n文b\n😀b\r\nc");
+
+        // ascii
+        assert_eq!(text_index.get_position(1), Position::new(0, 1));
+        assert_eq!(text_index.get_char_index(&Position::new(0, 1)), 1);
+
+        // BMP char
+        assert_eq!(text_index.get_position(4), Position::new(1, 1));
+        assert_eq!(text_index.get_char_index(&Position::new(1, 1)), 4);
+
+        // non-BMP char, i.e. the surrogate pair in UTF-16
+        assert_eq!(text_index.get_position(7), Position::new(2, 2));
+        assert_eq!(text_index.get_char_index(&Position::new(2, 2)), 7);
+
+        // the line break "\r\n"
+        assert_eq!(text_index.get_position(10), Position::new(3, 0));
+        assert_eq!(text_index.get_char_index(&Position::new(3, 0)), 10);
+
+        // out of range
+        assert_eq!(text_index.get_position(100), Position::new(3, 1));
+        assert_eq!(text_index.get_char_index(&Position::new(0, 100)), 2);
+        assert_eq!(text_index.get_char_index(&Position::new(100, 0)), 11);
+    }
+}
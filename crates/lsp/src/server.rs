@@ -0,0 +1,605 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+use std::{collections::HashMap, error::Error};
+
+use anc_assembly::printer::print_to_string;
+use anc_parser_asm::{
+    lexer::lex_from_str,
+    location::Location,
+    parser::parse_from_str,
+    token::{Comment, Token},
+};
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, RequestId, Response};
+use lsp_types::{
+    notification::{
+        DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+        Notification as NotificationTrait, PublishDiagnostics,
+    },
+    request::{
+        Completion, Formatting, GotoDefinition, HoverRequest, References, Request as RequestTrait,
+    },
+    CompletionItem, CompletionItemKind, CompletionOptions, CompletionParams, CompletionResponse,
+    Diagnostic, DiagnosticSeverity, DidChangeTextDocumentParams, DidCloseTextDocumentParams,
+    DidOpenTextDocumentParams, DocumentFormattingParams, GotoDefinitionParams,
+    GotoDefinitionResponse, Hover, HoverContents, HoverParams, HoverProviderCapability,
+    MarkupContent, MarkupKind, NumberOrString, OneOf, PublishDiagnosticsParams, ReferenceParams,
+    ServerCapabilities, TextDocumentSyncCapability, TextDocumentSyncKind, TextEdit, Url,
+};
+use serde_json::Value;
+
+use crate::{
+    analysis::{Analysis, Symbol, SymbolKind},
+    diagnostic::check_source,
+    instruction::{find_instruction_document, get_instruction_documents},
+    position::TextIndex,
+};
+
+const DIAGNOSTIC_SOURCE: &str = "ancasm";
+
+pub fn get_server_capabilities() -> ServerCapabilities {
+    ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        definition_provider: Some(OneOf::Left(true)),
+        references_provider: Some(OneOf::Left(true)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        completion_provider: Some(CompletionOptions::default()),
+        document_formatting_provider: Some(OneOf::Left(true)),
+        ..ServerCapabilities::default()
+    }
+}
+
+/// the opened source file.
+struct Document {
+    text: String,
+    text_index: TextIndex,
+    analysis: Analysis,
+}
+
+impl Document {
+    fn new(text: String) -> Self {
+        let text_index = TextIndex::new(&text);
+        let analysis = Analysis::from_source(&text);
+        Self {
+            text,
+            text_index,
+            analysis,
+        }
+    }
+
+    fn get_range(&self, location: &Location) -> lsp_types::Range {
+        self.text_index.get_range(location.index, location.length)
+    }
+}
+
+pub struct Server {
+    connection: Connection,
+    documents: HashMap<Url, Document>,
+}
+
+impl Server {
+    pub fn new(connection: Connection) -> Self {
+        Self {
+            connection,
+            documents: HashMap::new(),
+        }
+    }
+
+    /// handle the messages until the client requests to shutdown.
+    pub fn run(&mut self) -> Result<(), Box<dyn Error + Sync + Send>> {
+        while let Ok(message) = self.connection.receiver.recv() {
+            match message {
+                Message::Request(request) => {
+                    if self.connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+                    self.handle_request(request)?;
+                }
+                Message::Notification(notification) => {
+                    self.handle_notification(notification)?;
+                }
+                Message::Response(_) => {
+                    // the server does not send requests
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn handle_request(&mut self, request: Request) -> Result<(), Box<dyn Error + Sync + Send>> {
+        let Request { id, method, params } = request;
+
+        let result = match method.as_str() {
+            GotoDefinition::METHOD => {
+                serde_json::from_value(params).map(|params| self.goto_definition(params))
+            }
+            References::METHOD => {
+                serde_json::from_value(params).map(|params| self.find_references(params))
+            }
+            HoverRequest::METHOD => serde_json::from_value(params).map(|params| self.hover(params)),
+            Completion::METHOD => {
+                serde_json::from_value(params).map(|params| self.complete(params))
+            }
+            Formatting::METHOD => serde_json::from_value(params).map(|params| self.format(params)),
+            _ => {
+                return self.send_error(
+                    id,
+                    ErrorCode::MethodNotFound,
+                    format!("Unsupported method \"{}\".", method),
+                );
+            }
+        };
+
+        match result {
+            Ok(Ok(value)) => self.send(Message::Response(Response::new_ok(id, value))),
+            Ok(Err(message)) => self.send_error(id, ErrorCode::RequestFailed, message),
+            Err(error) => self.send_error(id, ErrorCode::InvalidParams, error.to_string()),
+        }
+    }
+
+    fn handle_notification(
+        &mut self,
+        notification: Notification,
+    ) -> Result<(), Box<dyn Error + Sync + Send>> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params: DidOpenTextDocumentParams =
+                    serde_json::from_value(notification.params)?;
+                let uri = params.text_document.uri;
+                self.documents
+                    .insert(uri.clone(), Document::new(params.text_document.text));
+                self.publish_diagnostics(&uri)?;
+            }
+            DidChangeTextDocument::METHOD => {
+                let params: DidChangeTextDocumentParams =
+                    serde_json::from_value(notification.params)?;
+
+                // the full text is sent since the sync kind is `FULL`.
+                if let Some(change) = params.content_changes.into_iter().last() {
+                    let uri = params.text_document.uri;
+                    self.documents
+                        .insert(uri.clone(), Document::new(change.text));
+                    self.publish_diagnostics(&uri)?;
+                }
+            }
+            DidCloseTextDocument::METHOD => {
+                let params: DidCloseTextDocumentParams =
+                    serde_json::from_value(notification.params)?;
+                let uri = params.text_document.uri;
+                self.documents.remove(&uri);
+
+                // clear the diagnostics of the closed document.
+                self.send(Message::Notification(Notification::new(
+                    PublishDiagnostics::METHOD.to_owned(),
+                    PublishDiagnosticsParams {
+                        uri,
+                        diagnostics: vec![],
+                        version: None,
+                    },
+                )))?;
+            }
+            _ => {
+                // ignore the other notifications
+            }
+        }
+
+        Ok(())
+    }
+
+    fn send(&self, message: Message) -> Result<(), Box<dyn Error + Sync + Send>> {
+        self.connection.sender.send(message)?;
+        Ok(())
+    }
+
+    fn send_error(
+        &self,
+        id: RequestId,
+        code: ErrorCode,
+        message: String,
+    ) -> Result<(), Box<dyn Error + Sync + Send>> {
+        self.send(Message::Response(Response::new_err(
+            id,
+            code as i32,
+            message,
+        )))
+    }
+
+    fn publish_diagnostics(&self, uri: &Url) -> Result<(), Box<dyn Error + Sync + Send>> {
+        let Some(document) = self.documents.get(uri) else {
+            return Ok(());
+        };
+
+        let source_file_path = uri.to_file_path().ok();
+        let diagnostics = check_source(&document.text, source_file_path.as_deref())
+            .into_iter()
+            .map(|source_diagnostic| Diagnostic {
+                range: document
+                    .text_index
+                    .get_range(source_diagnostic.index, source_diagnostic.length),
+                severity: Some(DiagnosticSeverity::ERROR),
+                code: source_diagnostic.code.map(NumberOrString::String),
+                source: Some(DIAGNOSTIC_SOURCE.to_owned()),
+                message: source_diagnostic.message,
+                ..Diagnostic::default()
+            })
+            .collect();
+
+        let params = PublishDiagnosticsParams {
+            uri: uri.clone(),
+            diagnostics,
+            version: None,
+        };
+
+        self.send(Message::Notification(Notification::new(
+            PublishDiagnostics::METHOD.to_owned(),
+            params,
+        )))
+    }
+
+    // find the document and the char index of the position.
+    fn get_document_and_char_index(
+        &self,
+        uri: &Url,
+        position: &lsp_types::Position,
+    ) -> Result<(&Document, usize), String> {
+        let document = self
+            .documents
+            .get(uri)
+            .ok_or_else(|| format!("The document \"{}\" is not opened.", uri))?;
+        let char_index = document.text_index.get_char_index(position);
+        Ok((document, char_index))
+    }
+
+    fn goto_definition(&self, params: GotoDefinitionParams) -> Result<Value, String> {
+        let position_params = params.text_document_position_params;
+        let uri = position_params.text_document.uri;
+        let (document, char_index) =
+            self.get_document_and_char_index(&uri, &position_params.position)?;
+
+        let response = document
+            .analysis
+            .find_symbol(char_index)
+            .map(|symbol_index| {
+                let symbol = &document.analysis.symbols[symbol_index];
+                GotoDefinitionResponse::Scalar(lsp_types::Location::new(
+                    uri.clone(),
+                    document.get_range(&symbol.range),
+                ))
+            });
+
+        Ok(serde_json::to_value(response).unwrap())
+    }
+
+    fn find_references(&self, params: ReferenceParams) -> Result<Value, String> {
+        let position_params = params.text_document_position;
+        let uri = position_params.text_document.uri;
+        let (document, char_index) =
+            self.get_document_and_char_index(&uri, &position_params.position)?;
+
+        let locations: Option<Vec<lsp_types::Location>> = document
+            .analysis
+            .find_symbol(char_index)
+            .map(|symbol_index| {
+                document
+                    .analysis
+                    .get_reference_ranges(symbol_index, params.context.include_declaration)
+                    .iter()
+                    .map(|range| lsp_types::Location::new(uri.clone(), document.get_range(range)))
+                    .collect()
+            });
+
+        Ok(serde_json::to_value(locations).unwrap())
+    }
+
+    fn hover(&self, params: HoverParams) -> Result<Value, String> {
+        let position_params = params.text_document_position_params;
+        let uri = position_params.text_document.uri;
+        let (document, char_index) =
+            self.get_document_and_char_index(&uri, &position_params.position)?;
+
+        let analysis = &document.analysis;
+
+        let hover = if let Some(symbol_index) = analysis.find_symbol(char_index) {
+            let symbol = &analysis.symbols[symbol_index];
+            let mut value = format!("```ancasm\n{}\n```", symbol.signature);
+            if let Some(symbol_document) = &symbol.document {
+                value.push_str("\n\n");
+                value.push_str(symbol_document);
+            }
+            Some(value)
+        } else if let Some(instruction_name) = analysis.find_instruction(char_index) {
+            find_instruction_document(instruction_name).map(|instruction_document| {
+                let mut value = format!(
+                    "```ancasm\n{}\n```\n\n{}",
+                    instruction_document.signature, instruction_document.category
+                );
+                if let Some(description) = &instruction_document.description {
+                    value.push_str("\n\n");
+                    value.push_str(description);
+                }
+                value
+            })
+        } else {
+            None
+        };
+
+        let response = hover.map(|value| Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value,
+            }),
+            range: None,
+        });
+
+        Ok(serde_json::to_value(response).unwrap())
+    }
+
+    fn complete(&self, params: CompletionParams) -> Result<Value, String> {
+        let position_params = params.text_document_position;
+        let uri = position_params.text_document.uri;
+        let (document, char_index) =
+            self.get_document_and_char_index(&uri, &position_params.position)?;
+
+        let mut items: Vec<CompletionItem> = document
+            .analysis
+            .get_visible_symbols(char_index)
+            .into_iter()
+            .map(get_symbol_completion_item)
+            .collect();
+
+        items.extend(
+            get_instruction_documents()
+                .iter()
+                .map(|instruction_document| CompletionItem {
+                    label: instruction_document.name.to_owned(),
+                    kind: Some(CompletionItemKind::KEYWORD),
+                    detail: Some(instruction_document.signature.to_owned()),
+                    ..CompletionItem::default()
+                }),
+        );
+
+        Ok(serde_json::to_value(CompletionResponse::Array(items)).unwrap())
+    }
+
+    // the printer does not preserve the ordinary comments (i.e., `//...` and `/*...*/`),
+    // so the documents which contain them are not formatted.
+    fn format(&self, params: DocumentFormattingParams) -> Result<Value, String> {
+        let uri = params.text_document.uri;
+        let document = self
+            .documents
+            .get(&uri)
+            .ok_or_else(|| format!("The document \"{}\" is not opened.", uri))?;
+
+        let tokens = lex_from_str(&document.text).map_err(|error| error.to_string())?;
+        if tokens.iter().any(|token_with_range| {
+            matches!(
+                token_with_range.token,
+                Token::Comment(Comment::Line(_) | Comment::Block(_))
+            )
+        }) {
+            return Err(
+                "The document contains comments which can not be preserved by the formatter."
+                    .to_owned(),
+            );
+        }
+
+        let module_node = parse_from_str(&document.text).map_err(|error| error.to_string())?;
+        let formatted_text = print_to_string(&module_node);
+
+        let edits = if formatted_text == document.text {
+            vec![]
+        } else {
+            vec![TextEdit::new(
+                document.text_index.get_range(0, document.text_index.len()),
+                formatted_text,
+            )]
+        };
+
+        Ok(serde_json::to_value(edits).unwrap())
+    }
+}
+
+fn get_symbol_completion_item(symbol: &Symbol) -> CompletionItem {
+    let kind = match symbol.kind {
+        SymbolKind::Function | SymbolKind::ImportFunction | SymbolKind::ExternalFunction => {
+            CompletionItemKind::FUNCTION
+        }
+        SymbolKind::Data | SymbolKind::ImportData | SymbolKind::ExternalData => {
+            CompletionItemKind::CONSTANT
+        }
+        SymbolKind::LocalVariable => CompletionItemKind::VARIABLE,
+        SymbolKind::ImportModule | SymbolKind::ExternalLibrary => CompletionItemKind::MODULE,
+    };
+
+    CompletionItem {
+        label: symbol.name.to_owned(),
+        kind: Some(kind),
+        detail: Some(symbol.signature.to_owned()),
+        documentation: symbol
+            .document
+            .as_ref()
+            .map(|document| lsp_types::Documentation::String(document.to_owned())),
+        ..CompletionItem::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use anc_assembly::printer::print_to_string;
+    use anc_parser_asm::parser::parse_from_str;
+    use lsp_server::{Connection, Message, Notification, Request, RequestId, Response};
+    use lsp_types::{
+        notification::{
+            DidOpenTextDocument, Exit, Notification as NotificationTrait, PublishDiagnostics,
+        },
+        request::{Formatting, GotoDefinition, Request as RequestTrait, Shutdown},
+        DiagnosticSeverity, DidOpenTextDocumentParams, DocumentFormattingParams, FormattingOptions,
+        GotoDefinitionParams, GotoDefinitionResponse, NumberOrString, Position,
+        PublishDiagnosticsParams, Range, TextDocumentIdentifier, TextDocumentItem,
+        TextDocumentPositionParams, TextEdit, Url,
+    };
+    use pretty_assertions::assert_eq;
+
+    use super::Server;
+
+    const SOURCE: &str = "\
+fn add(left:i32, right:i32) -> i32
+    add_i32(local_load_i32_s(left), local_load_i32_s(right))
+fn test() -> i32
+    call(add, imm_i32(11), imm_i32(13))";
+
+    fn open_document(client: &Connection, uri: &Url, text: &str) -> PublishDiagnosticsParams {
+        let params = DidOpenTextDocumentParams {
+            text_document: TextDocumentItem::new(
+                uri.clone(),
+                "ancasm".to_owned(),
+                1,
+                text.to_owned(),
+            ),
+        };
+
+        client
+            .sender
+            .send(Message::Notification(Notification::new(
+                DidOpenTextDocument::METHOD.to_owned(),
+                params,
+            )))
+            .unwrap();
+
+        match client.receiver.recv().unwrap() {
+            Message::Notification(notification) => {
+                assert_eq!(notification.method, PublishDiagnostics::METHOD);
+                serde_json::from_value(notification.params).unwrap()
+            }
+            message => panic!("Unexpected message: {:?}", message),
+        }
+    }
+
+    fn send_request(
+        client: &Connection,
+        id: i32,
+        method: &str,
+        params: serde_json::Value,
+    ) -> serde_json::Value {
+        client
+            .sender
+            .send(Message::Request(Request::new(
+                RequestId::from(id),
+                method.to_owned(),
+                params,
+            )))
+            .unwrap();
+
+        match client.receiver.recv().unwrap() {
+            Message::Response(Response {
+                id: response_id,
+                result: Some(result),
+                error: None,
+            }) => {
+                assert_eq!(response_id, RequestId::from(id));
+                result
+            }
+            message => panic!("Unexpected message: {:?}", message),
+        }
+    }
+
+    #[test]
+    fn test_server_round_trip() {
+        let (server_connection, client) = Connection::memory();
+        let server_thread = thread::spawn(move || Server::new(server_connection).run().unwrap());
+
+        // the diagnostics of the parser error
+        let broken_uri = Url::parse("untitled:broken.ancasm").unwrap();
+        let diagnostics_params = open_document(&client, &broken_uri, "fn 文foo@bar() nop()");
+
+        assert_eq!(diagnostics_params.uri, broken_uri);
+        assert_eq!(diagnostics_params.diagnostics.len(), 1);
+
+        let diagnostic = &diagnostics_params.diagnostics[0];
+        assert_eq!(
+            diagnostic.range,
+            Range::new(Position::new(0, 7), Position::new(0, 7))
+        );
+        assert_eq!(diagnostic.severity, Some(DiagnosticSeverity::ERROR));
+        assert_eq!(
+            diagnostic.code,
+            Some(NumberOrString::String("P0002".to_owned()))
+        );
+        assert_eq!(diagnostic.message, "Invalid char '@' for identifier.");
+
+        // no diagnostics for the valid source
+        let uri = Url::parse("untitled:main.ancasm").unwrap();
+        assert_eq!(open_document(&client, &uri, SOURCE).diagnostics, vec![]);
+
+        // go to the definition of `add` in `call(add, ...)`
+        let definition_params = GotoDefinitionParams {
+            text_document_position_params: TextDocumentPositionParams::new(
+                TextDocumentIdentifier::new(uri.clone()),
+                Position::new(3, 9),
+            ),
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        };
+
+        let definition: Option<GotoDefinitionResponse> = serde_json::from_value(send_request(
+            &client,
+            1,
+            GotoDefinition::METHOD,
+            serde_json::to_value(definition_params).unwrap(),
+        ))
+        .unwrap();
+
+        assert_eq!(
+            definition,
+            Some(GotoDefinitionResponse::Scalar(lsp_types::Location::new(
+                uri.clone(),
+                Range::new(Position::new(0, 3), Position::new(0, 6))
+            )))
+        );
+
+        // format the whole document
+        let formatting_params = DocumentFormattingParams {
+            text_document: TextDocumentIdentifier::new(uri.clone()),
+            options: FormattingOptions {
+                tab_size: 4,
+                insert_spaces: true,
+                ..Default::default()
+            },
+            work_done_progress_params: Default::default(),
+        };
+
+        let edits: Vec<TextEdit> = serde_json::from_value(send_request(
+            &client,
+            2,
+            Formatting::METHOD,
+            serde_json::to_value(formatting_params).unwrap(),
+        ))
+        .unwrap();
+
+        assert_eq!(
+            edits,
+            vec![TextEdit::new(
+                Range::new(Position::new(0, 0), Position::new(3, 39)),
+                print_to_string(&parse_from_str(SOURCE).unwrap())
+            )]
+        );
+
+        // shutdown
+        send_request(&client, 3, Shutdown::METHOD, serde_json::Value::Null);
+        client
+            .sender
+            .send(Message::Notification(Notification::new(
+                Exit::METHOD.to_owned(),
+                (),
+            )))
+            .unwrap();
+
+        server_thread.join().unwrap();
+    }
+}
@@ -0,0 +1,679 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the diagnostic with labelled spans, notes and suggestions, e.g.
+//
+// ```text
+// error: Duplicated identifier "add".
+//  --> main.ancasm:3:4
+//   |
+// 1 | fn add() nop()
+//   |    --- first declared by fn "add"
+// 2 |
+// 3 | fn add() nop()
+//   |    ^^^ redeclared by fn "add"
+//   |
+//   = help: rename one of the functions
+// ```
+//
+// the primary label (underlined with '^') marks the location of the error,
+// and the secondary labels (underlined with '-') mark the related locations.
+
+use std::fmt::Display;
+
+use serde::Serialize;
+
+use crate::{
+    location::Location,
+    sourcemap::{get_char_display_width, get_display_width, DisplayOptions, SourceMap},
+    ParserError,
+};
+
+const COLOR_RESET: &str = "\x1b[0m";
+const COLOR_BOLD: &str = "\x1b[1m";
+const COLOR_RED: &str = "\x1b[1;31m";
+const COLOR_YELLOW: &str = "\x1b[1;33m";
+const COLOR_BLUE: &str = "\x1b[1;34m";
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => f.write_str("error"),
+            Severity::Warning => f.write_str("warning"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum LabelStyle {
+    Primary,
+    Secondary,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Label {
+    pub style: LabelStyle,
+
+    // only the `index` and `length` are used when rendering.
+    pub location: Location,
+
+    // the message can be empty.
+    pub message: String,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+
+    // the stable code of the error, e.g. "A0001".
+    pub code: Option<String>,
+    pub message: String,
+    pub labels: Vec<Label>,
+    pub notes: Vec<String>,
+    pub suggestions: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct RenderOptions {
+    // output the ANSI colour escape codes.
+    pub colored: bool,
+
+    // the name (e.g. the file path) of the source, which is
+    // printed before the line and column.
+    pub source_name: Option<String>,
+
+    pub display_options: DisplayOptions,
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, message: &str) -> Self {
+        Self {
+            severity,
+            code: None,
+            message: message.to_owned(),
+            labels: vec![],
+            notes: vec![],
+            suggestions: vec![],
+        }
+    }
+
+    pub fn error(message: &str) -> Self {
+        Self::new(Severity::Error, message)
+    }
+
+    pub fn warning(message: &str) -> Self {
+        Self::new(Severity::Warning, message)
+    }
+
+    pub fn with_code(mut self, code: &str) -> Self {
+        self.code = Some(code.to_owned());
+        self
+    }
+
+    pub fn with_primary_label(self, location: Location, message: &str) -> Self {
+        self.with_label(LabelStyle::Primary, location, message)
+    }
+
+    pub fn with_secondary_label(self, location: Location, message: &str) -> Self {
+        self.with_label(LabelStyle::Secondary, location, message)
+    }
+
+    fn with_label(mut self, style: LabelStyle, location: Location, message: &str) -> Self {
+        self.labels.push(Label {
+            style,
+            location,
+            message: message.to_owned(),
+        });
+        self
+    }
+
+    pub fn with_note(mut self, note: &str) -> Self {
+        self.notes.push(note.to_owned());
+        self
+    }
+
+    pub fn with_suggestion(mut self, suggestion: &str) -> Self {
+        self.suggestions.push(suggestion.to_owned());
+        self
+    }
+
+    /// the primary label, or the first label if there is no primary label.
+    pub fn get_primary_label(&self) -> Option<&Label> {
+        self.labels
+            .iter()
+            .find(|label| label.style == LabelStyle::Primary)
+            .or(self.labels.first())
+    }
+
+    /// render the diagnostic with the source lines which the labels refer to.
+    pub fn render(&self, source: &str, options: &RenderOptions) -> String {
+        let painter = Painter {
+            colored: options.colored,
+        };
+        let severity_color = match self.severity {
+            Severity::Error => COLOR_RED,
+            Severity::Warning => COLOR_YELLOW,
+        };
+
+        let mut lines: Vec<String> = vec![];
+
+        // header, e.g. "error: message" or "error[A0001]: message"
+        let title = match &self.code {
+            Some(code) => format!("{}[{}]:", self.severity, code),
+            None => format!("{}:", self.severity),
+        };
+        lines.push(format!(
+            "{}{}",
+            painter.paint(&title, severity_color),
+            painter.paint(&format!(" {}", self.message), COLOR_BOLD)
+        ));
+
+        let source_map = SourceMap::new(source);
+
+        // the line indices which are referred by labels, in ascending order.
+        let mut line_indices: Vec<usize> = self
+            .labels
+            .iter()
+            .map(|label| source_map.get_line(label.location.index))
+            .collect();
+        line_indices.sort();
+        line_indices.dedup();
+
+        let gutter_width = line_indices
+            .last()
+            .map(|line_index| (line_index + 1).to_string().len())
+            .unwrap_or(1);
+        let empty_gutter = painter.paint(&format!("{} |", " ".repeat(gutter_width)), COLOR_BLUE);
+
+        if let Some(primary_label) = self.get_primary_label() {
+            // the position of the primary label, e.g. "--> main.ancasm:3:4"
+            let (line_index, column) = source_map.get_line_and_column(primary_label.location.index);
+            let source_name = match &options.source_name {
+                Some(name) => format!("{}:", name),
+                None => String::new(),
+            };
+            lines.push(format!(
+                "{}{}{}:{}",
+                " ".repeat(gutter_width),
+                painter.paint("--> ", COLOR_BLUE),
+                source_name,
+                line_index + 1,
+                column + 1
+            ));
+            lines.push(empty_gutter.clone());
+
+            let mut last_line_index: Option<usize> = None;
+            for line_index in &line_indices {
+                // the gap of one line is printed, and the
+                // longer gaps are omitted.
+                match last_line_index {
+                    Some(last) if line_index - last == 2 => {
+                        lines.push(self.render_source_line(
+                            &source_map,
+                            last + 1,
+                            gutter_width,
+                            options,
+                            &painter,
+                        ));
+                    }
+                    Some(last) if line_index - last > 2 => {
+                        lines.push(painter.paint("...", COLOR_BLUE));
+                    }
+                    _ => {}
+                }
+
+                lines.push(self.render_source_line(
+                    &source_map,
+                    *line_index,
+                    gutter_width,
+                    options,
+                    &painter,
+                ));
+                lines.extend(self.render_labels(
+                    &source_map,
+                    *line_index,
+                    &empty_gutter,
+                    options,
+                    &painter,
+                    severity_color,
+                ));
+
+                last_line_index = Some(*line_index);
+            }
+        }
+
+        // notes and suggestions, e.g. "= note: ..."
+        if !self.notes.is_empty() || !self.suggestions.is_empty() {
+            if !self.labels.is_empty() {
+                lines.push(empty_gutter);
+            }
+
+            let items = self.notes.iter().map(|note| ("note", note)).chain(
+                self.suggestions
+                    .iter()
+                    .map(|suggestion| ("help", suggestion)),
+            );
+
+            for (title, text) in items {
+                lines.push(format!(
+                    "{}{} {}",
+                    " ".repeat(gutter_width + 1),
+                    painter.paint(&format!("= {}:", title), COLOR_BOLD),
+                    text
+                ));
+            }
+        }
+
+        lines.join("\n")
+    }
+
+    // e.g. "3 | fn add() nop()"
+    fn render_source_line(
+        &self,
+        source_map: &SourceMap,
+        line_index: usize,
+        gutter_width: usize,
+        options: &RenderOptions,
+        painter: &Painter,
+    ) -> String {
+        let line_text = source_map.get_line_text(line_index).unwrap_or_default();
+
+        // expand the tabs to spaces.
+        let mut expanded_text = String::new();
+        let mut column = 0;
+        for c in line_text.chars() {
+            let width = get_char_display_width(c, column, &options.display_options);
+            match c {
+                '\t' => expanded_text.push_str(&" ".repeat(width)),
+                _ => expanded_text.push(c),
+            }
+            column += width;
+        }
+
+        let gutter = painter.paint(
+            &format!("{:>width$} |", line_index + 1, width = gutter_width),
+            COLOR_BLUE,
+        );
+
+        if expanded_text.is_empty() {
+            gutter
+        } else {
+            format!("{} {}", gutter, expanded_text)
+        }
+    }
+
+    // the underlines of the labels on the specified line, e.g.
+    // "  |    ^^^ redeclared here"
+    //
+    // each label occupies one line, and the label which spans multiple
+    // lines is underlined to the end of its first line.
+    fn render_labels(
+        &self,
+        source_map: &SourceMap,
+        line_index: usize,
+        empty_gutter: &str,
+        options: &RenderOptions,
+        painter: &Painter,
+        severity_color: &str,
+    ) -> Vec<String> {
+        let line_text = source_map.get_line_text(line_index).unwrap_or_default();
+
+        let mut labels: Vec<&Label> = self
+            .labels
+            .iter()
+            .filter(|label| source_map.get_line(label.location.index) == line_index)
+            .collect();
+        labels.sort_by_key(|label| label.location.index);
+
+        labels
+            .iter()
+            .map(|label| {
+                let (_, column) = source_map.get_line_and_column(label.location.index);
+                let line_start = label.location.index.min(source_map.len()) - column;
+                let line_end = line_start + line_text.chars().count();
+
+                let start = label.location.index.min(line_end);
+                let end = (label.location.index + label.location.length).min(line_end);
+
+                let display_options = &options.display_options;
+                let start_column =
+                    get_display_width(source_map.get_text(line_start, start), display_options);
+                let end_column =
+                    get_display_width(source_map.get_text(line_start, end), display_options);
+                let width = (end_column - start_column).max(1);
+
+                let (mark, color) = match label.style {
+                    LabelStyle::Primary => ('^', severity_color),
+                    LabelStyle::Secondary => ('-', COLOR_BLUE),
+                };
+
+                let mut underline = mark.to_string().repeat(width);
+                if !label.message.is_empty() {
+                    underline.push(' ');
+                    underline.push_str(&label.message);
+                }
+
+                format!(
+                    "{} {}{}",
+                    empty_gutter,
+                    " ".repeat(start_column),
+                    painter.paint(&underline, color)
+                )
+            })
+            .collect()
+    }
+}
+
+// the structures of the JSON output, e.g.
+//
+// ```json
+// {
+//     "file": "src/main.ancasm",
+//     "severity": "error",
+//     "code": "A0001",
+//     "message": "Unknown instruction \"foo\" in function \"main\".",
+//     "span": {
+//         "byte_start": 19, "byte_end": 22,
+//         "line_start": 2, "column_start": 5,
+//         "line_end": 2, "column_end": 8
+//     },
+//     "labels": [
+//         { "primary": true, "message": "", "span": {...} }
+//     ],
+//     "notes": [],
+//     "suggestions": []
+// }
+// ```
+//
+// the "span" is the span of the primary label, the line and column
+// are 1-based and counted in chars, and the ends are exclusive.
+#[derive(Serialize)]
+struct JsonDiagnostic<'a> {
+    file: Option<&'a str>,
+    severity: String,
+    code: Option<&'a str>,
+    message: &'a str,
+    span: Option<JsonSpan>,
+    labels: Vec<JsonLabel<'a>>,
+    notes: &'a [String],
+    suggestions: &'a [String],
+}
+
+#[derive(Serialize)]
+struct JsonLabel<'a> {
+    primary: bool,
+    message: &'a str,
+    span: JsonSpan,
+}
+
+#[derive(Serialize)]
+struct JsonSpan {
+    byte_start: usize,
+    byte_end: usize,
+    line_start: usize,
+    column_start: usize,
+    line_end: usize,
+    column_end: usize,
+}
+
+impl JsonSpan {
+    fn new(source_map: &SourceMap, location: &Location) -> Self {
+        let byte_range = source_map.get_byte_range(location);
+        let (line_start, column_start) = source_map.get_line_and_column(location.index);
+        let (line_end, column_end) =
+            source_map.get_line_and_column(location.index + location.length);
+
+        Self {
+            byte_start: byte_range.start,
+            byte_end: byte_range.end,
+            line_start: line_start + 1,
+            column_start: column_start + 1,
+            line_end: line_end + 1,
+            column_end: column_end + 1,
+        }
+    }
+}
+
+impl Diagnostic {
+    /// convert the diagnostic to a single line JSON object, so that the
+    /// tools (e.g. CI and editors) need not parse the human readable text.
+    ///
+    /// `file_path` is the path of the source file, which is output as is.
+    pub fn to_json(&self, source: &str, file_path: Option<&str>) -> String {
+        let source_map = SourceMap::new(source);
+
+        let json_diagnostic = JsonDiagnostic {
+            file: file_path,
+            severity: self.severity.to_string(),
+            code: self.code.as_deref(),
+            message: &self.message,
+            span: self
+                .get_primary_label()
+                .map(|label| JsonSpan::new(&source_map, &label.location)),
+            labels: self
+                .labels
+                .iter()
+                .map(|label| JsonLabel {
+                    primary: label.style == LabelStyle::Primary,
+                    message: &label.message,
+                    span: JsonSpan::new(&source_map, &label.location),
+                })
+                .collect(),
+            notes: &self.notes,
+            suggestions: &self.suggestions,
+        };
+
+        serde_json::to_string(&json_diagnostic).unwrap()
+    }
+}
+
+struct Painter {
+    colored: bool,
+}
+
+impl Painter {
+    fn paint(&self, text: &str, color: &str) -> String {
+        if self.colored {
+            format!("{}{}{}", color, text, COLOR_RESET)
+        } else {
+            text.to_owned()
+        }
+    }
+}
+
+impl ParserError {
+    /// convert the error to the diagnostic, the source is used for
+    /// locating the end of document.
+    pub fn to_diagnostic(&self, source: &str) -> Diagnostic {
+        let diagnostic = match self {
+            ParserError::Message(_, message) => Diagnostic::error(message),
+            ParserError::UnexpectedEndOfDocument(_, detail) => {
+                let source_map = SourceMap::new(source);
+                let index = source_map.len();
+                let (line, column) = source_map.get_line_and_column(index);
+                Diagnostic::error("Unexpected to reach the end of document.")
+                    .with_primary_label(Location::new_position(index, line, column), detail)
+            }
+            ParserError::MessageWithLocation(_, message, location) => {
+                Diagnostic::error(message).with_primary_label(*location, "")
+            }
+        };
+
+        diagnostic.with_code(self.get_code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::{location::Location, ParserError, ParserErrorKind};
+
+    use super::{Diagnostic, RenderOptions};
+
+    #[test]
+    fn test_render_diagnostic() {
+        let source = "fn add() nop()\n\nfn add() nop()";
+
+        let diagnostic = Diagnostic::error("Duplicated identifier \"add\".")
+            .with_code("A0027")
+            .with_secondary_label(Location::new_range(3, 0, 3, 3), "first defined here")
+            .with_primary_label(Location::new_range(19, 2, 3, 3), "redefined here")
+            .with_suggestion("rename one of the functions");
+
+        assert_eq!(
+            diagnostic.render(
+                source,
+                &RenderOptions {
+                    source_name: Some("main.ancasm".to_owned()),
+                    ..RenderOptions::default()
+                }
+            ),
+            "\
+error[A0027]: Duplicated identifier \"add\".
+ --> main.ancasm:3:4
+  |
+1 | fn add() nop()
+  |    --- first defined here
+2 |
+3 | fn add() nop()
+  |    ^^^ redefined here
+  |
+  = help: rename one of the functions"
+        );
+
+        // colored
+        assert_eq!(
+            Diagnostic::error("foo")
+                .with_primary_label(Location::new_range(0, 0, 0, 2), "bar")
+                .with_note("baz")
+                .render(
+                    "fn",
+                    &RenderOptions {
+                        colored: true,
+                        ..RenderOptions::default()
+                    }
+                ),
+            "\
+\x1b[1;31merror:\x1b[0m\x1b[1m foo\x1b[0m
+ \x1b[1;34m--> \x1b[0m1:1
+\x1b[1;34m  |\x1b[0m
+\x1b[1;34m1 |\x1b[0m fn
+\x1b[1;34m  |\x1b[0m \x1b[1;31m^^ bar\x1b[0m
+\x1b[1;34m  |\x1b[0m
+  \x1b[1m= note:\x1b[0m baz"
+        );
+    }
+
+    #[test]
+    fn test_render_diagnostic_with_multiple_lines() {
+        let source = "\
+fn foo()
+{
+    nop()
+    nop()
+}
+fn\tbar() 文nop()";
+
+        // the gap lines are omitted, and the labels on the same
+        // line are sorted by the columns.
+        let diagnostic = Diagnostic::warning("message")
+            .with_primary_label(Location::new_range(43, 5, 10, 3), "primary")
+            .with_secondary_label(Location::new_range(3, 0, 3, 3), "")
+            .with_secondary_label(Location::new_range(36, 5, 3, 3), "secondary");
+
+        assert_eq!(
+            diagnostic.render(source, &RenderOptions::default()),
+            "\
+warning: message
+ --> 6:11
+  |
+1 | fn foo()
+  |    ---
+...
+6 | fn  bar() 文nop()
+  |     --- secondary
+  |             ^^^ primary"
+        );
+
+        // multiple lines span
+        let diagnostic =
+            Diagnostic::error("message").with_primary_label(Location::new_range(9, 1, 0, 10), "");
+
+        assert_eq!(
+            diagnostic.render(source, &RenderOptions::default()),
+            "\
+error: message
+ --> 2:1
+  |
+2 | {
+  | ^"
+        );
+    }
+
+    #[test]
+    fn test_diagnostic_to_json() {
+        let source = "fn foo()\n  文nop(";
+
+        let diagnostic = Diagnostic::error("message")
+            .with_code("P0001")
+            .with_primary_label(Location::new_range(12, 1, 3, 3), "primary")
+            .with_secondary_label(Location::new_range(3, 0, 3, 3), "")
+            .with_note("note");
+
+        assert_eq!(
+            diagnostic.to_json(source, Some("src/main.ancasm")),
+            r#"{"file":"src/main.ancasm","severity":"error","code":"P0001","message":"message","span":{"byte_start":14,"byte_end":17,"line_start":2,"column_start":4,"line_end":2,"column_end":7},"labels":[{"primary":true,"message":"primary","span":{"byte_start":14,"byte_end":17,"line_start":2,"column_start":4,"line_end":2,"column_end":7}},{"primary":false,"message":"","span":{"byte_start":3,"byte_end":6,"line_start":1,"column_start":4,"line_end":1,"column_end":7}}],"notes":["note"],"suggestions":[]}"#
+        );
+
+        assert_eq!(
+            Diagnostic::warning("message").to_json(source, None),
+            r#"{"file":null,"severity":"warning","code":null,"message":"message","span":null,"labels":[],"notes":[],"suggestions":[]}"#
+        );
+    }
+
+    #[test]
+    fn test_parser_error_to_diagnostic() {
+        let source = "fn foo()\n  nop(";
+
+        assert_eq!(
+            ParserError::UnexpectedEndOfDocument(
+                ParserErrorKind::UnexpectedToken,
+                "Expect \")\".".to_owned()
+            )
+            .to_diagnostic(source)
+            .render(source, &RenderOptions::default()),
+            "\
+error[P0012]: Unexpected to reach the end of document.
+ --> 2:7
+  |
+2 |   nop(
+  |       ^ Expect \")\"."
+        );
+
+        assert_eq!(
+            ParserError::MessageWithLocation(
+                ParserErrorKind::Syntax,
+                "Invalid char.".to_owned(),
+                Location::new_range(3, 0, 3, 3)
+            )
+            .to_diagnostic(source)
+            .render(source, &RenderOptions::default()),
+            "\
+error[P0000]: Invalid char.
+ --> 1:4
+  |
+1 | fn foo()
+  |    ^^^"
+        );
+    }
+}
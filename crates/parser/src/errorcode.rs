@@ -0,0 +1,217 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the registry of the stable error codes.
+//
+// the entries (the codes, titles and explanations) are extracted from
+// the file "docs/error-codes.md", so that the explanations are always
+// consistent with the documentation.
+//
+// the codes starting with 'P' are the errors of the parser, and the codes
+// starting with 'A' are the errors of the assembler (see
+// `AssembleErrorType::get_code` of the crate "anc-assembler").
+
+use std::sync::OnceLock;
+
+use crate::{ParserError, ParserErrorKind};
+
+const ERROR_CODES_DOCUMENT: &str = include_str!("../../../docs/error-codes.md");
+
+/// the code for the syntax errors which have no specific code.
+pub const GENERIC_SYNTAX_ERROR_CODE: &str = "P0000";
+
+#[derive(Debug, PartialEq)]
+pub struct ErrorCodeEntry {
+    // e.g. "P0010"
+    pub code: String,
+
+    // e.g. "Unterminated string"
+    pub title: String,
+
+    // the long-form explanation (in Markdown) with the example fix.
+    pub explanation: String,
+}
+
+pub fn get_error_code_entries() -> &'static [ErrorCodeEntry] {
+    static ERROR_CODE_ENTRIES: OnceLock<Vec<ErrorCodeEntry>> = OnceLock::new();
+    ERROR_CODE_ENTRIES.get_or_init(|| parse_error_code_entries(ERROR_CODES_DOCUMENT))
+}
+
+/// get the entry of the error code, the code is case-insensitive, e.g. "p0010".
+pub fn explain(code: &str) -> Option<&'static ErrorCodeEntry> {
+    get_error_code_entries()
+        .iter()
+        .find(|entry| entry.code.eq_ignore_ascii_case(code.trim()))
+}
+
+// the entries are the sections with title "## CODE: Title".
+fn parse_error_code_entries(text: &str) -> Vec<ErrorCodeEntry> {
+    text.split("\n## ")
+        .skip(1)
+        .filter_map(|section| {
+            let (title_line, explanation) = section.split_once('\n').unwrap_or((section, ""));
+            let (code, title) = title_line.split_once(':')?;
+            Some(ErrorCodeEntry {
+                code: code.trim().to_owned(),
+                title: title.trim().to_owned(),
+                explanation: explanation.trim().to_owned(),
+            })
+        })
+        .collect()
+}
+
+impl ParserErrorKind {
+    /// get the stable code of the kind, e.g. "P0010" for the unterminated string.
+    pub fn get_code(&self) -> &'static str {
+        match self {
+            ParserErrorKind::Syntax => GENERIC_SYNTAX_ERROR_CODE,
+            ParserErrorKind::UnexpectedChar => "P0001",
+            ParserErrorKind::InvalidIdentifierChar => "P0002",
+            ParserErrorKind::InvalidNumber => "P0003",
+            ParserErrorKind::NumberOutOfRange => "P0004",
+            ParserErrorKind::InvalidSign => "P0005",
+            ParserErrorKind::InvalidChar => "P0006",
+            ParserErrorKind::InvalidEscapeSequence => "P0007",
+            ParserErrorKind::InvalidHexByteData => "P0008",
+            ParserErrorKind::InvalidAutoTrimmedString => "P0009",
+            ParserErrorKind::UnterminatedString => "P0010",
+            ParserErrorKind::UnterminatedBlockComment => "P0011",
+            ParserErrorKind::UnexpectedToken => "P0012",
+            ParserErrorKind::MisplacedDocument => "P0013",
+            ParserErrorKind::ReexportedImportModule => "P0014",
+            ParserErrorKind::DataSectionInGlobImport => "P0015",
+            ParserErrorKind::UnsupportedDataType => "P0016",
+            ParserErrorKind::InvalidIncludeFileArgument => "P0017",
+            ParserErrorKind::InvalidFillValue => "P0018",
+            ParserErrorKind::InvalidRepetitionCount => "P0019",
+            ParserErrorKind::EmptyImportGroup => "P0020",
+        }
+    }
+}
+
+impl ParserError {
+    pub fn get_kind(&self) -> ParserErrorKind {
+        match self {
+            ParserError::Message(kind, _)
+            | ParserError::UnexpectedEndOfDocument(kind, _)
+            | ParserError::MessageWithLocation(kind, _, _) => *kind,
+        }
+    }
+
+    /// get the stable code of the error, e.g. "P0010" for the unterminated string.
+    pub fn get_code(&self) -> &'static str {
+        self.get_kind().get_code()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::{parser::parse_from_str, ParserError, ParserErrorKind};
+
+    use super::{explain, get_error_code_entries};
+
+    #[test]
+    fn test_error_code_registry() {
+        let entries = get_error_code_entries();
+
+        // the codes are unique
+        for (index, entry) in entries.iter().enumerate() {
+            assert!(
+                entries[index + 1..]
+                    .iter()
+                    .all(|other| other.code != entry.code),
+                "Duplicated error code \"{}\".",
+                entry.code
+            );
+            assert!(!entry.title.is_empty());
+            assert!(!entry.explanation.is_empty());
+        }
+
+        // all parser error codes are registered
+        let kinds = [
+            ParserErrorKind::Syntax,
+            ParserErrorKind::UnexpectedChar,
+            ParserErrorKind::InvalidIdentifierChar,
+            ParserErrorKind::InvalidNumber,
+            ParserErrorKind::NumberOutOfRange,
+            ParserErrorKind::InvalidSign,
+            ParserErrorKind::InvalidChar,
+            ParserErrorKind::InvalidEscapeSequence,
+            ParserErrorKind::InvalidHexByteData,
+            ParserErrorKind::InvalidAutoTrimmedString,
+            ParserErrorKind::UnterminatedString,
+            ParserErrorKind::UnterminatedBlockComment,
+            ParserErrorKind::UnexpectedToken,
+            ParserErrorKind::MisplacedDocument,
+            ParserErrorKind::ReexportedImportModule,
+            ParserErrorKind::DataSectionInGlobImport,
+            ParserErrorKind::UnsupportedDataType,
+            ParserErrorKind::InvalidIncludeFileArgument,
+            ParserErrorKind::InvalidFillValue,
+            ParserErrorKind::InvalidRepetitionCount,
+            ParserErrorKind::EmptyImportGroup,
+        ];
+
+        for kind in kinds {
+            let code = kind.get_code();
+            assert!(explain(code).is_some(), "Unregistered code \"{}\".", code);
+        }
+
+        let entry = explain("p0010").unwrap();
+        assert_eq!(entry.code, "P0010");
+        assert_eq!(entry.title, "Unterminated string");
+        assert!(entry.explanation.contains("Fix:"));
+
+        assert!(explain("P9999").is_none());
+    }
+
+    #[test]
+    fn test_parser_error_code() {
+        let get_code = |source: &str| parse_from_str(source).unwrap_err().get_code();
+
+        assert_eq!(get_code("fn foo() @nop()"), "P0001");
+        assert_eq!(get_code("fn foo$bar() nop()"), "P0002");
+        assert_eq!(get_code("data foo:i32 = 0x"), "P0003");
+        assert_eq!(get_code("data foo:i8 = 256_i8"), "P0004");
+        assert_eq!(get_code("data foo:i32 = -'a'"), "P0005");
+        assert_eq!(get_code("data foo:i32 = 'ab'"), "P0006");
+        assert_eq!(get_code(r#"data foo:byte[] = "\x41""#), "P0007");
+        assert_eq!(get_code(r#"data foo:byte[] = h"1113 1g""#), "P0008");
+        assert_eq!(get_code(r#"data foo:byte[] = h"11 13"#), "P0008");
+        assert_eq!(
+            get_code("data foo:byte[] = \"\"\"hello\n    world\n    \"\"\""),
+            "P0009"
+        );
+        assert_eq!(get_code(r#"data foo:byte[] = "hello"#), "P0010");
+        assert_eq!(get_code("/* outer /* inner */"), "P0011");
+        assert_eq!(get_code("/* outer /* inner */\nfn foo() nop()"), "P0011");
+        assert_eq!(get_code("import math::add(i32,i32)->i32"), "P0012");
+        assert_eq!(
+            get_code("/// Does nothing.\n//! Utilities for strings.\nfn foo() nop()"),
+            "P0013"
+        );
+        assert_eq!(
+            get_code(r#"pub import module mymod from "modules/mymod""#),
+            "P0014"
+        );
+        assert_eq!(get_code("import readonly data mymod::*"), "P0015");
+        assert_eq!(get_code("fn foo(buf:byte[16]) nop()"), "P0016");
+        assert_eq!(
+            get_code(r#"data foo:byte[] = include_bytes("table.bin", start=8)"#),
+            "P0017"
+        );
+        assert_eq!(get_code("data foo:byte[] = fill(0_i8)"), "P0018");
+        assert_eq!(get_code("data foo:byte[] = [0_i32; 0]"), "P0019");
+        assert_eq!(get_code("import fn std::memory::{}"), "P0020");
+
+        assert_eq!(
+            ParserError::Message(ParserErrorKind::Syntax, "Something wrong.".to_owned()).get_code(),
+            "P0000"
+        );
+    }
+}
@@ -4,7 +4,10 @@
 // the Mozilla Public License version 2.0 and additional exceptions,
 // more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
 
-use crate::ParserError;
+use crate::{
+    sourcemap::{get_char_display_width, DisplayOptions},
+    ParserError,
+};
 
 //                 /-- selection start
 //                 |                 /-- selection length
@@ -73,38 +76,60 @@ fn generate_snippet_and_indented_detail(
     chars: &mut dyn Iterator<Item = char>,
     snippet_range: &SnippetRange,
     detail: &str,
+    display_options: &DisplayOptions,
 ) -> (String, String) {
     // build snippet
+    //
+    // the tabs are expanded to spaces (the tab stops are counted from the
+    // start of the selection), and the line breaks are replaced with spaces,
+    // the display column of each char is recorded for aligning the underline.
     let mut snippet = String::new();
     snippet.push_str("| ");
     if snippet_range.prefix {
         snippet.push_str("...");
     }
+
     let selection_chars = chars
         .skip(snippet_range.selection_start)
         .take(snippet_range.selection_length);
-    let selection_string = selection_chars
-        .map(|c| match c {
-            '\n' => ' ',
-            '\t' => ' ',
-            _ => c,
-        })
-        .collect::<String>();
-    snippet.push_str(&selection_string);
+
+    // the display columns of each char and the end of selection.
+    let mut columns: Vec<usize> = vec![];
+    let mut column = 0;
+    for c in selection_chars {
+        let width = get_char_display_width(c, column, display_options);
+        match c {
+            '\n' | '\r' | '\t' => snippet.push_str(&" ".repeat(width)),
+            _ => snippet.push(c),
+        }
+        columns.push(column);
+        column += width;
+    }
+    columns.push(column);
+
     if snippet_range.suffix {
         snippet.push_str("...");
     }
 
+    // the offset may exceed the end of selection (e.g. the error of incomplete char `'a`),
+    // each exceeded char is counted as one column.
+    let get_column = |char_offset: usize| match columns.get(char_offset) {
+        Some(column) => *column,
+        None => column + (char_offset + 1 - columns.len()),
+    };
+    let underline_start = get_column(snippet_range.offset);
+    let underline_width = get_column(snippet_range.offset + snippet_range.length) - underline_start;
+
     // build indented detail
     let mut indented_detail = String::new();
     indented_detail.push_str("| ");
     if snippet_range.prefix {
         indented_detail.push_str("   ");
     }
-    indented_detail.push_str(&" ".repeat(snippet_range.offset));
+    indented_detail.push_str(&" ".repeat(underline_start));
     indented_detail.push('^');
     if snippet_range.length > 0 {
-        indented_detail.push_str(&"^".repeat(snippet_range.length - 1));
+        indented_detail.push_str(&"^".repeat(underline_width.max(1) - 1));
     } else {
         indented_detail.push_str("____");
     }
@@ -115,9 +140,18 @@ fn generate_snippet_and_indented_detail(
 }
 
 impl ParserError {
+    /// print human readable error message with the source.
     pub fn with_source(&self, source: &str) -> String {
-        // print human readable error message with the source
+        self.with_source_and_display_options(source, &DisplayOptions::default())
+    }
 
+    /// print human readable error message with the source, the tab width and
+    /// the width of ambiguous chars are specified by `display_options`.
+    pub fn with_source_and_display_options(
+        &self,
+        source: &str,
+        display_options: &DisplayOptions,
+    ) -> String {
         let source_total_length = source.chars().count();
         let mut chars = source.chars();
 
@@ -130,16 +164,20 @@ impl ParserError {
         // | snippet length
 
         match self {
-            ParserError::Message(msg) => msg.to_owned(),
-            ParserError::UnexpectedEndOfDocument(detail) => {
+            ParserError::Message(_, msg) => msg.to_owned(),
+            ParserError::UnexpectedEndOfDocument(_, detail) => {
                 let msg = "Unexpected to reach the end of document.";
                 let snippet_range =
                     calculate_snippet_range(source_total_length, 0, source_total_length);
-                let (snippet, indented_detail) =
-                    generate_snippet_and_indented_detail(&mut chars, &snippet_range, detail);
+                let (snippet, indented_detail) = generate_snippet_and_indented_detail(
+                    &mut chars,
+                    &snippet_range,
+                    detail,
+                    display_options,
+                );
                 format!("{}\n{}\n{}", msg, snippet, indented_detail)
             }
-            ParserError::MessageWithLocation(detail, location) => {
+            ParserError::MessageWithLocation(_, detail, location) => {
                 let msg = format!(
                     "Error at line: {}, column: {}",
                     location.line + 1,
@@ -148,8 +186,12 @@ impl ParserError {
 
                 let snippet_range =
                     calculate_snippet_range(location.index, location.length, source_total_length);
-                let (snippet, indented_detail) =
-                    generate_snippet_and_indented_detail(&mut chars, &snippet_range, detail);
+                let (snippet, indented_detail) = generate_snippet_and_indented_detail(
+                    &mut chars,
+                    &snippet_range,
+                    detail,
+                    display_options,
+                );
                 format!("{}\n{}\n{}", msg, snippet, indented_detail)
             }
         }
@@ -161,7 +203,7 @@ mod tests {
 
     use pretty_assertions::assert_eq;
 
-    use crate::{location::Location, ParserError};
+    use crate::{location::Location, sourcemap::DisplayOptions, ParserError, ParserErrorKind};
 
     #[test]
     fn test_error_with_source() {
@@ -170,11 +212,11 @@ mod tests {
         let msg = "abcde";
 
         assert_eq!(
-            ParserError::Message(msg.to_owned()).with_source(source1),
+            ParserError::Message(ParserErrorKind::Syntax, msg.to_owned()).with_source(source1),
             msg
         );
         assert_eq!(
-            ParserError::Message(msg.to_owned()).with_source(source2),
+            ParserError::Message(ParserErrorKind::Syntax, msg.to_owned()).with_source(source2),
             msg
         );
     }
@@ -186,14 +228,16 @@ mod tests {
         let msg = "abcde";
 
         assert_eq!(
-            ParserError::UnexpectedEndOfDocument(msg.to_owned()).with_source(source1),
+            ParserError::UnexpectedEndOfDocument(ParserErrorKind::Syntax, msg.to_owned())
+                .with_source(source1),
             r#"Unexpected to reach the end of document.
 | 0123456789
 |           ^____ abcde"#
         );
 
         assert_eq!(
-            ParserError::UnexpectedEndOfDocument(msg.to_owned()).with_source(source2),
+            ParserError::UnexpectedEndOfDocument(ParserErrorKind::Syntax, msg.to_owned())
+                .with_source(source2),
             r#"Unexpected to reach the end of document.
 | ...b12345678_c12345678_d12345678_e123456789
 |                                            ^____ abcde"#
@@ -210,6 +254,7 @@ mod tests {
 
         assert_eq!(
             ParserError::MessageWithLocation(
+                ParserErrorKind::Syntax,
                 msg.to_owned(),
                 Location::new_position(/*0,*/ 0, 11, 13)
             )
@@ -221,6 +266,7 @@ mod tests {
 
         assert_eq!(
             ParserError::MessageWithLocation(
+                ParserErrorKind::Syntax,
                 msg.to_owned(),
                 Location::new_position(/*0,*/ 0, 11, 13)
             )
@@ -234,6 +280,7 @@ mod tests {
 
         assert_eq!(
             ParserError::MessageWithLocation(
+                ParserErrorKind::Syntax,
                 msg.to_owned(),
                 Location::new_position(/*0,*/ 2, 11, 13)
             )
@@ -245,6 +292,7 @@ mod tests {
 
         assert_eq!(
             ParserError::MessageWithLocation(
+                ParserErrorKind::Syntax,
                 msg.to_owned(),
                 Location::new_position(/*0,*/ 15, 11, 13)
             )
@@ -258,6 +306,7 @@ mod tests {
 
         assert_eq!(
             ParserError::MessageWithLocation(
+                ParserErrorKind::Syntax,
                 msg.to_owned(),
                 Location::new_position(/*0,*/ 5, 11, 13)
             )
@@ -269,6 +318,7 @@ mod tests {
 
         assert_eq!(
             ParserError::MessageWithLocation(
+                ParserErrorKind::Syntax,
                 msg.to_owned(),
                 Location::new_position(/*0,*/ 25, 11, 13)
             )
@@ -282,6 +332,7 @@ mod tests {
 
         assert_eq!(
             ParserError::MessageWithLocation(
+                ParserErrorKind::Syntax,
                 msg.to_owned(),
                 Location::new_position(/*0,*/ 8, 11, 13)
             )
@@ -293,6 +344,7 @@ mod tests {
 
         assert_eq!(
             ParserError::MessageWithLocation(
+                ParserErrorKind::Syntax,
                 msg.to_owned(),
                 Location::new_position(/*0,*/ 45, 11, 13)
             )
@@ -306,6 +358,7 @@ mod tests {
 
         assert_eq!(
             ParserError::MessageWithLocation(
+                ParserErrorKind::Syntax,
                 msg.to_owned(),
                 Location::new_position(/*0,*/ 10, 11, 13)
             )
@@ -317,6 +370,7 @@ mod tests {
 
         assert_eq!(
             ParserError::MessageWithLocation(
+                ParserErrorKind::Syntax,
                 msg.to_owned(),
                 Location::new_position(/*0,*/ 50, 11, 13)
             )
@@ -327,6 +381,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_error_with_source_and_wide_chars() {
+        let source = "\t文字\tabc"; // 7 chars
+        let msg = "abcde";
+
+        // the tab is expanded to the next tab stop, and the wide chars occupy two columns.
+        assert_eq!(
+            ParserError::MessageWithLocation(
+                ParserErrorKind::Syntax,
+                msg.to_owned(),
+                Location::new_range(4, 0, 4, 3)
+            )
+            .with_source(source),
+            "Error at line: 1, column: 5
+|     文字    abc
+|             ^^^ abcde"
+        );
+
+        assert_eq!(
+            ParserError::MessageWithLocation(
+                ParserErrorKind::Syntax,
+                msg.to_owned(),
+                Location::new_range(1, 0, 1, 2)
+            )
+            .with_source(source),
+            "Error at line: 1, column: 2
+|     文字    abc
+|     ^^^^ abcde"
+        );
+
+        // the tab width
+        assert_eq!(
+            ParserError::MessageWithLocation(
+                ParserErrorKind::Syntax,
+                msg.to_owned(),
+                Location::new_range(4, 0, 4, 3)
+            )
+            .with_source_and_display_options(
+                source,
+                &DisplayOptions {
+                    tab_width: 2,
+                    ..DisplayOptions::default()
+                }
+            ),
+            "Error at line: 1, column: 5
+|   文字  abc
+|         ^^^ abcde"
+        );
+    }
+
     #[test]
     fn test_error_with_source_and_range() {
         let source1 = "0123456789"; // 10 chars
@@ -337,6 +441,7 @@ mod tests {
 
         assert_eq!(
             ParserError::MessageWithLocation(
+                ParserErrorKind::Syntax,
                 msg.to_owned(),
                 Location::new_range(/*0,*/ 0, 17, 19, 4)
             )
@@ -348,6 +453,7 @@ mod tests {
 
         assert_eq!(
             ParserError::MessageWithLocation(
+                ParserErrorKind::Syntax,
                 msg.to_owned(),
                 Location::new_range(/*0,*/ 0, 17, 19, 8)
             )
@@ -361,6 +467,7 @@ mod tests {
 
         assert_eq!(
             ParserError::MessageWithLocation(
+                ParserErrorKind::Syntax,
                 msg.to_owned(),
                 Location::new_range(/*0,*/ 2, 17, 19, 4)
             )
@@ -372,6 +479,7 @@ mod tests {
 
         assert_eq!(
             ParserError::MessageWithLocation(
+                ParserErrorKind::Syntax,
                 msg.to_owned(),
                 Location::new_range(/*0,*/ 15, 17, 19, 8)
             )
@@ -385,6 +493,7 @@ mod tests {
 
         assert_eq!(
             ParserError::MessageWithLocation(
+                ParserErrorKind::Syntax,
                 msg.to_owned(),
                 Location::new_range(/*0,*/ 5, 17, 19, 4)
             )
@@ -396,6 +505,7 @@ mod tests {
 
         assert_eq!(
             ParserError::MessageWithLocation(
+                ParserErrorKind::Syntax,
                 msg.to_owned(),
                 Location::new_range(/*0,*/ 25, 17, 19, 8)
             )
@@ -409,6 +519,7 @@ mod tests {
 
         assert_eq!(
             ParserError::MessageWithLocation(
+                ParserErrorKind::Syntax,
                 msg.to_owned(),
                 Location::new_range(/*0,*/ 8, 17, 19, 4)
             )
@@ -420,6 +531,7 @@ mod tests {
 
         assert_eq!(
             ParserError::MessageWithLocation(
+                ParserErrorKind::Syntax,
                 msg.to_owned(),
                 Location::new_range(/*0,*/ 45, 17, 19, 8)
             )
@@ -433,6 +545,7 @@ mod tests {
 
         assert_eq!(
             ParserError::MessageWithLocation(
+                ParserErrorKind::Syntax,
                 msg.to_owned(),
                 Location::new_range(/*0,*/ 10, 17, 19, 4)
             )
@@ -444,6 +557,7 @@ mod tests {
 
         assert_eq!(
             ParserError::MessageWithLocation(
+                ParserErrorKind::Syntax,
                 msg.to_owned(),
                 Location::new_range(/*0,*/ 50, 17, 19, 8)
             )
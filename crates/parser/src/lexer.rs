@@ -11,7 +11,7 @@ use crate::{
     location::Location,
     peekableiter::PeekableIter,
     token::{NumberToken, NumberType},
-    ParserError,
+    ParserError, ParserErrorKind,
 };
 
 use super::token::{Comment, Token, TokenWithRange};
@@ -143,6 +143,15 @@ impl Lexer<'_> {
                         1,
                     ));
                 }
+                ';' => {
+                    self.next_char(); // consume ';'
+
+                    token_with_ranges.push(TokenWithRange::from_position_and_length(
+                        Token::Semicolon,
+                        &self.last_position,
+                        1,
+                    ));
+                }
                 '-' if self.peek_char_and_equals(1, '>') => {
                     self.push_peek_position();
 
@@ -256,10 +265,10 @@ impl Lexer<'_> {
 
                     token_with_ranges.push(twr);
                 }
-                // '\'' => {
-                //     // char
-                //     token_with_ranges.push(self.lex_char()?);
-                // }
+                '\'' => {
+                    // char
+                    token_with_ranges.push(self.lex_char()?);
+                }
                 '/' if self.peek_char_and_equals(1, '/') => {
                     // line comment
                     token_with_ranges.push(self.lex_line_comment()?);
@@ -274,6 +283,7 @@ impl Lexer<'_> {
                 }
                 current_char => {
                     return Err(ParserError::MessageWithLocation(
+                        ParserErrorKind::UnexpectedChar,
                         format!("Unexpected char '{}'.", current_char),
                         *self.peek_position(0).unwrap(),
                     ));
@@ -323,6 +333,12 @@ impl Lexer<'_> {
                     self.next_char(); // consume the 1st ":"
                     self.next_char(); // consume the 2nd ":"
                 }
+                '*' if name_string.ends_with("::") => {
+                    // the glob import, e.g. `import fn std::memory::*`
+                    name_string.push('*');
+                    self.next_char(); // consume '*'
+                    break;
+                }
                 '\u{a0}'..='\u{d7ff}' | '\u{e000}'..='\u{10ffff}' => {
                     // A char is a ‘Unicode scalar value’, which is any ‘Unicode code point’ other than a surrogate code point.
                     // This has a fixed numerical definition: code points are in the range 0 to 0x10FFFF,
@@ -359,13 +375,14 @@ impl Lexer<'_> {
                     name_string.push(*current_char);
                     self.next_char(); // consume char
                 }
-                ' ' | '\t' | '\r' | '\n' | ',' | ':' | '=' | '+' | '-' | '{' | '}' | '[' | ']'
-                | '(' | ')' | '/' | '"' => {
+                ' ' | '\t' | '\r' | '\n' | ',' | ':' | ';' | '=' | '+' | '-' | '{' | '}' | '['
+                | ']' | '(' | ')' | '/' | '"' => {
                     // terminator chars
                     break;
                 }
                 _ => {
                     return Err(ParserError::MessageWithLocation(
+                        ParserErrorKind::InvalidIdentifierChar,
                         format!("Invalid char '{}' for identifier.", current_char),
                         *self.peek_position(0).unwrap(),
                     ));
@@ -478,13 +495,14 @@ impl Lexer<'_> {
                     num_type.replace(nt);
                     break;
                 }
-                ' ' | '\t' | '\r' | '\n' | ',' | ':' | '=' | '+' | '-' | '{' | '}' | '[' | ']'
-                | '(' | ')' | '/' | '"' => {
+                ' ' | '\t' | '\r' | '\n' | ',' | ':' | ';' | '=' | '+' | '-' | '{' | '}' | '['
+                | ']' | '(' | ')' | '/' | '"' => {
                     // terminator chars
                     break;
                 }
                 _ => {
                     return Err(ParserError::MessageWithLocation(
+                        ParserErrorKind::InvalidNumber,
                         format!("Invalid char '{}' for decimal number.", current_char),
                         *self.peek_position(0).unwrap(),
                     ));
@@ -495,6 +513,7 @@ impl Lexer<'_> {
         // check syntax
         if num_string.ends_with('.') {
             return Err(ParserError::MessageWithLocation(
+                ParserErrorKind::InvalidNumber,
                 "Decimal number can not ends with \".\".".to_owned(),
                 self.last_position,
             ));
@@ -502,6 +521,7 @@ impl Lexer<'_> {
 
         if num_string.ends_with('e') {
             return Err(ParserError::MessageWithLocation(
+                ParserErrorKind::InvalidNumber,
                 "Decimal number can not ends with \"e\".".to_owned(),
                 self.last_position,
             ));
@@ -518,6 +538,7 @@ impl Lexer<'_> {
                 NumberType::I8 => {
                     let v = num_string.parse::<u8>().map_err(|_| {
                         ParserError::MessageWithLocation(
+                            ParserErrorKind::NumberOutOfRange,
                             format!("Can not convert \"{}\" to i8 integer number.", num_string),
                             num_range,
                         )
@@ -528,6 +549,7 @@ impl Lexer<'_> {
                 NumberType::I16 => {
                     let v = num_string.parse::<u16>().map_err(|_| {
                         ParserError::MessageWithLocation(
+                            ParserErrorKind::NumberOutOfRange,
                             format!("Can not convert \"{}\" to i16 integer number.", num_string),
                             num_range,
                         )
@@ -538,6 +560,7 @@ impl Lexer<'_> {
                 NumberType::I32 => {
                     let v = num_string.parse::<u32>().map_err(|_| {
                         ParserError::MessageWithLocation(
+                            ParserErrorKind::NumberOutOfRange,
                             format!("Can not convert \"{}\" to i32 integer number.", num_string),
                             num_range,
                         )
@@ -548,6 +571,7 @@ impl Lexer<'_> {
                 NumberType::I64 => {
                     let v = num_string.parse::<u64>().map_err(|_| {
                         ParserError::MessageWithLocation(
+                            ParserErrorKind::NumberOutOfRange,
                             format!("Can not convert \"{}\" to i64 integer number.", num_string),
                             num_range,
                         )
@@ -558,6 +582,7 @@ impl Lexer<'_> {
                 NumberType::F32 => {
                     let v = num_string.parse::<f32>().map_err(|_| {
                         ParserError::MessageWithLocation(
+                            ParserErrorKind::NumberOutOfRange,
                             format!(
                                 "Can not convert \"{}\" to f32 floating-point number.",
                                 num_string
@@ -569,6 +594,7 @@ impl Lexer<'_> {
                     // overflow when parsing from string
                     if v.is_infinite() {
                         return Err(ParserError::MessageWithLocation(
+                            ParserErrorKind::NumberOutOfRange,
                             format!("F32 floating point number \"{}\" is overflow.", num_string),
                             num_range,
                         ));
@@ -579,6 +605,7 @@ impl Lexer<'_> {
                 NumberType::F64 => {
                     let v = num_string.parse::<f64>().map_err(|_| {
                         ParserError::MessageWithLocation(
+                            ParserErrorKind::NumberOutOfRange,
                             format!(
                                 "Can not convert \"{}\" to f64 floating-point number.",
                                 num_string
@@ -590,6 +617,7 @@ impl Lexer<'_> {
                     // overflow when parsing from string
                     if v.is_infinite() {
                         return Err(ParserError::MessageWithLocation(
+                            ParserErrorKind::NumberOutOfRange,
                             format!("F64 floating point number \"{}\" is overflow.", num_string),
                             num_range,
                         ));
@@ -603,6 +631,7 @@ impl Lexer<'_> {
 
             let v = num_string.parse::<f64>().map_err(|_| {
                 ParserError::MessageWithLocation(
+                    ParserErrorKind::NumberOutOfRange,
                     format!(
                         "Can not convert \"{}\" to f64 floating-point number.",
                         num_string
@@ -614,6 +643,7 @@ impl Lexer<'_> {
             // overflow when parsing from string
             if v.is_infinite() {
                 return Err(ParserError::MessageWithLocation(
+                    ParserErrorKind::NumberOutOfRange,
                     format!("F64 floating point number \"{}\" is overflow.", num_string),
                     num_range,
                 ));
@@ -625,6 +655,7 @@ impl Lexer<'_> {
 
             let v = num_string.parse::<u32>().map_err(|_| {
                 ParserError::MessageWithLocation(
+                    ParserErrorKind::NumberOutOfRange,
                     format!("Can not convert \"{}\" to i32 integer number.", num_string,),
                     num_range,
                 )
@@ -673,8 +704,9 @@ impl Lexer<'_> {
             &self.last_position,
         );
 
-        let nt = NumberType::from_str(&type_name)
-            .map_err(|msg| ParserError::MessageWithLocation(msg, type_range))?;
+        let nt = NumberType::from_str(&type_name).map_err(|msg| {
+            ParserError::MessageWithLocation(ParserErrorKind::InvalidNumber, msg, type_range)
+        })?;
 
         Ok(nt)
     }
@@ -759,13 +791,14 @@ impl Lexer<'_> {
 
                     break;
                 }
-                ' ' | '\t' | '\r' | '\n' | ',' | ':' | '=' | '+' | '-' | '{' | '}' | '[' | ']'
-                | '(' | ')' | '/' | '"' => {
+                ' ' | '\t' | '\r' | '\n' | ',' | ':' | ';' | '=' | '+' | '-' | '{' | '}' | '['
+                | ']' | '(' | ')' | '/' | '"' => {
                     // terminator chars
                     break;
                 }
                 _ => {
                     return Err(ParserError::MessageWithLocation(
+                        ParserErrorKind::InvalidNumber,
                         format!("Invalid char '{}' for hexadecimal number.", current_char),
                         *self.peek_position(0).unwrap(),
                     ));
@@ -780,6 +813,7 @@ impl Lexer<'_> {
 
         if num_string.is_empty() {
             return Err(ParserError::MessageWithLocation(
+                ParserErrorKind::InvalidNumber,
                 "Empty hexadecimal number".to_owned(),
                 num_range,
             ));
@@ -787,6 +821,7 @@ impl Lexer<'_> {
 
         if found_point && !found_p {
             return Err(ParserError::MessageWithLocation(
+                ParserErrorKind::InvalidNumber,
                 format!(
                     "Hexadecimal floating point number \"{}\" is missing the exponent.",
                     num_string
@@ -808,7 +843,7 @@ impl Lexer<'_> {
                         to_f64 = true;
                     }
                     _ => {
-                        return Err(ParserError::MessageWithLocation(format!(
+                        return Err(ParserError::MessageWithLocation(ParserErrorKind::InvalidNumber, format!(
                                 "Invalid type \"{}\" for hexadecimal floating-point numbers, only type \"f32\" and \"f64\" are allowed.",
                                 nt
                             ),
@@ -824,6 +859,7 @@ impl Lexer<'_> {
                 let v = hexfloat2::parse::<f64>(&num_string).map_err(|_| {
                     // there is no detail message provided by `hexfloat2::parse`.
                     ParserError::MessageWithLocation(
+                        ParserErrorKind::NumberOutOfRange,
                         format!(
                             "Can not convert \"{}\" to f64 floating-point number.",
                             num_string
@@ -837,6 +873,7 @@ impl Lexer<'_> {
                 let v = hexfloat2::parse::<f32>(&num_string).map_err(|_| {
                     // there is no detail message provided by `hexfloat2::parse`.
                     ParserError::MessageWithLocation(
+                        ParserErrorKind::NumberOutOfRange,
                         format!(
                             "Can not convert \"{}\" to f32 floating-point number.",
                             num_string
@@ -852,6 +889,7 @@ impl Lexer<'_> {
                 NumberType::I8 => {
                     let v = u8::from_str_radix(&num_string, 16).map_err(|_| {
                         ParserError::MessageWithLocation(
+                            ParserErrorKind::NumberOutOfRange,
                             format!("Can not convert \"{}\" to i8 integer number.", num_string),
                             num_range,
                         )
@@ -862,6 +900,7 @@ impl Lexer<'_> {
                 NumberType::I16 => {
                     let v = u16::from_str_radix(&num_string, 16).map_err(|_| {
                         ParserError::MessageWithLocation(
+                            ParserErrorKind::NumberOutOfRange,
                             format!("Can not convert \"{}\" to i16 integer number.", num_string),
                             num_range,
                         )
@@ -872,6 +911,7 @@ impl Lexer<'_> {
                 NumberType::I32 => {
                     let v = u32::from_str_radix(&num_string, 16).map_err(|_| {
                         ParserError::MessageWithLocation(
+                            ParserErrorKind::NumberOutOfRange,
                             format!("Can not convert \"{}\" to i32 integer number.", num_string),
                             num_range,
                         )
@@ -882,6 +922,7 @@ impl Lexer<'_> {
                 NumberType::I64 => {
                     let v = u64::from_str_radix(&num_string, 16).map_err(|_| {
                         ParserError::MessageWithLocation(
+                            ParserErrorKind::NumberOutOfRange,
                             format!("Can not convert \"{}\" to i64 integer number.", num_string),
                             num_range,
                         )
@@ -900,6 +941,7 @@ impl Lexer<'_> {
             // convert to i32
             let v = u32::from_str_radix(&num_string, 16).map_err(|_| {
                 ParserError::MessageWithLocation(
+                    ParserErrorKind::NumberOutOfRange,
                     format!("Can not convert \"{}\" to i32 integer number.", num_string),
                     num_range,
                 )
@@ -944,13 +986,14 @@ impl Lexer<'_> {
                     num_type.replace(nt);
                     break;
                 }
-                ' ' | '\t' | '\r' | '\n' | ',' | ':' | '=' | '+' | '-' | '{' | '}' | '[' | ']'
-                | '(' | ')' | '/' | '"' => {
+                ' ' | '\t' | '\r' | '\n' | ',' | ':' | ';' | '=' | '+' | '-' | '{' | '}' | '['
+                | ']' | '(' | ')' | '/' | '"' => {
                     // terminator chars
                     break;
                 }
                 _ => {
                     return Err(ParserError::MessageWithLocation(
+                        ParserErrorKind::InvalidNumber,
                         format!("Invalid char '{}' for binary number.", current_char),
                         *self.peek_position(0).unwrap(),
                     ));
@@ -965,6 +1008,7 @@ impl Lexer<'_> {
 
         if num_string.is_empty() {
             return Err(ParserError::MessageWithLocation(
+                ParserErrorKind::InvalidNumber,
                 "Empty binary number.".to_owned(),
                 num_range,
             ));
@@ -975,6 +1019,7 @@ impl Lexer<'_> {
                 NumberType::I8 => {
                     let v = u8::from_str_radix(&num_string, 2).map_err(|_| {
                         ParserError::MessageWithLocation(
+                            ParserErrorKind::NumberOutOfRange,
                             format!("Can not convert \"{}\" to i8 integer number.", num_string,),
                             num_range,
                         )
@@ -985,6 +1030,7 @@ impl Lexer<'_> {
                 NumberType::I16 => {
                     let v = u16::from_str_radix(&num_string, 2).map_err(|_| {
                         ParserError::MessageWithLocation(
+                            ParserErrorKind::NumberOutOfRange,
                             format!("Can not convert \"{}\" to i16 integer number.", num_string,),
                             num_range,
                         )
@@ -995,6 +1041,7 @@ impl Lexer<'_> {
                 NumberType::I32 => {
                     let v = u32::from_str_radix(&num_string, 2).map_err(|_| {
                         ParserError::MessageWithLocation(
+                            ParserErrorKind::NumberOutOfRange,
                             format!("Can not convert \"{}\" to i32 integer number.", num_string),
                             num_range,
                         )
@@ -1005,6 +1052,7 @@ impl Lexer<'_> {
                 NumberType::I64 => {
                     let v = u64::from_str_radix(&num_string, 2).map_err(|_| {
                         ParserError::MessageWithLocation(
+                            ParserErrorKind::NumberOutOfRange,
                             format!("Can not convert \"{}\" to i64 integer number.", num_string),
                             num_range,
                         )
@@ -1022,6 +1070,7 @@ impl Lexer<'_> {
 
             let v = u32::from_str_radix(&num_string, 2).map_err(|_| {
                 ParserError::MessageWithLocation(
+                    ParserErrorKind::NumberOutOfRange,
                     format!("Can not convert \"{}\" to i32 integer number.", num_string),
                     num_range,
                 )
@@ -1033,128 +1082,138 @@ impl Lexer<'_> {
         Ok(TokenWithRange::new(Token::Number(num_token), num_range))
     }
 
-    //     fn lex_char(&mut self) -> Result<TokenWithRange, Error> {
-    //         // 'a'?  //
-    //         // ^  ^__// to here
-    //         // |_____// current char, validated
-    //
-    //         self.push_peek_position();
-    //
-    //         self.next_char(); // consume "'"
-    //
-    //         let character = match self.next_char() {
-    //             Some(previous_previous_char) => {
-    //                 match previous_previous_char {
-    //                     '\\' => {
-    //                         // escape chars
-    //                         match self.next_char() {
-    //                             Some(previous_char) => {
-    //                                 match previous_char {
-    //                                     '\\' => '\\',
-    //                                     '\'' => '\'',
-    //                                     '"' => {
-    //                                         // double quote does not necessary to be escaped for char
-    //                                         // however, it is still supported for consistency between chars and strings.
-    //                                         '"'
-    //                                     }
-    //                                     't' => {
-    //                                         // horizontal tabulation
-    //                                         '\t'
-    //                                     }
-    //                                     'r' => {
-    //                                         // carriage return (CR, ascii 13)
-    //                                         '\r'
-    //                                     }
-    //                                     'n' => {
-    //                                         // new line character (line feed, LF, ascii 10)
-    //                                         '\n'
-    //                                     }
-    //                                     '0' => {
-    //                                         // null char
-    //                                         '\0'
-    //                                     }
-    //                                     'u' => {
-    //                                         if self.peek_char_and_equals(0, '{') {
-    //                                             // unicode code point, e.g. '\u{2d}', '\u{6587}'
-    //                                             self.unescape_unicode()?
-    //                                         } else {
-    //                                             return Err(Error::MessageWithLocation(
-    //                                                 "Missing the brace for unicode escape sequence."
-    //                                                     .to_owned(),
-    //                                                 self.last_position.move_position_forward(),
-    //                                             ));
-    //                                         }
-    //                                     }
-    //                                     _ => {
-    //                                         return Err(Error::MessageWithLocation(
-    //                                             format!("Unexpected escape char '{}'.", previous_char),
-    //                                             // self.last_position,
-    //                                             Location::from_position_and_length(
-    //                                                 &self.last_position.move_position_backward(),
-    //                                                 2,
-    //                                             ),
-    //                                         ));
-    //                                     }
-    //                                 }
-    //                             }
-    //                             None => {
-    //                                 // `\` + EOF
-    //                                 return Err(Error::UnexpectedEndOfDocument(
-    //                                     "Incomplete escape character sequence.".to_owned(),
-    //                                 ));
-    //                             }
-    //                         }
-    //                     }
-    //                     '\'' => {
-    //                         // `''`
-    //                         return Err(Error::MessageWithLocation(
-    //                             "Empty char.".to_owned(),
-    //                             Location::from_position_pair_with_end_included(
-    //                                 &self.pop_saved_position(),
-    //                                 &self.last_position,
-    //                             ),
-    //                         ));
-    //                     }
-    //                     _ => {
-    //                         // ordinary char
-    //                         previous_previous_char
-    //                     }
-    //                 }
-    //             }
-    //             None => {
-    //                 // `'EOF`
-    //                 return Err(Error::UnexpectedEndOfDocument(
-    //                     "Incomplete character.".to_owned(),
-    //                 ));
-    //             }
-    //         };
-    //
-    //         // consume the right single quote
-    //         match self.next_char() {
-    //             Some('\'') => {
-    //                 // Ok
-    //             }
-    //             Some(_) => {
-    //                 // `'a?`
-    //                 return Err(Error::MessageWithLocation(
-    //                     "Expected a closing single quote for char".to_owned(),
-    //                     self.last_position,
-    //                 ));
-    //             }
-    //             None => {
-    //                 // `'aEOF`
-    //                 return Err(Error::UnexpectedEndOfDocument(
-    //                     "Incomplete character.".to_owned(),
-    //                 ));
-    //             }
-    //         }
-    //
-    //         let character_range = Location::from_position_pair_with_end_included(
-    //             &self.pop_saved_position(),
-    //             &self.last_position,
-    //         );
-    //         Ok(TokenWithRange::new(Token::Char(character), character_range))
-    //     }
+    fn lex_char(&mut self) -> Result<TokenWithRange, ParserError> {
+        // 'a'?  //
+        // ^  ^__// to here
+        // |_____// current char, validated
+
+        self.push_peek_position();
+
+        self.next_char(); // consume "'"
+
+        let character = match self.next_char() {
+            Some(previous_previous_char) => {
+                match previous_previous_char {
+                    '\\' => {
+                        // escape chars
+                        match self.next_char() {
+                            Some(previous_char) => {
+                                match previous_char {
+                                    '\\' => '\\',
+                                    '\'' => '\'',
+                                    '"' => {
+                                        // double quote does not necessary to be escaped for char
+                                        // however, it is still supported for consistency between chars and strings.
+                                        '"'
+                                    }
+                                    't' => {
+                                        // horizontal tabulation
+                                        '\t'
+                                    }
+                                    'r' => {
+                                        // carriage return (CR, ascii 13)
+                                        '\r'
+                                    }
+                                    'n' => {
+                                        // new line character (line feed, LF, ascii 10)
+                                        '\n'
+                                    }
+                                    '0' => {
+                                        // null char
+                                        '\0'
+                                    }
+                                    'u' => {
+                                        if self.peek_char_and_equals(0, '{') {
+                                            // unicode code point, e.g. '\u{2d}', '\u{6587}'
+                                            self.unescape_unicode()?
+                                        } else {
+                                            return Err(ParserError::MessageWithLocation(
+                                                ParserErrorKind::InvalidEscapeSequence,
+                                                "Missing the brace for unicode escape sequence."
+                                                    .to_owned(),
+                                                self.last_position.move_position_forward(),
+                                            ));
+                                        }
+                                    }
+                                    _ => {
+                                        return Err(ParserError::MessageWithLocation(
+                                            ParserErrorKind::InvalidEscapeSequence,
+                                            format!("Unsupported escape char '{}'.", previous_char),
+                                            Location::from_position_and_length(
+                                                &self.last_position.move_position_backward(),
+                                                2,
+                                            ),
+                                        ));
+                                    }
+                                }
+                            }
+                            None => {
+                                // `\` + EOF
+                                return Err(ParserError::UnexpectedEndOfDocument(
+                                    ParserErrorKind::InvalidEscapeSequence,
+                                    "Incomplete character escape sequence.".to_owned(),
+                                ));
+                            }
+                        }
+                    }
+                    '\'' => {
+                        // `''`
+                        return Err(ParserError::MessageWithLocation(
+                            ParserErrorKind::InvalidChar,
+                            "Empty char.".to_owned(),
+                            Location::from_position_pair_with_end_included(
+                                &self.pop_saved_position(),
+                                &self.last_position,
+                            ),
+                        ));
+                    }
+                    _ => {
+                        // ordinary char
+                        previous_previous_char
+                    }
+                }
+            }
+            None => {
+                // `'EOF`
+                return Err(ParserError::UnexpectedEndOfDocument(
+                    ParserErrorKind::InvalidChar,
+                    "Incomplete character.".to_owned(),
+                ));
+            }
+        };
+
+        // consume the right single quote
+        match self.next_char() {
+            Some('\'') => {
+                // Ok
+            }
+            Some(_) => {
+                // `'a?`
+                return Err(ParserError::MessageWithLocation(
+                    ParserErrorKind::InvalidChar,
+                    "Expect a closing single quote for char.".to_owned(),
+                    self.last_position,
+                ));
+            }
+            None => {
+                // `'aEOF`
+                return Err(ParserError::UnexpectedEndOfDocument(
+                    ParserErrorKind::InvalidChar,
+                    "Incomplete character.".to_owned(),
+                ));
+            }
+        }
+
+        let character_range = Location::from_position_pair_with_end_included(
+            &self.pop_saved_position(),
+            &self.last_position,
+        );
+
+        Ok(TokenWithRange::new(
+            Token::Number(NumberToken::Char(character)),
+            character_range,
+        ))
+    }
 
     fn unescape_unicode(&mut self) -> Result<char, ParserError> {
         // \u{6587}?  //
@@ -1174,6 +1233,7 @@ impl Lexer<'_> {
                     '0'..='9' | 'a'..='f' | 'A'..='F' => codepoint_string.push(previous_char),
                     _ => {
                         return Err(ParserError::MessageWithLocation(
+                            ParserErrorKind::InvalidEscapeSequence,
                             format!(
                                 "Invalid character '{}' for unicode escape sequence.",
                                 previous_char
@@ -1185,6 +1245,7 @@ impl Lexer<'_> {
                 None => {
                     // EOF
                     return Err(ParserError::UnexpectedEndOfDocument(
+                        ParserErrorKind::InvalidEscapeSequence,
                         "Incomplete unicode escape sequence.".to_owned(),
                     ));
                 }
@@ -1202,6 +1263,7 @@ impl Lexer<'_> {
 
         if codepoint_string.len() > 6 {
             return Err(ParserError::MessageWithLocation(
+                ParserErrorKind::InvalidEscapeSequence,
                 "Unicode point code exceeds six digits.".to_owned(),
                 codepoint_range,
             ));
@@ -1209,6 +1271,7 @@ impl Lexer<'_> {
 
         if codepoint_string.is_empty() {
             return Err(ParserError::MessageWithLocation(
+                ParserErrorKind::InvalidEscapeSequence,
                 "Empty unicode code point.".to_owned(),
                 codepoint_range,
             ));
@@ -1225,6 +1288,7 @@ impl Lexer<'_> {
             Ok(c)
         } else {
             Err(ParserError::MessageWithLocation(
+                ParserErrorKind::InvalidEscapeSequence,
                 "Invalid unicode code point.".to_owned(),
                 codepoint_range,
             ))
@@ -1285,6 +1349,7 @@ impl Lexer<'_> {
                                                 final_string.push(ch);
                                             } else {
                                                 return Err(ParserError::MessageWithLocation(
+                                                    ParserErrorKind::InvalidEscapeSequence,
                                                     "Missing the brace for unicode escape sequence.".to_owned(),
                                                     self.last_position.move_position_forward()
                                                 ));
@@ -1302,6 +1367,7 @@ impl Lexer<'_> {
                                         }
                                         _ => {
                                             return Err(ParserError::MessageWithLocation(
+                                                ParserErrorKind::InvalidEscapeSequence,
                                                 format!(
                                                     "Unsupported escape char '{}'.",
                                                     previous_char
@@ -1317,6 +1383,7 @@ impl Lexer<'_> {
                                 None => {
                                     // `\` + EOF
                                     return Err(ParserError::UnexpectedEndOfDocument(
+                                        ParserErrorKind::InvalidEscapeSequence,
                                         "Incomplete character escape sequence.".to_owned(),
                                     ));
                                 }
@@ -1335,6 +1402,7 @@ impl Lexer<'_> {
                 None => {
                     // `"...EOF`
                     return Err(ParserError::UnexpectedEndOfDocument(
+                        ParserErrorKind::UnterminatedString,
                         "Incomplete string.".to_owned(),
                     ));
                 }
@@ -1372,6 +1440,7 @@ impl Lexer<'_> {
                 None => {
                     // EOF
                     return Err(ParserError::UnexpectedEndOfDocument(
+                        ParserErrorKind::UnterminatedString,
                         "Incomplete string.".to_owned(),
                     ));
                 }
@@ -1411,6 +1480,7 @@ impl Lexer<'_> {
                 None => {
                     // `r"...EOF`
                     return Err(ParserError::UnexpectedEndOfDocument(
+                        ParserErrorKind::UnterminatedString,
                         "Incomplete string.".to_owned(),
                     ));
                 }
@@ -1463,6 +1533,7 @@ impl Lexer<'_> {
                 None => {
                     // `r#"...EOF`
                     return Err(ParserError::UnexpectedEndOfDocument(
+                        ParserErrorKind::UnterminatedString,
                         "Incomplete string.".to_owned(),
                     ));
                 }
@@ -1507,6 +1578,7 @@ impl Lexer<'_> {
             self.next_char(); // consume '\n'
         } else {
             return Err(ParserError::MessageWithLocation(
+                ParserErrorKind::InvalidAutoTrimmedString,
                 "The content of auto-trimmed string should start on a new line.".to_owned(),
                 self.last_position.move_position_forward(),
             ));
@@ -1552,6 +1624,7 @@ impl Lexer<'_> {
                 None => {
                     // `"""\n...EOF`
                     return Err(ParserError::UnexpectedEndOfDocument(
+                        ParserErrorKind::UnterminatedString,
                         "Incomplete string.".to_owned(),
                     ));
                 }
@@ -1657,6 +1730,7 @@ impl Lexer<'_> {
                                     break;
                                 } else {
                                     return Err(ParserError::MessageWithLocation(
+                                            ParserErrorKind::InvalidHexByteData,
                                             "Expect a whitespace between the hexadecimal byte data digits."
                                                 .to_owned(),
                                             iter.last_position.move_position_forward()
@@ -1668,6 +1742,7 @@ impl Lexer<'_> {
                     None => {
                         // h"...EOF
                         return Err(ParserError::UnexpectedEndOfDocument(
+                            ParserErrorKind::InvalidHexByteData,
                             "Incomplete hexadecimal byte data.".to_owned(),
                         ));
                     }
@@ -1700,6 +1775,7 @@ impl Lexer<'_> {
                         }
                         _ => {
                             return Err(ParserError::MessageWithLocation(
+                                ParserErrorKind::InvalidHexByteData,
                                 format!(
                                     "Invalid digit '{}' for hexadecimal byte data.",
                                     previous_char
@@ -1710,6 +1786,7 @@ impl Lexer<'_> {
                     },
                     None => {
                         return Err(ParserError::UnexpectedEndOfDocument(
+                            ParserErrorKind::InvalidHexByteData,
                             "Incomplete hexadecimal byte data.".to_owned(),
                         ))
                     }
@@ -1751,6 +1828,13 @@ impl Lexer<'_> {
         self.next_char(); // consume the 1st '/'
         self.next_char(); // consume the 2nd '/'
 
+        let is_document = self.peek_char_and_equals(0, '/') && !self.peek_char_and_equals(1, '/');
+        let is_module_document = self.peek_char_and_equals(0, '!');
+
+        if is_document || is_module_document {
+            self.next_char(); // consume the 3rd '/' or '!'
+        }
+
         let mut comment_string = String::new();
 
         while let Some(current_char) = self.peek_char(0) {
@@ -1777,10 +1861,15 @@ impl Lexer<'_> {
             &self.last_position,
         );
 
-        Ok(TokenWithRange::new(
-            Token::Comment(Comment::Line(comment_string)),
-            comment_range,
-        ))
+        let comment = if is_document {
+            Comment::Document(comment_string)
+        } else if is_module_document {
+            Comment::ModuleDocument(comment_string)
+        } else {
+            Comment::Line(comment_string)
+        };
+
+        Ok(TokenWithRange::new(Token::Comment(comment), comment_range))
     }
 
     fn lex_block_comment(&mut self) -> Result<TokenWithRange, ParserError> {
@@ -1837,7 +1926,10 @@ impl Lexer<'_> {
                         "Incomplete block comment.".to_owned()
                     };
 
-                    return Err(ParserError::UnexpectedEndOfDocument(msg));
+                    return Err(ParserError::UnexpectedEndOfDocument(
+                        ParserErrorKind::UnterminatedBlockComment,
+                        msg,
+                    ));
                 }
             }
         }
@@ -1963,11 +2055,12 @@ mod tests {
     #[test]
     fn test_lex_punctuations() {
         assert_eq!(
-            lex_from_str_without_location("\n,:=->+-{}[]()").unwrap(),
+            lex_from_str_without_location("\n,:;=->+-{}[]()").unwrap(),
             vec![
                 Token::NewLine,
                 Token::Comma,
                 Token::Colon,
+                Token::Semicolon,
                 Token::Equal,
                 Token::RightArrow,
                 Token::Plus,
@@ -2058,6 +2151,7 @@ mod tests {
         assert!(matches!(
             lex_from_str_without_location("abc&xyz"),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -2102,6 +2196,18 @@ mod tests {
                 )
             ]
         );
+
+        // glob and group
+        assert_eq!(
+            lex_from_str_without_location("foo::* foo::bar::{a}").unwrap(),
+            vec![
+                Token::new_full_name("foo::*"),
+                Token::new_full_name("foo::bar::"),
+                Token::LeftBrace,
+                Token::new_name("a"),
+                Token::RightBrace,
+            ]
+        );
     }
 
     #[test]
@@ -2284,6 +2390,17 @@ recur recur_fn"
             ]
         );
 
+        assert_eq!(
+            lex_from_str_without_location("[0;16]").unwrap(),
+            vec![
+                Token::LeftBracket,
+                Token::Number(NumberToken::I32(0)),
+                Token::Semicolon,
+                Token::Number(NumberToken::I32(16)),
+                Token::RightBracket,
+            ]
+        );
+
         // location
 
         assert_eq!(
@@ -2306,6 +2423,7 @@ recur recur_fn"
         assert!(matches!(
             lex_from_str_without_location("12x34"),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -2321,6 +2439,7 @@ recur recur_fn"
         assert!(matches!(
             lex_from_str_without_location("4_294_967_296"),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -2370,6 +2489,7 @@ recur recur_fn"
         assert!(matches!(
             lex_from_str_without_location("123."),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -2385,6 +2505,7 @@ recur recur_fn"
         assert!(matches!(
             lex_from_str_without_location("123e"),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -2400,6 +2521,7 @@ recur recur_fn"
         assert!(matches!(
             lex_from_str_without_location("1.23.456"),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -2415,6 +2537,7 @@ recur recur_fn"
         assert!(matches!(
             lex_from_str_without_location("1e23e456"),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -2430,6 +2553,7 @@ recur recur_fn"
         assert!(matches!(
             lex_from_str_without_location(".123"),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -2498,6 +2622,7 @@ recur recur_fn"
             assert!(matches!(
                 lex_from_str_without_location("256_i8"),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /* unit: 0, */
@@ -2526,6 +2651,7 @@ recur recur_fn"
             assert!(matches!(
                 lex_from_str_without_location("65536_i16"),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /* unit: 0, */
@@ -2554,6 +2680,7 @@ recur recur_fn"
             assert!(matches!(
                 lex_from_str_without_location("4_294_967_296_i32"),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /* unit: 0, */
@@ -2584,6 +2711,7 @@ recur recur_fn"
             assert!(matches!(
                 lex_from_str_without_location("18_446_744_073_709_551_616_i64"),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /* unit: 0, */
@@ -2612,6 +2740,7 @@ recur recur_fn"
             assert!(matches!(
                 lex_from_str_without_location("3.4e39_f32"),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /* unit: 0, */
@@ -2642,6 +2771,7 @@ recur recur_fn"
             assert!(matches!(
                 lex_from_str_without_location("1.8e309_f64"),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /* unit: 0, */
@@ -2694,6 +2824,7 @@ recur recur_fn"
         assert!(matches!(
             lex_from_str_without_location("0x1234xyz"),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -2709,6 +2840,7 @@ recur recur_fn"
         assert!(matches!(
             lex_from_str_without_location("0x1_0000_0000"),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -2724,6 +2856,7 @@ recur recur_fn"
         assert!(matches!(
             lex_from_str_without_location("0x"),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -2792,6 +2925,7 @@ recur recur_fn"
             assert!(matches!(
                 lex_from_str_without_location("0x1_ff_i8"),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /* unit: 0, */
@@ -2820,6 +2954,7 @@ recur recur_fn"
             assert!(matches!(
                 lex_from_str_without_location("0x1_ffff_i16"),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /* unit: 0, */
@@ -2848,6 +2983,7 @@ recur recur_fn"
             assert!(matches!(
                 lex_from_str_without_location("0x1_ffff_ffff_i32"),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /* unit: 0, */
@@ -2878,6 +3014,7 @@ recur recur_fn"
             assert!(matches!(
                 lex_from_str_without_location("0x1_ffff_ffff_ffff_ffff_i64"),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /* unit: 0, */
@@ -2947,6 +3084,7 @@ recur recur_fn"
         assert!(matches!(
             lex_from_str_without_location("0x1.23"),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -2962,6 +3100,7 @@ recur recur_fn"
         assert!(matches!(
             lex_from_str_without_location("0x1.2.3"),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -2977,6 +3116,7 @@ recur recur_fn"
         assert!(matches!(
             lex_from_str_without_location("0x1.2p3p4"),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -2992,6 +3132,7 @@ recur recur_fn"
         assert!(matches!(
             lex_from_str_without_location("0x1.23p4.5"),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -3007,6 +3148,7 @@ recur recur_fn"
         assert!(matches!(
             lex_from_str_without_location("0x1.23p4_i32"),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -3058,6 +3200,7 @@ recur recur_fn"
         assert!(matches!(
             lex_from_str_without_location("0b11.10"),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -3073,6 +3216,7 @@ recur recur_fn"
         assert!(matches!(
             lex_from_str_without_location("0b1_0000_0000_0000_0000_0000_0000_0000_0000"),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -3088,6 +3232,7 @@ recur recur_fn"
         assert!(matches!(
             lex_from_str_without_location("0b101xyz"),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -3103,6 +3248,7 @@ recur recur_fn"
         assert!(matches!(
             lex_from_str_without_location("0b"),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -3171,6 +3317,7 @@ recur recur_fn"
             assert!(matches!(
                 lex_from_str_without_location("0b1_1111_1111_i8"),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /* unit: 0, */
@@ -3199,6 +3346,7 @@ recur recur_fn"
             assert!(matches!(
                 lex_from_str_without_location("0b1_1111_1111_1111_1111_i16"),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /* unit: 0, */
@@ -3229,6 +3377,7 @@ recur recur_fn"
             assert!(matches!(
                 lex_from_str_without_location("0b1_1111_1111_1111_1111__1111_1111_1111_1111_i32"),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /* unit: 0, */
@@ -3257,6 +3406,7 @@ recur recur_fn"
             assert!(matches!(
                 lex_from_str_without_location("0b1_1111_1111_1111_1111__1111_1111_1111_1111__1111_1111_1111_1111__1111_1111_1111_1111_i64"),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /* unit: 0, */
@@ -3273,6 +3423,7 @@ recur recur_fn"
         assert!(matches!(
             lex_from_str_without_location("0b11_f32"),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -3285,314 +3436,332 @@ recur recur_fn"
         ));
     }
 
-    //     #[test]
-    //     fn test_lex_char() {
-    //         assert_eq!(
-    //             lex_from_str_without_location("'a'").unwrap(),
-    //             vec![Token::Char('a')]
-    //         );
-    //
-    //         assert_eq!(
-    //             lex_from_str_without_location("('a')").unwrap(),
-    //             vec![Token::LeftParen, Token::Char('a'), Token::RightParen]
-    //         );
-    //
-    //         assert_eq!(
-    //             lex_from_str_without_location("'a' 'z'").unwrap(),
-    //             vec![Token::Char('a'), Token::Char('z')]
-    //         );
-    //
-    //         // CJK
-    //         assert_eq!(
-    //             lex_from_str_without_location("'文'").unwrap(),
-    //             vec![Token::Char('文')]
-    //         );
-    //
-    //         // emoji
-    //         assert_eq!(
-    //             lex_from_str_without_location("'😊'").unwrap(),
-    //             vec![Token::Char('😊')]
-    //         );
-    //
-    //         // escape char `\\`
-    //         assert_eq!(
-    //             lex_from_str_without_location("'\\\\'").unwrap(),
-    //             vec![Token::Char('\\')]
-    //         );
-    //
-    //         // escape char `\'`
-    //         assert_eq!(
-    //             lex_from_str_without_location("'\\\''").unwrap(),
-    //             vec![Token::Char('\'')]
-    //         );
-    //
-    //         // escape char `"`
-    //         assert_eq!(
-    //             lex_from_str_without_location("'\\\"'").unwrap(),
-    //             vec![Token::Char('"')]
-    //         );
-    //
-    //         // escape char `\t`
-    //         assert_eq!(
-    //             lex_from_str_without_location("'\\t'").unwrap(),
-    //             vec![Token::Char('\t')]
-    //         );
-    //
-    //         // escape char `\r`
-    //         assert_eq!(
-    //             lex_from_str_without_location("'\\r'").unwrap(),
-    //             vec![Token::Char('\r')]
-    //         );
-    //
-    //         // escape char `\n`
-    //         assert_eq!(
-    //             lex_from_str_without_location("'\\n'").unwrap(),
-    //             vec![Token::Char('\n')]
-    //         );
-    //
-    //         // escape char `\0`
-    //         assert_eq!(
-    //             lex_from_str_without_location("'\\0'").unwrap(),
-    //             vec![Token::Char('\0')]
-    //         );
-    //
-    //         // escape char, unicode
-    //         assert_eq!(
-    //             lex_from_str_without_location("'\\u{2d}'").unwrap(),
-    //             vec![Token::Char('-')]
-    //         );
-    //
-    //         // escape char, unicode
-    //         assert_eq!(
-    //             lex_from_str_without_location("'\\u{6587}'").unwrap(),
-    //             vec![Token::Char('文')]
-    //         );
-    //
-    //         // location
-    //
-    //         assert_eq!(
-    //             lex_from_str("'a' '文'").unwrap(),
-    //             vec![
-    //                 TokenWithRange::from_position_and_length(
-    //                     Token::Char('a'),
-    //                     &Location::new_position(/*0,*/ 0, 0, 0),
-    //                     3
-    //                 ),
-    //                 TokenWithRange::from_position_and_length(
-    //                     Token::Char('文'),
-    //                     &Location::new_position(/*0,*/ 4, 0, 4),
-    //                     3
-    //                 )
-    //             ]
-    //         );
-    //
-    //         assert_eq!(
-    //             lex_from_str("'\\t'").unwrap(),
-    //             vec![TokenWithRange::from_position_and_length(
-    //                 Token::Char('\t'),
-    //                 &Location::new_position(/*0,*/ 0, 0, 0),
-    //                 4
-    //             )]
-    //         );
-    //
-    //         assert_eq!(
-    //             lex_from_str("'\\u{6587}'").unwrap(),
-    //             vec![TokenWithRange::from_position_and_length(
-    //                 Token::Char('文'),
-    //                 &Location::new_position(/*0,*/ 0, 0, 0),
-    //                 10
-    //             )]
-    //         );
-    //
-    //         // err: empty char
-    //         assert!(matches!(
-    //             lex_from_str_without_location("''"),
-    //             Err(Error::MessageWithLocation(
-    //                 _,
-    //                 Location {
-    //                     /* unit: 0, */
-    //                     index: 0,
-    //                     line: 0,
-    //                     column: 0,
-    //                     length: 2
-    //                 }
-    //             ))
-    //         ));
-    //
-    //         // err: empty char, missing the char
-    //         assert!(matches!(
-    //             lex_from_str_without_location("'"),
-    //             Err(Error::UnexpectedEndOfDocument(_))
-    //         ));
-    //
-    //         // err: incomplete char, missing the right quote, encounter EOF
-    //         assert!(matches!(
-    //             lex_from_str_without_location("'a"),
-    //             Err(Error::UnexpectedEndOfDocument(_))
-    //         ));
-    //
-    //         // err: invalid char, expect the right quote, encounter another char
-    //         assert!(matches!(
-    //             lex_from_str_without_location("'ab"),
-    //             Err(Error::MessageWithLocation(
-    //                 _,
-    //                 Location {
-    //                     /* unit: 0, */
-    //                     index: 2,
-    //                     line: 0,
-    //                     column: 2,
-    //                     length: 0
-    //                 }
-    //             ))
-    //         ));
-    //
-    //         // err: invalid char, expect the right quote, encounter another char
-    //         assert!(matches!(
-    //             lex_from_str_without_location("'ab'"),
-    //             Err(Error::MessageWithLocation(
-    //                 _,
-    //                 Location {
-    //                     /* unit: 0, */
-    //                     index: 2,
-    //                     line: 0,
-    //                     column: 2,
-    //                     length: 0
-    //                 }
-    //             ))
-    //         ));
-    //
-    //         // err: unsupported escape char \v
-    //         assert!(matches!(
-    //             lex_from_str_without_location(r#"'\v'"#),
-    //             Err(Error::MessageWithLocation(
-    //                 _,
-    //                 Location {
-    //                     /* unit: 0, */
-    //                     index: 1,
-    //                     line: 0,
-    //                     column: 1,
-    //                     length: 2
-    //                 }
-    //             ))
-    //         ));
-    //
-    //         // err: unsupported hex escape "\x.."
-    //         assert!(matches!(
-    //             lex_from_str_without_location(r#"'\x33'"#),
-    //             Err(Error::MessageWithLocation(
-    //                 _,
-    //                 Location {
-    //                     /* unit: 0, */
-    //                     index: 1,
-    //                     line: 0,
-    //                     column: 1,
-    //                     length: 2
-    //                 }
-    //             ))
-    //         ));
-    //
-    //         // err: empty unicode escape string
-    //         // "'\\u{}'"
-    //         //  01 2345     // index
-    //         assert!(matches!(
-    //             lex_from_str_without_location("'\\u{}'"),
-    //             Err(Error::MessageWithLocation(
-    //                 _,
-    //                 Location {
-    //                     /* unit: 0, */
-    //                     index: 3,
-    //                     line: 0,
-    //                     column: 3,
-    //                     length: 2
-    //                 }
-    //             ))
-    //         ));
-    //
-    //         // err: invalid unicode code point, digits too much
-    //         // "'\\u{1000111}'"
-    //         //  01 234567890    // index
-    //         assert!(matches!(
-    //             lex_from_str_without_location("'\\u{1000111}'"),
-    //             Err(Error::MessageWithLocation(
-    //                 _,
-    //                 Location {
-    //                     /* unit: 0, */
-    //                     index: 3,
-    //                     line: 0,
-    //                     column: 3,
-    //                     length: 8
-    //                 }
-    //             ))
-    //         ));
-    //
-    //         // err: invalid unicode code point, code point out of range
-    //         // "'\\u{123456}'"
-    //         //  01 2345678901
-    //         assert!(matches!(
-    //             lex_from_str_without_location("'\\u{123456}'"),
-    //             Err(Error::MessageWithLocation(
-    //                 _,
-    //                 Location {
-    //                     /* unit: 0, */
-    //                     index: 3,
-    //                     line: 0,
-    //                     column: 3,
-    //                     length: 8
-    //                 }
-    //             ))
-    //         ));
-    //
-    //         // err: invalid char in the unicode escape sequence
-    //         assert!(matches!(
-    //             lex_from_str_without_location("'\\u{12mn}''"),
-    //             Err(Error::MessageWithLocation(
-    //                 _,
-    //                 Location {
-    //                     /* unit: 0, */
-    //                     index: 6,
-    //                     line: 0,
-    //                     column: 6,
-    //                     length: 0
-    //                 }
-    //             ))
-    //         ));
-    //
-    //         // err: missing the closed brace for unicode escape sequence
-    //         assert!(matches!(
-    //             lex_from_str_without_location("'\\u{1234'"),
-    //             Err(Error::MessageWithLocation(
-    //                 _,
-    //                 Location {
-    //                     /* unit: 0, */
-    //                     index: 8,
-    //                     line: 0,
-    //                     column: 8,
-    //                     length: 0
-    //                 }
-    //             ))
-    //         ));
-    //
-    //         // err: incomplete unicode escape sequence, encounter EOF
-    //         assert!(matches!(
-    //             lex_from_str_without_location("'\\u{1234"),
-    //             Err(Error::UnexpectedEndOfDocument(_))
-    //         ));
-    //
-    //         // err: missing left brace for unicode escape sequence
-    //         assert!(matches!(
-    //             lex_from_str_without_location("'\\u1234}'"),
-    //             Err(Error::MessageWithLocation(
-    //                 _,
-    //                 Location {
-    //                     /* unit: 0, */
-    //                     index: 3,
-    //                     line: 0,
-    //                     column: 3,
-    //                     length: 0
-    //                 }
-    //             ))
-    //         ));
-    //     }
+    #[test]
+    fn test_lex_char() {
+        assert_eq!(
+            lex_from_str_without_location("'a'").unwrap(),
+            vec![Token::Number(NumberToken::Char('a'))]
+        );
+
+        assert_eq!(
+            lex_from_str_without_location("('a')").unwrap(),
+            vec![
+                Token::LeftParen,
+                Token::Number(NumberToken::Char('a')),
+                Token::RightParen
+            ]
+        );
+
+        assert_eq!(
+            lex_from_str_without_location("'a' 'z'").unwrap(),
+            vec![
+                Token::Number(NumberToken::Char('a')),
+                Token::Number(NumberToken::Char('z'))
+            ]
+        );
+
+        // CJK
+        assert_eq!(
+            lex_from_str_without_location("'文'").unwrap(),
+            vec![Token::Number(NumberToken::Char('文'))]
+        );
+
+        // emoji
+        assert_eq!(
+            lex_from_str_without_location("'😊'").unwrap(),
+            vec![Token::Number(NumberToken::Char('😊'))]
+        );
+
+        // escape char `\\`
+        assert_eq!(
+            lex_from_str_without_location("'\\\\'").unwrap(),
+            vec![Token::Number(NumberToken::Char('\\'))]
+        );
+
+        // escape char `\'`
+        assert_eq!(
+            lex_from_str_without_location("'\\\''").unwrap(),
+            vec![Token::Number(NumberToken::Char('\''))]
+        );
+
+        // escape char `"`
+        assert_eq!(
+            lex_from_str_without_location("'\\\"'").unwrap(),
+            vec![Token::Number(NumberToken::Char('"'))]
+        );
+
+        // escape char `\t`
+        assert_eq!(
+            lex_from_str_without_location("'\\t'").unwrap(),
+            vec![Token::Number(NumberToken::Char('\t'))]
+        );
+
+        // escape char `\r`
+        assert_eq!(
+            lex_from_str_without_location("'\\r'").unwrap(),
+            vec![Token::Number(NumberToken::Char('\r'))]
+        );
+
+        // escape char `\n`
+        assert_eq!(
+            lex_from_str_without_location("'\\n'").unwrap(),
+            vec![Token::Number(NumberToken::Char('\n'))]
+        );
+
+        // escape char `\0`
+        assert_eq!(
+            lex_from_str_without_location("'\\0'").unwrap(),
+            vec![Token::Number(NumberToken::Char('\0'))]
+        );
+
+        // escape char, unicode
+        assert_eq!(
+            lex_from_str_without_location("'\\u{2d}'").unwrap(),
+            vec![Token::Number(NumberToken::Char('-'))]
+        );
+
+        // escape char, unicode
+        assert_eq!(
+            lex_from_str_without_location("'\\u{6587}'").unwrap(),
+            vec![Token::Number(NumberToken::Char('文'))]
+        );
+
+        // location
+
+        assert_eq!(
+            lex_from_str("'a' '文'").unwrap(),
+            vec![
+                TokenWithRange::from_position_and_length(
+                    Token::Number(NumberToken::Char('a')),
+                    &Location::new_position(/*0,*/ 0, 0, 0),
+                    3
+                ),
+                TokenWithRange::from_position_and_length(
+                    Token::Number(NumberToken::Char('文')),
+                    &Location::new_position(/*0,*/ 4, 0, 4),
+                    3
+                )
+            ]
+        );
+
+        assert_eq!(
+            lex_from_str("'\\t'").unwrap(),
+            vec![TokenWithRange::from_position_and_length(
+                Token::Number(NumberToken::Char('\t')),
+                &Location::new_position(/*0,*/ 0, 0, 0),
+                4
+            )]
+        );
+
+        assert_eq!(
+            lex_from_str("'\\u{6587}'").unwrap(),
+            vec![TokenWithRange::from_position_and_length(
+                Token::Number(NumberToken::Char('文')),
+                &Location::new_position(/*0,*/ 0, 0, 0),
+                10
+            )]
+        );
+
+        // err: empty char
+        assert!(matches!(
+            lex_from_str_without_location("''"),
+            Err(ParserError::MessageWithLocation(
+                _,
+                _,
+                Location {
+                    /* unit: 0, */
+                    index: 0,
+                    line: 0,
+                    column: 0,
+                    length: 2
+                }
+            ))
+        ));
+
+        // err: empty char, missing the char
+        assert!(matches!(
+            lex_from_str_without_location("'"),
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
+        ));
+
+        // err: incomplete char, missing the right quote, encounter EOF
+        assert!(matches!(
+            lex_from_str_without_location("'a"),
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
+        ));
+
+        // err: invalid char, expect the right quote, encounter another char
+        assert!(matches!(
+            lex_from_str_without_location("'ab"),
+            Err(ParserError::MessageWithLocation(
+                _,
+                _,
+                Location {
+                    /* unit: 0, */
+                    index: 2,
+                    line: 0,
+                    column: 2,
+                    length: 0
+                }
+            ))
+        ));
+
+        // err: invalid char, expect the right quote, encounter another char
+        assert!(matches!(
+            lex_from_str_without_location("'ab'"),
+            Err(ParserError::MessageWithLocation(
+                _,
+                _,
+                Location {
+                    /* unit: 0, */
+                    index: 2,
+                    line: 0,
+                    column: 2,
+                    length: 0
+                }
+            ))
+        ));
+
+        // err: unsupported escape char \v
+        assert!(matches!(
+            lex_from_str_without_location(r#"'\v'"#),
+            Err(ParserError::MessageWithLocation(
+                _,
+                _,
+                Location {
+                    /* unit: 0, */
+                    index: 1,
+                    line: 0,
+                    column: 1,
+                    length: 2
+                }
+            ))
+        ));
+
+        // err: unsupported hex escape "\x.."
+        assert!(matches!(
+            lex_from_str_without_location(r#"'\x33'"#),
+            Err(ParserError::MessageWithLocation(
+                _,
+                _,
+                Location {
+                    /* unit: 0, */
+                    index: 1,
+                    line: 0,
+                    column: 1,
+                    length: 2
+                }
+            ))
+        ));
+
+        // err: empty unicode escape string
+        // "'\\u{}'"
+        //  01 2345     // index
+        assert!(matches!(
+            lex_from_str_without_location("'\\u{}'"),
+            Err(ParserError::MessageWithLocation(
+                _,
+                _,
+                Location {
+                    /* unit: 0, */
+                    index: 3,
+                    line: 0,
+                    column: 3,
+                    length: 2
+                }
+            ))
+        ));
+
+        // err: invalid unicode code point, digits too much
+        // "'\\u{1000111}'"
+        //  01 234567890    // index
+        assert!(matches!(
+            lex_from_str_without_location("'\\u{1000111}'"),
+            Err(ParserError::MessageWithLocation(
+                _,
+                _,
+                Location {
+                    /* unit: 0, */
+                    index: 3,
+                    line: 0,
+                    column: 3,
+                    length: 8
+                }
+            ))
+        ));
+
+        // err: invalid unicode code point, code point out of range
+        // "'\\u{123456}'"
+        //  01 2345678901
+        assert!(matches!(
+            lex_from_str_without_location("'\\u{123456}'"),
+            Err(ParserError::MessageWithLocation(
+                _,
+                _,
+                Location {
+                    /* unit: 0, */
+                    index: 3,
+                    line: 0,
+                    column: 3,
+                    length: 8
+                }
+            ))
+        ));
+
+        // err: invalid char in the unicode escape sequence
+        assert!(matches!(
+            lex_from_str_without_location("'\\u{12mn}''"),
+            Err(ParserError::MessageWithLocation(
+                _,
+                _,
+                Location {
+                    /* unit: 0, */
+                    index: 6,
+                    line: 0,
+                    column: 6,
+                    length: 0
+                }
+            ))
+        ));
+
+        // err: missing the closed brace for unicode escape sequence
+        assert!(matches!(
+            lex_from_str_without_location("'\\u{1234'"),
+            Err(ParserError::MessageWithLocation(
+                _,
+                _,
+                Location {
+                    /* unit: 0, */
+                    index: 8,
+                    line: 0,
+                    column: 8,
+                    length: 0
+                }
+            ))
+        ));
+
+        // err: incomplete unicode escape sequence, encounter EOF
+        assert!(matches!(
+            lex_from_str_without_location("'\\u{1234"),
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
+        ));
+
+        // err: missing left brace for unicode escape sequence
+        assert!(matches!(
+            lex_from_str_without_location("'\\u1234}'"),
+            Err(ParserError::MessageWithLocation(
+                _,
+                _,
+                Location {
+                    /* unit: 0, */
+                    index: 3,
+                    line: 0,
+                    column: 3,
+                    length: 0
+                }
+            ))
+        ));
+    }
 
     #[test]
     fn test_lex_string() {
@@ -3684,25 +3853,26 @@ recur recur_fn"
         // err: incomplete string, missing the closed quote
         assert!(matches!(
             lex_from_str_without_location("\"abc"),
-            Err(ParserError::UnexpectedEndOfDocument(_))
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
         ));
 
         // err: incomplete string, missing the closed quote, ends with \n
         assert!(matches!(
             lex_from_str_without_location("\"abc\n"),
-            Err(ParserError::UnexpectedEndOfDocument(_))
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
         ));
 
         // err: incomplete string, missing the closed quote, ends with whitespaces/other chars
         assert!(matches!(
             lex_from_str_without_location("\"abc\n   "),
-            Err(ParserError::UnexpectedEndOfDocument(_))
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
         ));
 
         // err: unsupported escape char \v
         assert!(matches!(
             lex_from_str_without_location(r#""abc\vxyz""#),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -3718,6 +3888,7 @@ recur recur_fn"
         assert!(matches!(
             lex_from_str_without_location(r#""abc\x33xyz""#),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -3735,6 +3906,7 @@ recur recur_fn"
         assert!(matches!(
             lex_from_str_without_location(r#""abc\u{}xyz""#),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -3752,6 +3924,7 @@ recur recur_fn"
         assert!(matches!(
             lex_from_str_without_location(r#""abc\u{1000111}xyz""#),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -3769,6 +3942,7 @@ recur recur_fn"
         assert!(matches!(
             lex_from_str_without_location(r#""abc\u{123456}xyz""#),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -3784,6 +3958,7 @@ recur recur_fn"
         assert!(matches!(
             lex_from_str_without_location(r#""abc\u{12mn}xyz""#),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -3799,6 +3974,7 @@ recur recur_fn"
         assert!(matches!(
             lex_from_str_without_location(r#""abc\u{1234""#),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -3813,13 +3989,14 @@ recur recur_fn"
         // err: incomplete unicode escape sequence, encounter EOF
         assert!(matches!(
             lex_from_str_without_location(r#""abc\u{1234"#),
-            Err(ParserError::UnexpectedEndOfDocument(_))
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
         ));
 
         // err: missing left brace for unicode escape sequence
         assert!(matches!(
             lex_from_str_without_location(r#""abc\u1234}xyz""#),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -3865,13 +4042,13 @@ recur recur_fn"
         // err: incomplete string, missing the closed quote, ends with \n
         assert!(matches!(
             lex_from_str_without_location("\"abc\n    \n\n    \n"),
-            Err(ParserError::UnexpectedEndOfDocument(_))
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
         ));
 
         // err: incomplete string, missing the closed quote, whitespaces/other chars
         assert!(matches!(
             lex_from_str_without_location("\"abc\n    \n\n    \n   "),
-            Err(ParserError::UnexpectedEndOfDocument(_))
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
         ));
     }
 
@@ -3910,13 +4087,13 @@ recur recur_fn"
         // err: incomplete string, missing the right quote, ends with \n
         assert!(matches!(
             lex_from_str_without_location("\"abc\\\n"),
-            Err(ParserError::UnexpectedEndOfDocument(_))
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
         ));
 
         // err: incomplete string, missing the right quote, ends with whitespaces/other chars
         assert!(matches!(
             lex_from_str_without_location("\"abc\\\n    "),
-            Err(ParserError::UnexpectedEndOfDocument(_))
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
         ));
     }
 
@@ -3953,19 +4130,19 @@ recur recur_fn"
         // err: incomplete string, missing the right quote
         assert!(matches!(
             lex_from_str_without_location("r\"abc    "),
-            Err(ParserError::UnexpectedEndOfDocument(_))
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
         ));
 
         // err: incomplete string, missing the right quote, ends with \n
         assert!(matches!(
             lex_from_str_without_location("r\"abc\n"),
-            Err(ParserError::UnexpectedEndOfDocument(_))
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
         ));
 
         // err: incomplete string, missing the right quote, ends with whitespaces/other chars
         assert!(matches!(
             lex_from_str_without_location("r\"abc\n   "),
-            Err(ParserError::UnexpectedEndOfDocument(_))
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
         ));
     }
 
@@ -4001,19 +4178,19 @@ recur recur_fn"
         // err: incomplete string, missing the closed hash
         assert!(matches!(
             lex_from_str_without_location("r#\"abc    \""),
-            Err(ParserError::UnexpectedEndOfDocument(_))
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
         ));
 
         // err: incomplete string, missing the closed quote, ends with \n
         assert!(matches!(
             lex_from_str_without_location("r#\"abc\n"),
-            Err(ParserError::UnexpectedEndOfDocument(_))
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
         ));
 
         // err: incomplete string, missing the closed quote, ends with whitespace/other chars
         assert!(matches!(
             lex_from_str_without_location("r#\"abc\nxyz"),
-            Err(ParserError::UnexpectedEndOfDocument(_))
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
         ));
     }
 
@@ -4164,6 +4341,7 @@ recur recur_fn"
 "#
             ),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -4183,7 +4361,7 @@ recur recur_fn"
 hello"""
 "#
             ),
-            Err(ParserError::UnexpectedEndOfDocument(_))
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
         ));
 
         // err: missing the ending marker
@@ -4194,7 +4372,7 @@ hello"""
 hello
 world"#
             ),
-            Err(ParserError::UnexpectedEndOfDocument(_))
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
         ));
 
         // err: missing the ending marker, ends with \n
@@ -4205,7 +4383,7 @@ world"#
 hello
 "#
             ),
-            Err(ParserError::UnexpectedEndOfDocument(_))
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
         ));
     }
 
@@ -4294,6 +4472,7 @@ hello
         assert!(matches!(
             lex_from_str_without_location("h\"11 1\""),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -4309,6 +4488,7 @@ hello
         assert!(matches!(
             lex_from_str_without_location("h\"11 1317\""),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -4324,6 +4504,7 @@ hello
         assert!(matches!(
             lex_from_str_without_location("h\"11 1x\""),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -4339,6 +4520,7 @@ hello
         assert!(matches!(
             lex_from_str_without_location("h\"11-13\""),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /* unit: 0, */
@@ -4353,19 +4535,19 @@ hello
         // err: missing the close quote
         assert!(matches!(
             lex_from_str_without_location("h\"11 13"),
-            Err(ParserError::UnexpectedEndOfDocument(_))
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
         ));
 
         // err: missing the close quote, ends with \n
         assert!(matches!(
             lex_from_str_without_location("h\"11 13\n"),
-            Err(ParserError::UnexpectedEndOfDocument(_))
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
         ));
 
         // err: missing the close quote, ends with whitespaces/other chars
         assert!(matches!(
             lex_from_str_without_location("h\"11 13\n    "),
-            Err(ParserError::UnexpectedEndOfDocument(_))
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
         ));
     }
 
@@ -4448,6 +4630,43 @@ hello
         );
     }
 
+    #[test]
+    fn test_lex_document() {
+        assert_eq!(
+            lex_from_str_without_location(
+                r#"
+                //! module
+                /// foo
+                ///
+                //// bar
+                fn"#
+            )
+            .unwrap(),
+            vec![
+                Token::NewLine,
+                Token::Comment(Comment::ModuleDocument(" module".to_owned())),
+                Token::NewLine,
+                Token::Comment(Comment::Document(" foo".to_owned())),
+                Token::NewLine,
+                Token::Comment(Comment::Document("".to_owned())),
+                Token::NewLine,
+                Token::Comment(Comment::Line("// bar".to_owned())),
+                Token::NewLine,
+                Token::new_keyword("fn"),
+            ]
+        );
+
+        // location
+        assert_eq!(
+            lex_from_str("/// foo").unwrap(),
+            vec![TokenWithRange::from_position_and_length(
+                Token::Comment(Comment::Document(" foo".to_owned())),
+                &Location::new_position(/*0,*/ 0, 0, 0),
+                7
+            )]
+        );
+    }
+
     #[test]
     fn test_lex_block_comment() {
         assert_eq!(
@@ -4542,25 +4761,25 @@ hello
         // err: incomplete, missing "*/"
         assert!(matches!(
             lex_from_str_without_location("7 /* 11"),
-            Err(ParserError::UnexpectedEndOfDocument(_))
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
         ));
 
         // err: incomplete, missing "*/", ends with \n
         assert!(matches!(
             lex_from_str_without_location("7 /* 11\n"),
-            Err(ParserError::UnexpectedEndOfDocument(_))
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
         ));
 
         // err: incomplete, unpaired, missing "*/"
         assert!(matches!(
             lex_from_str_without_location("a /* b /* c */"),
-            Err(ParserError::UnexpectedEndOfDocument(_))
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
         ));
 
         // err: incomplete, unpaired, missing "*/", ends with \n
         assert!(matches!(
             lex_from_str_without_location("a /* b /* c */\n"),
-            Err(ParserError::UnexpectedEndOfDocument(_))
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
         ));
     }
 }
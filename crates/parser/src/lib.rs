@@ -9,36 +9,69 @@ use std::fmt::Display;
 
 mod charwithposition;
 mod errorprinter;
-mod lexer;
-mod location;
 mod normalizer;
 mod peekableiter;
-mod token;
 
+pub mod diagnostic;
+pub mod errorcode;
+pub mod lexer;
 pub mod parser;
+pub mod sourcemap;
+pub mod token;
+
+// the location is shared with the AST nodes.
+pub use anc_assembly::location;
 
 pub const NAME_PATH_SEPARATOR: &str = "::";
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum ParserError {
-    Message(String),
-    UnexpectedEndOfDocument(String),
+    Message(ParserErrorKind, String),
+    UnexpectedEndOfDocument(ParserErrorKind, String),
 
     // note that the "index" (and the result of "index+length") may exceed
     // the last index of string, for example, the "char incomplete" error raised by a string `'a`,
     // which index is 2.
-    MessageWithLocation(String, Location),
+    MessageWithLocation(ParserErrorKind, String, Location),
+}
+
+// the kind of the parser error, each kind has a stable error code,
+// see `ParserErrorKind::get_code` and the file "docs/error-codes.md".
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ParserErrorKind {
+    // the syntax errors which have no specific kind.
+    Syntax,
+    UnexpectedChar,
+    InvalidIdentifierChar,
+    InvalidNumber,
+    NumberOutOfRange,
+    InvalidSign,
+    InvalidChar,
+    InvalidEscapeSequence,
+    InvalidHexByteData,
+    InvalidAutoTrimmedString,
+    UnterminatedString,
+    UnterminatedBlockComment,
+    UnexpectedToken,
+    MisplacedDocument,
+    ReexportedImportModule,
+    DataSectionInGlobImport,
+    UnsupportedDataType,
+    InvalidIncludeFileArgument,
+    InvalidFillValue,
+    InvalidRepetitionCount,
+    EmptyImportGroup,
 }
 
 impl Display for ParserError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            ParserError::Message(msg) => f.write_str(msg),
-            ParserError::UnexpectedEndOfDocument(detail) => {
+            ParserError::Message(_, msg) => f.write_str(msg),
+            ParserError::UnexpectedEndOfDocument(_, detail) => {
                 writeln!(f, "Unexpected to reach the end of document.")?;
                 write!(f, "{}", detail)
             }
-            ParserError::MessageWithLocation(detail, location) => {
+            ParserError::MessageWithLocation(_, detail, location) => {
                 writeln!(
                     f,
                     "Error at line: {}, column: {}",
@@ -9,23 +9,64 @@ use std::ops::Neg;
 use crate::{
     location::Location,
     peekableiter::PeekableIter,
-    token::{NumberToken, Token, TokenWithRange},
-    ParserError,
+    token::{Comment, NumberToken, Token, TokenWithRange},
+    ParserError, ParserErrorKind,
 };
 
+// the keywords which start a statement, or which are followed by a statement keyword.
+const STATEMENT_KEYWORDS: [&str; 7] = [
+    "import", "external", "data", "readonly", "uninit", "fn", "pub",
+];
+
 pub fn clean(tokens: Vec<TokenWithRange>) -> Vec<TokenWithRange> {
-    // remove all comments.
+    // remove all comments except the documents (i.e. `///...` and `//!...`),
+    // which are attached to the AST nodes by the parser.
+    //
+    // the documents are only kept in the following positions, otherwise
+    // they are removed as the ordinary comments:
+    //
+    // - the statement document `///...` which is followed by a statement,
+    //   e.g. `/// Adds two numbers.\nfn add(...)`.
+    // - the module document `//!...` which precedes all statements.
+    let is_significant = |token: &Token| !matches!(token, Token::NewLine | Token::Comment(_));
+
+    let is_statement_keyword = |token: Option<&Token>| match token {
+        Some(Token::Keyword(keyword)) => STATEMENT_KEYWORDS.contains(&keyword.as_str()),
+        _ => false,
+    };
+
+    let keeps: Vec<bool> = tokens
+        .iter()
+        .enumerate()
+        .map(|(index, item)| match &item.token {
+            Token::Comment(Comment::Line(_) | Comment::Block(_)) => false,
+            Token::Comment(Comment::Document(_)) => {
+                let next_token = tokens[index + 1..]
+                    .iter()
+                    .map(|item| &item.token)
+                    .find(|token| is_significant(token));
+
+                let previous_token = tokens[..index]
+                    .iter()
+                    .rev()
+                    .map(|item| &item.token)
+                    .find(|token| is_significant(token));
+
+                // the previous token should not be a part of the statement header,
+                // e.g. `pub /// ... fn foo()`.
+                is_statement_keyword(next_token) && !is_statement_keyword(previous_token)
+            }
+            Token::Comment(Comment::ModuleDocument(_)) => !tokens[..index]
+                .iter()
+                .any(|item| is_significant(&item.token)),
+            _ => true,
+        })
+        .collect();
+
     let clean_tokens: Vec<TokenWithRange> = tokens
         .into_iter()
-        .filter(|item| {
-            !matches!(
-                item,
-                TokenWithRange {
-                    token: Token::Comment(_),
-                    ..
-                }
-            )
-        })
+        .zip(keeps)
+        .filter_map(|(item, keep)| if keep { Some(item) } else { None })
         .collect();
 
     clean_tokens
@@ -151,6 +192,7 @@ pub fn normalize(tokens: Vec<TokenWithRange>) -> Result<Vec<TokenWithRange>, Par
                     }) => {
                         // combines two token ranges.
                         return Err(ParserError::MessageWithLocation(
+                            ParserErrorKind::InvalidSign,
                             "The plus sign can only be applied to numbers.".to_owned(),
                             Location::from_range_pair(&start_range, current_range),
                         ));
@@ -158,6 +200,7 @@ pub fn normalize(tokens: Vec<TokenWithRange>) -> Result<Vec<TokenWithRange>, Par
                     None => {
                         // "...+EOF"
                         return Err(ParserError::UnexpectedEndOfDocument(
+                            ParserErrorKind::InvalidSign,
                             "Missing the number that follow the plus sign.".to_owned(),
                         ));
                     }
@@ -203,6 +246,7 @@ pub fn normalize(tokens: Vec<TokenWithRange>) -> Result<Vec<TokenWithRange>, Par
                                 let parse_result =
                                     format!("-{}", v).parse::<i8>().map_err(|_| {
                                         ParserError::MessageWithLocation(
+                                            ParserErrorKind::NumberOutOfRange,
                                             format!("Can not convert \"{}\" to negative i8", v),
                                             combined_range,
                                         )
@@ -225,6 +269,7 @@ pub fn normalize(tokens: Vec<TokenWithRange>) -> Result<Vec<TokenWithRange>, Par
                                 let parse_result =
                                     format!("-{}", v).parse::<i16>().map_err(|_| {
                                         ParserError::MessageWithLocation(
+                                            ParserErrorKind::NumberOutOfRange,
                                             format!("Can not convert \"{}\" to negative i16.", v),
                                             combined_range,
                                         )
@@ -247,6 +292,7 @@ pub fn normalize(tokens: Vec<TokenWithRange>) -> Result<Vec<TokenWithRange>, Par
                                 let parse_result =
                                     format!("-{}", v).parse::<i32>().map_err(|_| {
                                         ParserError::MessageWithLocation(
+                                            ParserErrorKind::NumberOutOfRange,
                                             format!("Can not convert \"{}\" to negative i32.", v),
                                             combined_range,
                                         )
@@ -269,6 +315,7 @@ pub fn normalize(tokens: Vec<TokenWithRange>) -> Result<Vec<TokenWithRange>, Par
                                 let parse_result =
                                     format!("-{}", v).parse::<i64>().map_err(|_| {
                                         ParserError::MessageWithLocation(
+                                            ParserErrorKind::NumberOutOfRange,
                                             format!("Can not convert \"{}\" to negative i64.", v),
                                             combined_range,
                                         )
@@ -284,6 +331,13 @@ pub fn normalize(tokens: Vec<TokenWithRange>) -> Result<Vec<TokenWithRange>, Par
 
                                 ret_val
                             }
+                            NumberToken::Char(_) => {
+                                return Err(ParserError::MessageWithLocation(
+                                    ParserErrorKind::InvalidSign,
+                                    "The minus sign can not be applied to chars.".to_owned(),
+                                    Location::from_range_pair(&start_range, current_range),
+                                ));
+                            }
                         }
                     }
                     Some(TokenWithRange {
@@ -292,6 +346,7 @@ pub fn normalize(tokens: Vec<TokenWithRange>) -> Result<Vec<TokenWithRange>, Par
                     }) => {
                         // combines two token ranges.
                         return Err(ParserError::MessageWithLocation(
+                            ParserErrorKind::InvalidSign,
                             "The minus sign can only be applied to numbers.".to_owned(),
                             Location::from_range_pair(&start_range, current_range),
                         ));
@@ -299,6 +354,7 @@ pub fn normalize(tokens: Vec<TokenWithRange>) -> Result<Vec<TokenWithRange>, Par
                     None => {
                         // "...-EOF"
                         return Err(ParserError::UnexpectedEndOfDocument(
+                            ParserErrorKind::InvalidSign,
                             "Missing the number that follow the minus sign.".to_owned(),
                         ));
                     }
@@ -337,7 +393,7 @@ mod tests {
     use crate::{
         lexer::lex_from_str,
         location::Location,
-        token::{NumberToken, Token, TokenWithRange},
+        token::{Comment, NumberToken, Token, TokenWithRange},
         ParserError,
     };
 
@@ -414,6 +470,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_clean_comments_keep_documents() {
+        assert_eq!(
+            clean_and_lex_from_str_without_location(
+                r#"//! module
+                // line comment
+                /// document
+                fn"#
+            )
+            .unwrap(),
+            vec![
+                Token::Comment(Comment::ModuleDocument(" module".to_owned())),
+                Token::NewLine,
+                Token::NewLine,
+                Token::Comment(Comment::Document(" document".to_owned())),
+                Token::NewLine,
+                Token::Keyword("fn".to_owned()),
+            ]
+        );
+
+        // the documents which are not followed by a statement, and the module
+        // documents after the statements, are removed as the ordinary comments.
+        assert_eq!(
+            clean_and_lex_from_str_without_location(
+                r#"fn
+                //! module
+                /// document
+                11"#
+            )
+            .unwrap(),
+            vec![
+                Token::Keyword("fn".to_owned()),
+                Token::NewLine,
+                Token::NewLine,
+                Token::NewLine,
+                Token::Number(NumberToken::I32(11)),
+            ]
+        );
+    }
+
     #[test]
     fn test_normalize_blanks_commas_and_comments() {
         assert_eq!(
@@ -656,6 +752,7 @@ mod tests {
             assert!(matches!(
                 normalize_and_lex_from_str_without_location("-2_147_483_649"),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /*unit: 0,*/
@@ -699,6 +796,7 @@ mod tests {
             assert!(matches!(
                 normalize_and_lex_from_str_without_location("-129_i8"),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /*unit: 0,*/
@@ -757,6 +855,7 @@ mod tests {
             assert!(matches!(
                 normalize_and_lex_from_str_without_location("-32769_i16"),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /*unit: 0,*/
@@ -815,6 +914,7 @@ mod tests {
             assert!(matches!(
                 normalize_and_lex_from_str_without_location("-2_147_483_649_i32"),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /*unit: 0,*/
@@ -879,6 +979,7 @@ mod tests {
             assert!(matches!(
                 normalize_and_lex_from_str_without_location("-9_223_372_036_854_775_809_i64"),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /*unit: 0,*/
@@ -952,19 +1053,20 @@ mod tests {
         // +EOF
         assert!(matches!(
             normalize_and_lex_from_str_without_location("abc,+"),
-            Err(ParserError::UnexpectedEndOfDocument(_,))
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
         ));
 
         // -EOF
         assert!(matches!(
             normalize_and_lex_from_str_without_location("xyz,-"),
-            Err(ParserError::UnexpectedEndOfDocument(_,))
+            Err(ParserError::UnexpectedEndOfDocument(_, _))
         ));
 
         // err: plus sign is added to non-numbers
         assert!(matches!(
             normalize_and_lex_from_str_without_location("+true"),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /*unit: 0,*/
@@ -980,6 +1082,7 @@ mod tests {
         assert!(matches!(
             normalize_and_lex_from_str_without_location("-true"),
             Err(ParserError::MessageWithLocation(
+                _,
                 _,
                 Location {
                     /*unit: 0,*/
@@ -1334,6 +1437,7 @@ mod tests {
             assert!(matches!(
                 normalize_and_lex_from_str_without_location("-0x8000_0001"),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /*unit: 0,*/
@@ -1377,6 +1481,7 @@ mod tests {
             assert!(matches!(
                 normalize_and_lex_from_str_without_location("-0x81_i8"),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /*unit: 0,*/
@@ -1435,6 +1540,7 @@ mod tests {
             assert!(matches!(
                 normalize_and_lex_from_str_without_location("-0x8001_i16"),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /*unit: 0,*/
@@ -1493,6 +1599,7 @@ mod tests {
             assert!(matches!(
                 normalize_and_lex_from_str_without_location("-0x8000_0001_i32"),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /*unit: 0,*/
@@ -1555,6 +1662,7 @@ mod tests {
             assert!(matches!(
                 normalize_and_lex_from_str_without_location("-0x8000_0000_0000_0001_i64"),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /*unit: 0,*/
@@ -1702,6 +1810,7 @@ mod tests {
                     "-0b1000_0000_0000_0000__0000_0000_0000_0001"
                 ),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /*unit: 0,*/
@@ -1745,6 +1854,7 @@ mod tests {
             assert!(matches!(
                 normalize_and_lex_from_str_without_location("-0b1000_0001_i8"),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /*unit: 0,*/
@@ -1803,6 +1913,7 @@ mod tests {
             assert!(matches!(
                 normalize_and_lex_from_str_without_location("-0b1000_0000_0000_0001_i16"),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /*unit: 0,*/
@@ -1869,6 +1980,7 @@ mod tests {
                     "-0b1000_0000_0000_0000__0000_0000_0000_0001_i32"
                 ),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /*unit: 0,*/
@@ -1927,6 +2039,7 @@ mod tests {
             assert!(matches!(
                 normalize_and_lex_from_str_without_location("-0b1000_0000_0000_0000__0000_0000_0000_0000__0000_0000_0000_0000__0000_0000_0000_0001_i64"),
                 Err(ParserError::MessageWithLocation(
+                    _,
                     _,
                     Location {
                         /*unit: 0,*/
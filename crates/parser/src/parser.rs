@@ -8,10 +8,11 @@ use anc_isa::{DataSectionType, MemoryDataType, OperandDataType};
 
 use anc_assembly::ast::{
     ArgumentValue, BlockNode, BreakNode, DataNode, DataSection, DataTypeValuePair, DataValue,
-    DeclareDataType, ExpressionNode, ExternalDataNode, ExternalFunctionNode, ExternalNode,
-    FixedDeclareDataType, FunctionNode, IfNode, ImportDataNode, ImportFunctionNode, ImportNode,
-    InstructionNode, LiteralNumber, LocalVariable, ModuleNode, NameValueParameter, NamedArgument,
-    NamedParameter, WhenNode,
+    DeclareDataType, DependencySource, ExpressionNode, ExternalDataNode, ExternalFunctionNode,
+    ExternalLibraryNode, ExternalNode, FixedDeclareDataType, FunctionNode, IfNode, ImportDataNode,
+    ImportFunctionNode, ImportGlobNode, ImportItemType, ImportModuleNode, ImportNode, IncludeFile,
+    IncludeFileType, InstructionNode, LiteralNumber, LocalVariable, ModuleNode, NameValueParameter,
+    NamedArgument, NamedParameter, RemoteSource, WhenNode,
 };
 
 use crate::{
@@ -19,8 +20,8 @@ use crate::{
     location::Location,
     normalizer::{clean, normalize},
     peekableiter::PeekableIter,
-    token::{NumberToken, Token, TokenWithRange},
-    ParserError,
+    token::{Comment, NumberToken, Token, TokenWithRange},
+    ParserError, ParserErrorKind, NAME_PATH_SEPARATOR,
 };
 
 pub const PARSER_PEEK_TOKEN_MAX_COUNT: usize = 4;
@@ -167,15 +168,16 @@ impl<'a> Parser<'a> {
                     Ok(())
                 } else {
                     Err(ParserError::MessageWithLocation(
+                        ParserErrorKind::UnexpectedToken,
                         format!("Expect token: {}.", token_description),
                         self.last_range.get_position_by_range_start(),
                     ))
                 }
             }
-            None => Err(ParserError::UnexpectedEndOfDocument(format!(
-                "Expect token: {}.",
-                token_description
-            ))),
+            None => Err(ParserError::UnexpectedEndOfDocument(
+                ParserErrorKind::UnexpectedToken,
+                format!("Expect token: {}.", token_description),
+            )),
         }
     }
 
@@ -183,10 +185,12 @@ impl<'a> Parser<'a> {
         match self.next_token() {
             Some(Token::FullName(s)) => Ok(s),
             Some(_) => Err(ParserError::MessageWithLocation(
+                ParserErrorKind::UnexpectedToken,
                 "Expect a name path.".to_owned(),
                 self.last_range.get_position_by_range_start(),
             )),
             None => Err(ParserError::UnexpectedEndOfDocument(
+                ParserErrorKind::UnexpectedToken,
                 "Expect a name path.".to_owned(),
             )),
         }
@@ -196,10 +200,12 @@ impl<'a> Parser<'a> {
         match self.next_token() {
             Some(Token::Name(s)) => Ok(s),
             Some(_) => Err(ParserError::MessageWithLocation(
+                ParserErrorKind::UnexpectedToken,
                 "Expect a name.".to_owned(),
                 self.last_range.get_position_by_range_start(),
             )),
             None => Err(ParserError::UnexpectedEndOfDocument(
+                ParserErrorKind::UnexpectedToken,
                 "Expect a name.".to_owned(),
             )),
         }
@@ -209,13 +215,29 @@ impl<'a> Parser<'a> {
         match self.next_token() {
             Some(Token::Keyword(s)) if s == keyword => Ok(s),
             Some(_) => Err(ParserError::MessageWithLocation(
+                ParserErrorKind::UnexpectedToken,
+                format!("Expect keyword \"{}\".", keyword),
+                self.last_range.get_position_by_range_start(),
+            )),
+            None => Err(ParserError::UnexpectedEndOfDocument(
+                ParserErrorKind::UnexpectedToken,
                 format!("Expect keyword \"{}\".", keyword),
+            )),
+        }
+    }
+
+    fn consume_string(&mut self) -> Result<String, ParserError> {
+        match self.next_token() {
+            Some(Token::String(s)) => Ok(s),
+            Some(_) => Err(ParserError::MessageWithLocation(
+                ParserErrorKind::UnexpectedToken,
+                "Expect a string.".to_owned(),
                 self.last_range.get_position_by_range_start(),
             )),
-            None => Err(ParserError::UnexpectedEndOfDocument(format!(
-                "Expect keyword \"{}\".",
-                keyword
-            ))),
+            None => Err(ParserError::UnexpectedEndOfDocument(
+                ParserErrorKind::UnexpectedToken,
+                "Expect a string.".to_owned(),
+            )),
         }
     }
 
@@ -223,10 +245,12 @@ impl<'a> Parser<'a> {
         match self.next_token() {
             Some(Token::Number(NumberToken::I32(n))) => Ok(n),
             Some(_) => Err(ParserError::MessageWithLocation(
+                ParserErrorKind::UnexpectedToken,
                 "Expect an i32 number.".to_owned(),
                 self.last_range.get_position_by_range_start(),
             )),
             None => Err(ParserError::UnexpectedEndOfDocument(
+                ParserErrorKind::UnexpectedToken,
                 "Expect an i32 number.".to_owned(),
             )),
         }
@@ -247,6 +271,11 @@ impl<'a> Parser<'a> {
         self.consume_token(&Token::LeftBracket, "left bracket")
     }
 
+    // ';'
+    fn consume_semicolon(&mut self) -> Result<(), ParserError> {
+        self.consume_token(&Token::Semicolon, "semicolon")
+    }
+
     // ']'
     fn consume_right_bracket(&mut self) -> Result<(), ParserError> {
         self.consume_token(&Token::RightBracket, "right bracket")
@@ -276,7 +305,28 @@ impl Parser<'_> {
         let mut datas: Vec<DataNode> = vec![];
         let mut functions: Vec<FunctionNode> = vec![];
 
-        while let Some(token) = self.peek_token(0) {
+        // the module document `//! ...` can only be placed at
+        // the beginning of the source file.
+        let module_document = self.parse_module_document();
+
+        loop {
+            // the statement document `/// ...`
+            let document = self.parse_document();
+
+            let token = match self.peek_token(0) {
+                Some(token) => token,
+                None => {
+                    if document.is_some() {
+                        return Err(ParserError::UnexpectedEndOfDocument(
+                            ParserErrorKind::MisplacedDocument,
+                            "Expect a statement after the document.".to_owned(),
+                        ));
+                    }
+
+                    break;
+                }
+            };
+
             match token {
                 // Token::Keyword(keyword) if keyword == "use" => {
                 //     // use statement
@@ -284,19 +334,23 @@ impl Parser<'_> {
                 // }
                 Token::Keyword(keyword) if keyword == "import" => {
                     // import statements
-                    imports.push(self.parse_import_node()?);
+                    imports.extend(self.parse_import_node(false, document)?);
                 }
                 Token::Keyword(keyword) if keyword == "external" => {
                     // external statement
-                    externals.push(self.parse_external_node()?);
+                    externals.push(self.parse_external_node(document)?);
                 }
                 Token::Keyword(keyword) if keyword == "data" => {
                     // private read-write data statement
-                    datas.push(self.parse_data_node(false, DataSectionType::ReadWrite)?);
+                    datas.push(self.parse_data_node(
+                        false,
+                        document,
+                        DataSectionType::ReadWrite,
+                    )?);
                 }
                 Token::Keyword(keyword) if keyword == "fn" => {
                     // private fn statement
-                    functions.push(self.parse_function_node(false)?);
+                    functions.push(self.parse_function_node(false, document)?);
                 }
                 Token::Keyword(keyword) if keyword == "readonly" || keyword == "uninit" => {
                     // private {readonly|uninit} data statement
@@ -309,7 +363,7 @@ impl Parser<'_> {
                     self.next_token(); // consume 'readonly' or 'uninit'
                     self.consume_new_line_if_exist();
 
-                    datas.push(self.parse_data_node(false, data_section_type)?);
+                    datas.push(self.parse_data_node(false, document, data_section_type)?);
                 }
                 Token::Keyword(keyword) if keyword == "pub" => {
                     self.next_token(); // consume 'pub'
@@ -319,11 +373,19 @@ impl Parser<'_> {
                         match next_token {
                             Token::Keyword(next_keyword) if next_keyword == "data" => {
                                 // public read-write data statement
-                                datas.push(self.parse_data_node(true, DataSectionType::ReadWrite)?);
+                                datas.push(self.parse_data_node(
+                                    true,
+                                    document,
+                                    DataSectionType::ReadWrite,
+                                )?);
                             }
                             Token::Keyword(next_keyword) if next_keyword == "fn" => {
                                 // public fn statement
-                                functions.push(self.parse_function_node(true)?);
+                                functions.push(self.parse_function_node(true, document)?);
+                            }
+                            Token::Keyword(next_keyword) if next_keyword == "import" => {
+                                // re-export import statement
+                                imports.extend(self.parse_import_node(true, document)?);
                             }
                             Token::Keyword(next_keyword)
                                 if next_keyword == "readonly" || next_keyword == "uninit" =>
@@ -338,23 +400,38 @@ impl Parser<'_> {
                                 self.next_token(); // consume 'readonly' or 'uninit'
                                 self.consume_new_line_if_exist();
 
-                                datas.push(self.parse_data_node(true, data_section_type)?);
+                                datas.push(self.parse_data_node(
+                                    true,
+                                    document,
+                                    data_section_type,
+                                )?);
                             }
                             _ => {
                                 return Err(ParserError::MessageWithLocation(
-                                    "Expect a data or a function.".to_owned(),
+                                    ParserErrorKind::UnexpectedToken,
+                                    "Expect a data, a function or an import.".to_owned(),
                                     self.peek_range(0).unwrap().get_position_by_range_start(),
                                 ));
                             }
                         }
                     } else {
                         return Err(ParserError::UnexpectedEndOfDocument(
-                            "Expect a data or a function.".to_owned(),
+                            ParserErrorKind::UnexpectedToken,
+                            "Expect a data, a function or an import.".to_owned(),
                         ));
                     }
                 }
+                Token::Comment(Comment::ModuleDocument(_)) => {
+                    return Err(ParserError::MessageWithLocation(
+                        ParserErrorKind::MisplacedDocument,
+                        "The module document must be placed before the documents of statements."
+                            .to_owned(),
+                        self.peek_range(0).unwrap().get_position_by_range_start(),
+                    ));
+                }
                 _ => {
                     return Err(ParserError::MessageWithLocation(
+                        ParserErrorKind::UnexpectedToken,
                         "Unexpected token.".to_owned(),
                         self.peek_range(0).unwrap().get_position_by_range_start(),
                     ));
@@ -363,6 +440,7 @@ impl Parser<'_> {
         }
 
         let module_node = ModuleNode {
+            document: module_document,
             // full_name: full_name.to_owned(),
             // uses,
             imports,
@@ -374,6 +452,40 @@ impl Parser<'_> {
         Ok(module_node)
     }
 
+    fn parse_module_document(&mut self) -> Option<String> {
+        // //! ... ?  //
+        // ^       ^__// to here
+        // |----------// current token, NOT validated
+
+        let mut lines: Vec<String> = vec![];
+
+        while let Some(Token::Comment(Comment::ModuleDocument(line))) = self.peek_token(0) {
+            lines.push(trim_document_line(line));
+
+            self.next_token(); // consume the document line
+            self.consume_new_line_if_exist();
+        }
+
+        join_document_lines(lines)
+    }
+
+    fn parse_document(&mut self) -> Option<String> {
+        // /// ... ?  //
+        // ^       ^__// to here
+        // |----------// current token, NOT validated
+
+        let mut lines: Vec<String> = vec![];
+
+        while let Some(Token::Comment(Comment::Document(line))) = self.peek_token(0) {
+            lines.push(trim_document_line(line));
+
+            self.next_token(); // consume the document line
+            self.consume_new_line_if_exist();
+        }
+
+        join_document_lines(lines)
+    }
+
     // fn parse_use_node(&mut self) -> Result<UseNode, Error> {
     //     // use ... [as ...] ?  //
     //     // ^                ^__// to here
@@ -407,10 +519,19 @@ impl Parser<'_> {
     //     Ok(node)
     // }
 
-    fn parse_import_node(&mut self) -> Result<ImportNode, ParserError> {
-        // import {fn|data} ... ?  //
+    fn parse_import_node(
+        &mut self,
+        public: bool,
+        document: Option<String>,
+    ) -> Result<Vec<ImportNode>, ParserError> {
+        // import {fn|data|module} ... ?  //
         // ^                      ^__// to here
         // |-------------------------// current token, validated
+        //
+        // a grouped import statement produces multiple nodes, e.g.
+        // `import fn std::memory::{copy, fill as memset}`
+        //
+        // the `public` indicates the imported items are re-exported, i.e. `pub import ...`
 
         self.next_token(); // consume 'import'
         self.consume_new_line_if_exist();
@@ -418,78 +539,345 @@ impl Parser<'_> {
         if let Some(token) = self.peek_token(0) {
             match token {
                 Token::Keyword(keyword) if keyword == "fn" => {
-                    let function_node = self.parse_import_function_node()?;
-                    Ok(ImportNode::Function(function_node))
+                    self.parse_import_function_nodes(public, document)
                 }
                 Token::Keyword(keyword) if keyword == "data" => {
-                    let data_node = self.parse_import_data_node(DataSectionType::ReadWrite)?;
-                    Ok(ImportNode::Data(data_node))
+                    self.parse_import_data_nodes(public, document, None)
                 }
                 Token::Keyword(keyword) if keyword == "readonly" => {
                     self.next_token(); // consume 'readonly'
                     self.consume_new_line_if_exist();
 
-                    let data_node = self.parse_import_data_node(DataSectionType::ReadOnly)?;
-                    Ok(ImportNode::Data(data_node))
+                    self.parse_import_data_nodes(public, document, Some(DataSectionType::ReadOnly))
                 }
                 Token::Keyword(keyword) if keyword == "uninit" => {
                     self.next_token(); // consume 'uninit'
                     self.consume_new_line_if_exist();
 
-                    let data_node = self.parse_import_data_node(DataSectionType::Uninit)?;
-                    Ok(ImportNode::Data(data_node))
+                    self.parse_import_data_nodes(public, document, Some(DataSectionType::Uninit))
+                }
+                Token::Name(name) if name == "module" && public => {
+                    Err(ParserError::MessageWithLocation(
+                        ParserErrorKind::ReexportedImportModule,
+                        "The import module can not be re-exported.".to_owned(),
+                        self.peek_range(0).unwrap().get_position_by_range_start(),
+                    ))
+                }
+                Token::Name(name) if name == "module" => {
+                    let module_node = self.parse_import_module_node(document)?;
+                    Ok(vec![ImportNode::Module(module_node)])
                 }
                 _ => Err(ParserError::MessageWithLocation(
-                    "Expect import \"fn\" or \"data\".".to_owned(),
+                    ParserErrorKind::UnexpectedToken,
+                    "Expect import \"fn\", \"data\" or \"module\".".to_owned(),
                     self.peek_range(0).unwrap().get_position_by_range_start(),
                 )),
             }
         } else {
             Err(ParserError::UnexpectedEndOfDocument(
-                "Expect import \"fn\" or \"data\".".to_owned(),
+                ParserErrorKind::UnexpectedToken,
+                "Expect import \"fn\", \"data\" or \"module\".".to_owned(),
             ))
         }
     }
 
-    fn parse_import_function_node(&mut self) -> Result<ImportFunctionNode, ParserError> {
-        // fn full_name ()->() [as ...] [from ...]?  //
-        // ^                                      ^__// to here
-        // |-----------------------------------------// current token, validated
+    fn parse_import_module_node(
+        &mut self,
+        document: Option<String>,
+    ) -> Result<ImportModuleNode, ParserError> {
+        // module name {version|from} "..." ?  //
+        // ^                                ^__// to here
+        // |-----------------------------------// current token, validated
+
+        self.next_token(); // consume 'module'
+        self.consume_new_line_if_exist();
+
+        let name = self.consume_name()?;
+        let location = self.last_range;
+        self.consume_new_line_if_exist();
+
+        let source = self.continue_parse_dependency_source(false)?;
+        self.consume_new_line_if_exist();
+
+        let node = ImportModuleNode {
+            document,
+            name,
+            source,
+            location,
+        };
+        Ok(node)
+    }
+
+    fn continue_parse_dependency_source(
+        &mut self,
+        is_external_library: bool,
+    ) -> Result<DependencySource, ParserError> {
+        // {version|from|remote|runtime|system} "..." ?  //
+        // ^                                          ^__// to here
+        // |---------------------------------------------// current token, NOT validated
+
+        let expected_message = if is_external_library {
+            "Expect \"version\", \"from\", \"remote\" or \"system\"."
+        } else {
+            "Expect \"version\", \"from\", \"remote\" or \"runtime\"."
+        };
+
+        let source = match self.peek_token(0) {
+            Some(Token::Name(name)) if name == "version" => {
+                self.next_token(); // consume 'version'
+                self.consume_new_line_if_exist();
+
+                DependencySource::Version(self.consume_string()?)
+            }
+            Some(Token::Keyword(keyword)) if keyword == "from" => {
+                self.next_token(); // consume 'from'
+                self.consume_new_line_if_exist();
+
+                DependencySource::Local(self.consume_string()?)
+            }
+            Some(Token::Name(name)) if name == "remote" => {
+                self.next_token(); // consume 'remote'
+                self.consume_new_line_if_exist();
+
+                let url = self.consume_string()?;
+                self.consume_new_line_if_exist();
+
+                let reversion = self.consume_string()?;
+
+                DependencySource::Remote(RemoteSource { url, reversion })
+            }
+            Some(Token::Name(name)) if name == "runtime" && !is_external_library => {
+                self.next_token(); // consume 'runtime'
+
+                DependencySource::Runtime
+            }
+            Some(Token::Name(name)) if name == "system" && is_external_library => {
+                self.next_token(); // consume 'system'
+                self.consume_new_line_if_exist();
+
+                DependencySource::System(self.consume_string()?)
+            }
+            Some(_) => {
+                return Err(ParserError::MessageWithLocation(
+                    ParserErrorKind::UnexpectedToken,
+                    expected_message.to_owned(),
+                    self.peek_range(0).unwrap().get_position_by_range_start(),
+                ));
+            }
+            None => {
+                return Err(ParserError::UnexpectedEndOfDocument(
+                    ParserErrorKind::UnexpectedToken,
+                    expected_message.to_owned(),
+                ));
+            }
+        };
+
+        Ok(source)
+    }
+
+    fn parse_import_function_nodes(
+        &mut self,
+        public: bool,
+        document: Option<String>,
+    ) -> Result<Vec<ImportNode>, ParserError> {
+        // fn full_name [()->()] [as ...] [from ...] ?  //
+        // fn module_path::{name [()->()] [as ...], ...} [from ...] ?
+        // fn module_path::* [from ...] ?
+        // ^                                         ^__// to here
+        // |--------------------------------------------// current token, validated
 
         self.next_token(); // consume 'fn'
         self.consume_new_line_if_exist();
 
         let full_name = self.consume_full_name()?;
+        let full_name_location = self.last_range;
         self.consume_new_line_if_exist();
 
+        if let Some(module_path) = full_name.strip_suffix("::*") {
+            // glob import
+            let from = self.continue_parse_import_from()?;
+
+            let node = ImportGlobNode {
+                document,
+                public,
+                item_type: ImportItemType::Function,
+                module_path: module_path.to_owned(),
+                from,
+                location: full_name_location,
+            };
+            return Ok(vec![ImportNode::Glob(node)]);
+        }
+
+        if full_name.ends_with(NAME_PATH_SEPARATOR) && self.expect_token(0, &Token::LeftBrace) {
+            // grouped import
+            return self.continue_parse_import_group(|parser, name, name_location| {
+                let signature = parser.continue_parse_import_function_signature()?;
+                let (alias_name, location) =
+                    parser.continue_parse_import_alias_name(name_location)?;
+
+                Ok(ImportNode::Function(ImportFunctionNode {
+                    document: document.clone(),
+                    public,
+                    full_name: format!("{}{}", full_name, name),
+                    signature,
+                    alias_name,
+                    from: None,
+                    location,
+                }))
+            });
+        }
+
+        let signature = self.continue_parse_import_function_signature()?;
+        self.consume_new_line_if_exist();
+
+        let (alias_name, location) = self.continue_parse_import_alias_name(
+            get_identifier_location(&full_name, &full_name_location),
+        )?;
+        let from = self.continue_parse_import_from()?;
+
+        let node = ImportFunctionNode {
+            document,
+            public,
+            full_name,
+            signature,
+            alias_name,
+            from,
+            location,
+        };
+
+        Ok(vec![ImportNode::Function(node)])
+    }
+
+    fn continue_parse_import_function_signature(
+        &mut self,
+    ) -> Result<Option<(Vec<OperandDataType>, Vec<OperandDataType>)>, ParserError> {
+        // [(type, ...) [-> ...]] ?  //
+        // ^                      ^__// to here
+        // |-------------------------// current token, NOT validated
+
+        // the signature can be omitted, it is resolved from
+        // the image of the dependency module.
+        if !self.expect_token(0, &Token::LeftParen) {
+            return Ok(None);
+        }
+
         // parse the parameters
         let params = self.continue_parse_function_signature_params()?;
-        self.consume_new_line_if_exist();
 
         // parse the return data type
-        let results: Vec<OperandDataType> = if self.expect_token(0, &Token::RightArrow) {
-            self.next_token(); // consume '->'
-            self.consume_new_line_if_exist();
+        let results: Vec<OperandDataType> =
+            match self.expect_token_ignore_newline(0, &Token::RightArrow) {
+                Some(exists_newline) => {
+                    if exists_newline {
+                        self.next_token(); // consume '\n'
+                    }
+                    self.next_token(); // consume '->'
+                    self.consume_new_line_if_exist();
 
-            self.continue_parse_function_results()?
-        } else {
-            vec![]
-        };
+                    self.continue_parse_function_results()?
+                }
+                None => vec![],
+            };
+
+        // the trailing newline is not consumed since it may be the separator of the grouped items.
+        Ok(Some((params, results)))
+    }
+
+    fn continue_parse_import_group(
+        &mut self,
+        mut parse_item: impl FnMut(&mut Self, String, Location) -> Result<ImportNode, ParserError>,
+    ) -> Result<Vec<ImportNode>, ParserError> {
+        // {name ..., ...} [from ...] ?  //
+        // ^                          ^__// to here
+        // |-----------------------------// current token, validated
+        //
+        // the `parse_item` parses the rest of each item after the name,
+        // the arguments are the name and its location.
+
+        self.next_token(); // consume '{'
         self.consume_new_line_if_exist();
 
-        // parse the 'as' part
-        let alias_name = if self.expect_keyword(0, "as") {
+        let mut nodes = vec![];
+
+        while let Some(token) = self.peek_token(0) {
+            if token == &Token::RightBrace {
+                break;
+            }
+
+            let name = self.consume_name()?;
+            let name_location = self.last_range;
+            nodes.push(parse_item(self, name, name_location)?);
+
+            let found_sep = self.consume_new_line_or_comma_if_exist();
+            if !found_sep {
+                break;
+            }
+        }
+
+        self.consume_right_brace()?; // consume '}'
+
+        if nodes.is_empty() {
+            return Err(ParserError::MessageWithLocation(
+                ParserErrorKind::EmptyImportGroup,
+                "The group of import items can not be empty.".to_owned(),
+                self.last_range.get_position_by_range_start(),
+            ));
+        }
+
+        self.consume_new_line_if_exist();
+
+        // the 'from' part applies to all items in the group.
+        let from = self.continue_parse_import_from()?;
+
+        for node in &mut nodes {
+            match node {
+                ImportNode::Function(import_function_node) => {
+                    import_function_node.from = from.clone();
+                }
+                ImportNode::Data(import_data_node) => {
+                    import_data_node.from = from.clone();
+                }
+                ImportNode::Module(_) | ImportNode::Glob(_) => {
+                    // the group contains functions or data only.
+                }
+            }
+        }
+
+        Ok(nodes)
+    }
+
+    fn continue_parse_import_alias_name(
+        &mut self,
+        identifier_location: Location,
+    ) -> Result<(Option<String>, Location), ParserError> {
+        // [as name] ?  //
+        // ^         ^__// to here
+        // |------------// current token, NOT validated
+        //
+        // returns the alias name and the location of the identifier of the imported item,
+        // i.e. the location of the alias name if it presents, otherwise the `identifier_location`.
+
+        let (alias_name, location) = if self.expect_keyword(0, "as") {
             self.next_token(); // consume 'as'
             self.consume_new_line_if_exist();
 
             let name = self.consume_name()?;
-            Some(name)
+            (Some(name), self.last_range)
         } else {
-            None
+            (None, identifier_location)
         };
+
+        // the newline is not consumed since it may be the separator of the grouped items.
+        Ok((alias_name, location))
+    }
+
+    fn continue_parse_import_from(&mut self) -> Result<Option<String>, ParserError> {
+        // [from name] ?  //
+        // ^           ^__// to here
+        // |--------------// current token, NOT validated
+
         self.consume_new_line_if_exist();
 
-        // parse the 'from' part
         let from = if self.expect_keyword(0, "from") {
             self.next_token(); // consume 'from'
             self.consume_new_line_if_exist();
@@ -501,15 +889,7 @@ impl Parser<'_> {
         };
         self.consume_new_line_if_exist();
 
-        let node = ImportFunctionNode {
-            full_name,
-            params,
-            results,
-            alias_name,
-            from,
-        };
-
-        Ok(node)
+        Ok(from)
     }
 
     fn continue_parse_function_signature_params(
@@ -542,63 +922,116 @@ impl Parser<'_> {
         Ok(params)
     }
 
-    fn parse_import_data_node(
+    fn parse_import_data_nodes(
         &mut self,
-        data_section_type: DataSectionType,
-    ) -> Result<ImportDataNode, ParserError> {
-        // data full_name type data_type [as ...] [from ...] ?  //
-        // ^                                                 ^__// to here
-        // |----------------------------------------------------// current token, NOT validated
+        public: bool,
+        document: Option<String>,
+        data_section_type: Option<DataSectionType>,
+    ) -> Result<Vec<ImportNode>, ParserError> {
+        // data full_name [type data_type] [as ...] [from ...] ?  //
+        // data module_path::{name [type data_type] [as ...], ...} [from ...] ?
+        // data module_path::* [from ...] ?
+        // ^                                                   ^__// to here
+        // |------------------------------------------------------// current token, NOT validated
 
         self.consume_keyword("data")?; // consume 'data'
         self.consume_new_line_if_exist();
 
         let full_name = self.consume_full_name()?;
-        self.consume_new_line_if_exist();
+        let full_name_location = self.last_range;
 
-        self.consume_keyword("type")?; // consume keyword "type"
-        self.consume_new_line_if_exist();
-
-        let data_type = self.continue_parse_external_data_type()?;
-        self.consume_new_line_if_exist();
+        if let Some(module_path) = full_name.strip_suffix("::*") {
+            // glob import, the data section of each item is taken
+            // from the dependency module.
+            if data_section_type.is_some() {
+                return Err(ParserError::MessageWithLocation(
+                    ParserErrorKind::DataSectionInGlobImport,
+                    "The data section can not be specified for glob import.".to_owned(),
+                    self.last_range.get_position_by_range_start(),
+                ));
+            }
 
-        // parse the 'as' part
-        let alias_name = if self.expect_keyword(0, "as") {
-            self.next_token(); // consume 'as'
             self.consume_new_line_if_exist();
+            let from = self.continue_parse_import_from()?;
+
+            let node = ImportGlobNode {
+                document,
+                public,
+                item_type: ImportItemType::Data,
+                module_path: module_path.to_owned(),
+                from,
+                location: full_name_location,
+            };
+            return Ok(vec![ImportNode::Glob(node)]);
+        }
 
-            let name = self.consume_name()?;
-            Some(name)
-        } else {
-            None
-        };
         self.consume_new_line_if_exist();
 
-        // parse the 'from' part
-        let from = if self.expect_keyword(0, "from") {
-            self.next_token(); // consume 'from'
-            self.consume_new_line_if_exist();
+        if full_name.ends_with(NAME_PATH_SEPARATOR) && self.expect_token(0, &Token::LeftBrace) {
+            // grouped import
+            return self.continue_parse_import_group(|parser, name, name_location| {
+                let data_type = parser.continue_parse_import_data_type()?;
+                let (alias_name, location) =
+                    parser.continue_parse_import_alias_name(name_location)?;
 
-            let name = self.consume_name()?;
-            Some(name)
-        } else {
-            None
-        };
+                Ok(ImportNode::Data(ImportDataNode {
+                    document: document.clone(),
+                    public,
+                    data_section_type: get_import_data_section_type(data_section_type, data_type),
+                    full_name: format!("{}{}", full_name, name),
+                    data_type,
+                    alias_name,
+                    from: None,
+                    location,
+                }))
+            });
+        }
+
+        let data_type = self.continue_parse_import_data_type()?;
         self.consume_new_line_if_exist();
 
+        let (alias_name, location) = self.continue_parse_import_alias_name(
+            get_identifier_location(&full_name, &full_name_location),
+        )?;
+        let from = self.continue_parse_import_from()?;
+
         let node = ImportDataNode {
-            data_section_type,
+            document,
+            public,
+            data_section_type: get_import_data_section_type(data_section_type, data_type),
             full_name,
             data_type,
             alias_name,
             from,
+            location,
         };
 
-        Ok(node)
+        Ok(vec![ImportNode::Data(node)])
+    }
+
+    fn continue_parse_import_data_type(&mut self) -> Result<Option<MemoryDataType>, ParserError> {
+        // [type data_type] ?  //
+        // ^                ^__// to here
+        // |-------------------// current token, NOT validated
+
+        // the data type can be omitted, it is resolved from
+        // the image of the dependency module.
+        if !self.expect_keyword(0, "type") {
+            return Ok(None);
+        }
+
+        self.next_token(); // consume keyword "type"
+        self.consume_new_line_if_exist();
+
+        let data_type = self.continue_parse_external_data_type()?;
+        Ok(Some(data_type))
     }
 
-    fn parse_external_node(&mut self) -> Result<ExternalNode, ParserError> {
-        // external {fn|data} ... ?  //
+    fn parse_external_node(
+        &mut self,
+        document: Option<String>,
+    ) -> Result<ExternalNode, ParserError> {
+        // external {fn|data|library} ... ?  //
         // ^                      ^__// to here
         // |-------------------------// current token, validated
 
@@ -609,27 +1042,64 @@ impl Parser<'_> {
             match token {
                 Token::Keyword(keyword) if keyword == "fn" => {
                     // external fn ...
-                    let function_node = self.parse_external_function_node()?;
+                    let function_node = self.parse_external_function_node(document)?;
                     Ok(ExternalNode::Function(function_node))
                 }
                 Token::Keyword(keyword) if keyword == "data" => {
                     // external data ...
-                    let data_node = self.parse_external_data_node()?;
+                    let data_node = self.parse_external_data_node(document)?;
                     Ok(ExternalNode::Data(data_node))
                 }
+                Token::Name(name) if name == "library" => {
+                    // external library ...
+                    let library_node = self.parse_external_library_node(document)?;
+                    Ok(ExternalNode::Library(library_node))
+                }
                 _ => Err(ParserError::MessageWithLocation(
-                    "Expect external \"fn\" or \"data\".".to_owned(),
+                    ParserErrorKind::UnexpectedToken,
+                    "Expect external \"fn\", \"data\" or \"library\".".to_owned(),
                     self.peek_range(0).unwrap().get_position_by_range_start(),
                 )),
             }
         } else {
             Err(ParserError::UnexpectedEndOfDocument(
-                "Expect external \"fn\" or \"data\".".to_owned(),
+                ParserErrorKind::UnexpectedToken,
+                "Expect external \"fn\", \"data\" or \"library\".".to_owned(),
             ))
         }
     }
 
-    fn parse_external_function_node(&mut self) -> Result<ExternalFunctionNode, ParserError> {
+    fn parse_external_library_node(
+        &mut self,
+        document: Option<String>,
+    ) -> Result<ExternalLibraryNode, ParserError> {
+        // library name {version|from|system} "..." ?  //
+        // ^                                        ^__// to here
+        // |-------------------------------------------// current token, validated
+
+        self.next_token(); // consume 'library'
+        self.consume_new_line_if_exist();
+
+        let name = self.consume_name()?;
+        let location = self.last_range;
+        self.consume_new_line_if_exist();
+
+        let source = self.continue_parse_dependency_source(true)?;
+        self.consume_new_line_if_exist();
+
+        let node = ExternalLibraryNode {
+            document,
+            name,
+            source,
+            location,
+        };
+        Ok(node)
+    }
+
+    fn parse_external_function_node(
+        &mut self,
+        document: Option<String>,
+    ) -> Result<ExternalFunctionNode, ParserError> {
         // fn full_name ()->() [as ...] ?  //
         // ^                            ^__// to here
         // |-------------------------------// current token, validated
@@ -638,6 +1108,7 @@ impl Parser<'_> {
         self.consume_new_line_if_exist();
 
         let full_name = self.consume_full_name()?;
+        let full_name_location = self.last_range;
         self.consume_new_line_if_exist();
 
         // parse the parameters
@@ -682,22 +1153,27 @@ impl Parser<'_> {
         self.consume_new_line_if_exist();
 
         // parse the 'as' part
-        let alias_name = if self.expect_keyword(0, "as") {
+        let (alias_name, location) = if self.expect_keyword(0, "as") {
             self.next_token(); // consume 'as'
             self.consume_new_line_if_exist();
 
             let name = self.consume_name()?;
-            Some(name)
+            (Some(name), self.last_range)
         } else {
-            None
+            (
+                None,
+                get_identifier_location(&full_name, &full_name_location),
+            )
         };
         self.consume_new_line_if_exist();
 
         let node = ExternalFunctionNode {
+            document,
             full_name,
             params,
             result: return_,
             alias_name,
+            location,
         };
 
         Ok(node)
@@ -737,6 +1213,7 @@ impl Parser<'_> {
                     }
                     _ => {
                         return Err(ParserError::MessageWithLocation(
+                            ParserErrorKind::UnsupportedDataType,
                             "Unsupported data type for function parameters.".to_owned(),
                             self.peek_range(0).unwrap().get_position_by_range_start(),
                         ));
@@ -745,6 +1222,7 @@ impl Parser<'_> {
             }
             _ => {
                 return Err(ParserError::MessageWithLocation(
+                    ParserErrorKind::UnsupportedDataType,
                     "Expect a valid data type".to_owned(),
                     self.peek_range(0).unwrap().get_position_by_range_start(),
                 ));
@@ -754,7 +1232,10 @@ impl Parser<'_> {
         Ok(data_type)
     }
 
-    fn parse_external_data_node(&mut self) -> Result<ExternalDataNode, ParserError> {
+    fn parse_external_data_node(
+        &mut self,
+        document: Option<String>,
+    ) -> Result<ExternalDataNode, ParserError> {
         // data full_name type data_type [as ...] ?  //
         // ^                                      ^__// to here
         // |-----------------------------------------// current token, validated
@@ -762,6 +1243,7 @@ impl Parser<'_> {
         self.consume_new_line_if_exist();
 
         let full_name = self.consume_full_name()?;
+        let full_name_location = self.last_range;
         self.consume_new_line_if_exist();
 
         self.consume_keyword("type")?; // consume keyword "type"
@@ -771,21 +1253,26 @@ impl Parser<'_> {
         self.consume_new_line_if_exist();
 
         // parse the 'as' part
-        let alias_name = if self.expect_keyword(0, "as") {
+        let (alias_name, location) = if self.expect_keyword(0, "as") {
             self.next_token(); // consume 'as'
             self.consume_new_line_if_exist();
 
             let name = self.consume_name()?;
-            Some(name)
+            (Some(name), self.last_range)
         } else {
-            None
+            (
+                None,
+                get_identifier_location(&full_name, &full_name_location),
+            )
         };
         self.consume_new_line_if_exist();
 
         let node = ExternalDataNode {
+            document,
             full_name,
             data_type,
             alias_name,
+            location,
         };
 
         Ok(node)
@@ -836,6 +1323,7 @@ impl Parser<'_> {
                     }
                     _ => {
                         return Err(ParserError::MessageWithLocation(
+                            ParserErrorKind::UnsupportedDataType,
                             "Unsupported data type for external data.".to_owned(),
                             self.peek_range(0).unwrap().get_position_by_range_start(),
                         ));
@@ -844,6 +1332,7 @@ impl Parser<'_> {
             }
             _ => {
                 return Err(ParserError::MessageWithLocation(
+                    ParserErrorKind::UnsupportedDataType,
                     "Expect a valid data type".to_owned(),
                     self.peek_range(0).unwrap().get_position_by_range_start(),
                 ));
@@ -856,6 +1345,7 @@ impl Parser<'_> {
     fn parse_data_node(
         &mut self,
         public: bool,
+        document: Option<String>,
         data_section_type: DataSectionType,
     ) -> Result<DataNode, ParserError> {
         // data name:type = value ?  //
@@ -869,6 +1359,7 @@ impl Parser<'_> {
         self.consume_new_line_if_exist();
 
         let name = self.consume_name()?;
+        let location = self.last_range;
         self.consume_new_line_if_exist();
 
         self.consume_colon()?; // consume ':'
@@ -883,11 +1374,18 @@ impl Parser<'_> {
                 self.consume_equal()?;
                 self.consume_new_line_if_exist();
 
-                let value = self.continue_parse_data_value()?;
+                let value = if matches!(self.peek_token(0), Some(Token::Name(name)) if name == "fill")
+                    && self.expect_token(1, &Token::LeftParen)
+                {
+                    self.continue_parse_fill_data_value(&data_type)?
+                } else {
+                    self.continue_parse_data_value()?
+                };
 
                 self.consume_new_line_if_exist(); // consume '\n'
 
                 Ok(DataNode {
+                    document,
                     public,
                     name,
                     data_section: if data_section_type == DataSectionType::ReadOnly {
@@ -895,6 +1393,7 @@ impl Parser<'_> {
                     } else {
                         DataSection::ReadWrite(DataTypeValuePair { data_type, value })
                     },
+                    location,
                 })
             }
             DataSectionType::Uninit => {
@@ -902,9 +1401,11 @@ impl Parser<'_> {
                 self.consume_new_line_if_exist(); // consume '\n'
 
                 Ok(DataNode {
+                    document,
                     public,
                     name,
                     data_section: DataSection::Uninit(data_type),
+                    location,
                 })
             }
         }
@@ -916,7 +1417,7 @@ impl Parser<'_> {
         // |------// current token, validated
         //
         // also:
-        // - i64, f32, f64
+        // - i64, i16, i8, f32, f64
         // - byte[], byte[align=4]
         // - byte[1024], byte[1024, align=8]
 
@@ -936,6 +1437,14 @@ impl Parser<'_> {
                         self.next_token(); // consume i32
                         DeclareDataType::I32
                     }
+                    "i16" => {
+                        self.next_token(); // consume i16
+                        DeclareDataType::I16
+                    }
+                    "i8" => {
+                        self.next_token(); // consume i8
+                        DeclareDataType::I8
+                    }
                     "f64" => {
                         self.next_token(); // consume f64
                         DeclareDataType::F64
@@ -997,6 +1506,7 @@ impl Parser<'_> {
                     }
                     _ => {
                         return Err(ParserError::MessageWithLocation(
+                            ParserErrorKind::UnsupportedDataType,
                             "Unsupported data type for data.".to_owned(),
                             self.peek_range(0).unwrap().get_position_by_range_start(),
                         ));
@@ -1005,6 +1515,7 @@ impl Parser<'_> {
             }
             _ => {
                 return Err(ParserError::MessageWithLocation(
+                    ParserErrorKind::UnsupportedDataType,
                     "Expect a valid data type".to_owned(),
                     self.peek_range(0).unwrap().get_position_by_range_start(),
                 ));
@@ -1022,7 +1533,7 @@ impl Parser<'_> {
         // |------// current token, validated
         //
         // also:
-        // - i64, f32, f64
+        // - i64, i16, i8, f32, f64
         // - byte[1024], byte[1024, align=8]
 
         let token = self.peek_token(0).unwrap();
@@ -1041,6 +1552,14 @@ impl Parser<'_> {
                         self.next_token(); // consume i32
                         FixedDeclareDataType::I32
                     }
+                    "i16" => {
+                        self.next_token(); // consume i16
+                        FixedDeclareDataType::I16
+                    }
+                    "i8" => {
+                        self.next_token(); // consume i8
+                        FixedDeclareDataType::I8
+                    }
                     "f64" => {
                         self.next_token(); // consume f64
                         FixedDeclareDataType::F64
@@ -1080,6 +1599,7 @@ impl Parser<'_> {
                     }
                     _ => {
                         return Err(ParserError::MessageWithLocation(
+                            ParserErrorKind::UnsupportedDataType,
                             "Unsupported data type for data.".to_owned(),
                             self.peek_range(0).unwrap().get_position_by_range_start(),
                         ));
@@ -1088,6 +1608,7 @@ impl Parser<'_> {
             }
             _ => {
                 return Err(ParserError::MessageWithLocation(
+                    ParserErrorKind::UnsupportedDataType,
                     "Expect a valid data type".to_owned(),
                     self.peek_range(0).unwrap().get_position_by_range_start(),
                 ));
@@ -1107,6 +1628,8 @@ impl Parser<'_> {
         // - Strings: normal string, multiline string, long string, raw string, raw string with hash symbol, auto-trimmed string.
         // - Hex byte data.
         // - List. The element of list can be numbers, strings, hex byte data and list.
+        // - Repetition, e.g. `[0_i32; 256]`.
+        // - Included files, e.g. `include_bytes("assets/table.bin")`, `include_str("message.txt")`.
 
         if let Some(token) = self.peek_token(0) {
             let value = match token {
@@ -1118,6 +1641,7 @@ impl Parser<'_> {
                         NumberToken::I64(v) => DataValue::I64(*v),
                         NumberToken::F32(v) => DataValue::F32(*v),
                         NumberToken::F64(v) => DataValue::F64(*v),
+                        NumberToken::Char(v) => DataValue::Char(*v),
                     };
                     self.next_token(); // consume number token
                     value_num
@@ -1132,6 +1656,19 @@ impl Parser<'_> {
                     self.next_token(); // consume hex byte data token
                     value_byte_data
                 }
+                Token::Name(name)
+                    if (name == "include_bytes" || name == "include_str")
+                        && self.expect_token(1, &Token::LeftParen) =>
+                {
+                    let file_type = if name == "include_bytes" {
+                        IncludeFileType::Binary
+                    } else {
+                        IncludeFileType::Text
+                    };
+
+                    let include_file = self.continue_parse_include_file(file_type)?;
+                    DataValue::IncludeFile(include_file)
+                }
                 Token::LeftBracket => {
                     // list
                     self.next_token(); // consume '['
@@ -1145,6 +1682,37 @@ impl Parser<'_> {
                         }
 
                         let value_element = self.continue_parse_data_value()?;
+
+                        if values.is_empty()
+                            && self
+                                .expect_token_ignore_newline(0, &Token::Semicolon)
+                                .is_some()
+                        {
+                            // repetition, e.g. `[0_i32; 256]`
+                            self.consume_new_line_if_exist();
+                            self.consume_semicolon()?; // consume ';'
+                            self.consume_new_line_if_exist();
+
+                            let count = self.consume_number_i32()?; // consume i32
+
+                            // the count is an unsigned number in the token, a negative
+                            // literal (e.g. `-1`) becomes a number greater than `i32::MAX`.
+                            if count == 0 || count > i32::MAX as u32 {
+                                return Err(ParserError::MessageWithLocation(
+                                    ParserErrorKind::InvalidRepetitionCount,
+                                    "The count of repetition must be a positive number.".to_owned(),
+                                    self.last_range.get_position_by_range_start(),
+                                ));
+                            }
+
+                            let count = count as usize;
+                            self.consume_new_line_if_exist();
+
+                            self.consume_right_bracket()?; // consume ']'
+
+                            return Ok(DataValue::Repetition(Box::new(value_element), count));
+                        }
+
                         values.push(value_element);
 
                         let found_sep = self.consume_new_line_or_comma_if_exist();
@@ -1159,6 +1727,7 @@ impl Parser<'_> {
                 }
                 _ => {
                     return Err(ParserError::MessageWithLocation(
+                        ParserErrorKind::UnexpectedToken,
                         "Expect a data value.".to_owned(),
                         self.peek_range(0).unwrap().get_position_by_range_start(),
                     ))
@@ -1168,20 +1737,115 @@ impl Parser<'_> {
             Ok(value)
         } else {
             Err(ParserError::UnexpectedEndOfDocument(
+                ParserErrorKind::UnexpectedToken,
                 "Expect a data value.".to_owned(),
             ))
         }
     }
 
-    fn parse_function_node(&mut self, public: bool) -> Result<FunctionNode, ParserError> {
+    fn continue_parse_include_file(
+        &mut self,
+        file_type: IncludeFileType,
+    ) -> Result<IncludeFile, ParserError> {
+        // include_bytes("path", offset=N, length=N) ?  //
+        // ^                                         ^__// to here
+        // |--------------------------------------------// current token, validated
+        //
+        // also:
+        // - include_str(...)
+
+        self.next_token(); // consume 'include_bytes' or 'include_str'
+        self.consume_left_paren()?; // consume '('
+        self.consume_new_line_if_exist();
+
+        let file_path = self.consume_string()?; // consume string
+
+        let mut offset: Option<usize> = None;
+        let mut length: Option<usize> = None;
+
+        while self.consume_new_line_or_comma_if_exist() {
+            if self.expect_token(0, &Token::RightParen) {
+                break;
+            }
+
+            let name = self.consume_name()?; // consume name
+            let name_position = self.last_range.get_position_by_range_start();
+            self.consume_new_line_if_exist();
+
+            self.consume_equal()?; // consume '='
+            self.consume_new_line_if_exist();
+
+            let value = self.consume_number_i32()? as usize; // consume i32
+
+            match name.as_str() {
+                "offset" => offset = Some(value),
+                "length" => length = Some(value),
+                _ => {
+                    return Err(ParserError::MessageWithLocation(
+                        ParserErrorKind::InvalidIncludeFileArgument,
+                        format!(
+                            "Unknown argument \"{}\" for including file, expect \"offset\" or \"length\".",
+                            name
+                        ),
+                        name_position,
+                    ))
+                }
+            }
+        }
+
+        self.consume_right_paren()?; // consume ')'
+
+        Ok(IncludeFile {
+            file_type,
+            file_path,
+            offset,
+            length,
+        })
+    }
+
+    fn continue_parse_fill_data_value(
+        &mut self,
+        data_type: &DeclareDataType,
+    ) -> Result<DataValue, ParserError> {
+        // fill(value) ?  //
+        // ^           ^__// to here
+        // |--------------// current token, validated
+
+        if !matches!(data_type, DeclareDataType::FixedBytes(_, _)) {
+            return Err(ParserError::MessageWithLocation(
+                ParserErrorKind::InvalidFillValue,
+                "The fill value is only allowed for the fixed-length byte array, e.g. \"byte[1024] = fill(0_i8)\".".to_owned(),
+                self.peek_range(0).unwrap().get_position_by_range_start(),
+            ));
+        }
+
+        self.next_token(); // consume 'fill'
+        self.consume_left_paren()?; // consume '('
+        self.consume_new_line_if_exist();
+
+        let value = self.continue_parse_data_value()?;
+        self.consume_new_line_if_exist();
+
+        self.consume_right_paren()?; // consume ')'
+
+        Ok(DataValue::Fill(Box::new(value)))
+    }
+
+    fn parse_function_node(
+        &mut self,
+        public: bool,
+        document: Option<String>,
+    ) -> Result<FunctionNode, ParserError> {
         // fn (...) [-> ...] [...] exp ?  //
         // ^                           ^__// to here
         // |------------------------------// current token, validated
 
         self.next_token(); // consume 'fn'
+        let keyword_location = self.last_range;
         self.consume_new_line_if_exist();
 
         let name = self.consume_name()?;
+        let location = self.last_range;
         self.consume_new_line_if_exist();
 
         let params = self.continue_parse_function_params()?;
@@ -1206,16 +1870,20 @@ impl Parser<'_> {
         self.consume_new_line_if_exist();
 
         let body = self.parse_expression_node()?;
+        let statement_location = Location::from_range_pair(&keyword_location, &self.last_range);
 
         self.consume_new_line_if_exist();
 
         let node = FunctionNode {
+            document,
             public,
             name,
             params,
             results,
             locals,
             body: Box::new(body),
+            location,
+            statement_location,
         };
 
         Ok(node)
@@ -1236,6 +1904,7 @@ impl Parser<'_> {
             }
 
             let name = self.consume_name()?;
+            let location = self.last_range;
             self.consume_new_line_if_exist();
 
             self.consume_colon()?;
@@ -1243,7 +1912,11 @@ impl Parser<'_> {
 
             let data_type = self.continue_parse_function_data_type()?;
 
-            params.push(NamedParameter { name, data_type });
+            params.push(NamedParameter {
+                name,
+                data_type,
+                location,
+            });
 
             let found_sep = self.consume_new_line_or_comma_if_exist();
             if !found_sep {
@@ -1313,6 +1986,7 @@ impl Parser<'_> {
             }
 
             let name = self.consume_name()?;
+            let location = self.last_range;
             self.consume_new_line_if_exist();
 
             self.consume_colon()?;
@@ -1320,7 +1994,11 @@ impl Parser<'_> {
 
             let data_type = self.continue_parse_fixed_declare_data_type()?;
 
-            local_variables.push(LocalVariable { name, data_type });
+            local_variables.push(LocalVariable {
+                name,
+                data_type,
+                location,
+            });
 
             let found_sep = self.consume_new_line_or_comma_if_exist();
             if !found_sep {
@@ -1350,6 +2028,7 @@ impl Parser<'_> {
             }
 
             let name = self.consume_name()?;
+            let location = self.last_range;
             self.consume_new_line_if_exist();
 
             self.consume_colon()?;
@@ -1367,6 +2046,7 @@ impl Parser<'_> {
                 name,
                 data_type,
                 value: Box::new(value),
+                location,
             });
 
             let found_sep = self.consume_new_line_or_comma_if_exist();
@@ -1435,6 +2115,7 @@ impl Parser<'_> {
                 }
                 _ => {
                     return Err(ParserError::MessageWithLocation(
+                        ParserErrorKind::UnexpectedToken,
                         "Expect an expression.".to_owned(),
                         self.peek_range(0).unwrap().get_position_by_range_start(),
                     ));
@@ -1444,6 +2125,7 @@ impl Parser<'_> {
             Ok(node)
         } else {
             Err(ParserError::UnexpectedEndOfDocument(
+                ParserErrorKind::UnexpectedToken,
                 "Expect an expression.".to_owned(),
             ))
         }
@@ -1456,6 +2138,7 @@ impl Parser<'_> {
         // |------------------// current token, validated
 
         let name = self.consume_name()?;
+        let location = self.last_range;
         self.consume_new_line_if_exist();
 
         let (positional_args, named_args) = self.continue_parse_calling_arguments()?;
@@ -1464,6 +2147,7 @@ impl Parser<'_> {
             name,
             positional_args,
             named_args,
+            location,
         };
 
         Ok(node)
@@ -1524,6 +2208,7 @@ impl Parser<'_> {
         //
         // - Numbers: includes decimal, hexadecimal, binary, float-point, hex float-point.
         // - Identifiers: name of functions or data.
+        // - Data literals: strings, hex byte data and list.
         // - Expression: an expression.
         //
         // The identifier can be is the name of function or data, name path is not allowed.
@@ -1538,10 +2223,17 @@ impl Parser<'_> {
                         NumberToken::I64(v) => ArgumentValue::LiteralNumber(LiteralNumber::I64(*v)),
                         NumberToken::F32(v) => ArgumentValue::LiteralNumber(LiteralNumber::F32(*v)),
                         NumberToken::F64(v) => ArgumentValue::LiteralNumber(LiteralNumber::F64(*v)),
+                        NumberToken::Char(v) => {
+                            ArgumentValue::LiteralNumber(LiteralNumber::Char(*v))
+                        }
                     };
                     self.next_token(); // consume number token
                     value_num
                 }
+                Token::String(_) | Token::HexByteData(_) | Token::LeftBracket => {
+                    let data_value = self.continue_parse_data_value()?;
+                    ArgumentValue::LiteralData(data_value)
+                }
                 Token::Name(name)
                     if self
                         .expect_token_ignore_newline(1, &Token::LeftParen)
@@ -1549,7 +2241,7 @@ impl Parser<'_> {
                 {
                     let identifier = name.to_owned();
                     self.next_token(); // consume name
-                    ArgumentValue::Identifier(identifier)
+                    ArgumentValue::Identifier(identifier, self.last_range)
                 }
                 // Token::FullName(full_name) => {
                 //     let identifier = full_name.to_owned();
@@ -1565,6 +2257,7 @@ impl Parser<'_> {
             Ok(value)
         } else {
             Err(ParserError::UnexpectedEndOfDocument(
+                ParserErrorKind::UnexpectedToken,
                 "Expect a value for argument.".to_owned(),
             ))
         }
@@ -1795,6 +2488,50 @@ impl Parser<'_> {
     }
 }
 
+// the section of `import data ...` is read-write if the data type presents,
+// otherwise both of them are resolved from the image of the dependency module.
+fn get_import_data_section_type(
+    data_section_type: Option<DataSectionType>,
+    data_type: Option<MemoryDataType>,
+) -> Option<DataSectionType> {
+    match (data_section_type, data_type) {
+        (None, Some(_)) => Some(DataSectionType::ReadWrite),
+        _ => data_section_type,
+    }
+}
+
+// the location of the identifier (i.e. the last segment) of the full name, e.g.
+// the "copy" of "std::memory::copy".
+fn get_identifier_location(full_name: &str, full_name_location: &Location) -> Location {
+    let identifier = full_name
+        .rsplit_once(NAME_PATH_SEPARATOR)
+        .map(|(_, identifier)| identifier)
+        .unwrap_or(full_name);
+
+    let identifier_length = identifier.chars().count();
+    let offset = full_name.chars().count() - identifier_length;
+
+    Location::new_range(
+        full_name_location.index + offset,
+        full_name_location.line,
+        full_name_location.column + offset,
+        identifier_length,
+    )
+}
+
+// the space following `///` or `//!` is not part of the document.
+fn trim_document_line(line: &str) -> String {
+    line.strip_prefix(' ').unwrap_or(line).to_owned()
+}
+
+fn join_document_lines(lines: Vec<String>) -> Option<String> {
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join("\n"))
+    }
+}
+
 pub fn parse_from_str(source_code: &str) -> Result<ModuleNode, ParserError> {
     let tokens = lex_from_str(source_code)?;
     let clean_tokens = clean(tokens);
@@ -1809,7 +2546,14 @@ pub fn parse_from_str(source_code: &str) -> Result<ModuleNode, ParserError> {
 mod tests {
     use pretty_assertions::assert_eq;
 
-    use anc_assembly::printer::print_to_string;
+    use anc_assembly::{
+        ast::{ArgumentValue, ExpressionNode, ExternalNode, ImportNode},
+        location::Location,
+        printer::print_to_string,
+    };
+    use anc_isa::{DataSectionType, MemoryDataType};
+
+    use crate::ParserError;
 
     use super::parse_from_str;
 
@@ -1868,6 +2612,23 @@ mod tests {
             "import fn foo::bar() -> ()\n\n"
         );
 
+        // test omit signature
+        assert_eq!(
+            format("import fn std::memory::copy"),
+            "import fn std::memory::copy\n\n"
+        );
+
+        assert_eq!(
+            format(
+                "\
+import fn std::memory::copy as mem_copy from std
+import fn std::memory::move"
+            ),
+            "\
+import fn std::memory::copy as mem_copy from std
+import fn std::memory::move\n\n"
+        );
+
         // test with params
         assert_eq!(
             format("import fn foo::add(i32,i32)->i32"),
@@ -1930,6 +2691,43 @@ mymod"
             ),
             "import fn foo::add(i32, i32) -> i32 as add_i32 from mymod\n\n"
         );
+
+        // test grouped items
+        assert_eq!(
+            format("import fn std::memory::{copy, fill as memset}"),
+            "\
+import fn std::memory::copy
+import fn std::memory::fill as memset\n\n"
+        );
+
+        // the group can not be empty
+        assert!(parse_from_str("import fn std::memory::{}").is_err());
+
+        assert_eq!(
+            format(
+                "\
+import fn foo::{
+    add(i32,i32)->i32 as add_i32
+    bar()
+    baz
+} from mymod"
+            ),
+            "\
+import fn foo::add(i32, i32) -> i32 as add_i32 from mymod
+import fn foo::bar() -> () from mymod
+import fn foo::baz from mymod\n\n"
+        );
+
+        // test glob
+        assert_eq!(
+            format("import fn std::memory::*"),
+            "import fn std::memory::*\n\n"
+        );
+
+        assert_eq!(
+            format("import fn foo::bar::* from mymod"),
+            "import fn foo::bar::* from mymod\n\n"
+        );
     }
 
     #[test]
@@ -1961,6 +2759,35 @@ mymod"
             "import data foo::count type i32 from mymod\n\n"
         );
 
+        // test omit data type
+        assert_eq!(format("import data foo::bar"), "import data foo::bar\n\n");
+
+        assert_eq!(
+            format("import readonly data foo::msg as message from mymod"),
+            "import readonly data foo::msg as message from mymod\n\n"
+        );
+
+        // the section is resolved with the data type if both are omitted,
+        // and the declared section is kept.
+        let get_section_and_type =
+            |source_code: &str| match &parse_from_str(source_code).unwrap().imports[0] {
+                ImportNode::Data(import_data_node) => (
+                    import_data_node.data_section_type,
+                    import_data_node.data_type,
+                ),
+                _ => unreachable!(),
+            };
+
+        assert_eq!(get_section_and_type("import data foo::bar"), (None, None));
+        assert_eq!(
+            get_section_and_type("import uninit data foo::bar"),
+            (Some(DataSectionType::Uninit), None)
+        );
+        assert_eq!(
+            get_section_and_type("import data foo::bar type i32"),
+            (Some(DataSectionType::ReadWrite), Some(MemoryDataType::I32))
+        );
+
         // test multiple items
         assert_eq!(
             format(
@@ -1992,6 +2819,134 @@ mymod"
             ),
             "import readonly data foo::bar type byte[] as baz from mymod\n\n"
         );
+
+        // test grouped items
+        assert_eq!(
+            format("import readonly data foo::{PI type f32, msg as message} from mymod"),
+            "\
+import readonly data foo::PI type f32 from mymod
+import readonly data foo::msg as message from mymod\n\n"
+        );
+
+        // the group can not be empty
+        assert!(parse_from_str("import data foo::{}").is_err());
+
+        // test glob
+        assert_eq!(format("import data foo::*"), "import data foo::*\n\n");
+
+        // the data section can not be specified for glob import
+        assert!(matches!(
+            parse_from_str("import readonly data foo::*"),
+            Err(ParserError::MessageWithLocation(_, _, _))
+        ));
+    }
+
+    #[test]
+    fn test_parse_reexport_statement() {
+        assert_eq!(
+            format("pub import fn foo::add(i32, i32) -> i32 as add_i32"),
+            "pub import fn foo::add(i32, i32) -> i32 as add_i32\n\n"
+        );
+
+        assert_eq!(
+            format("pub import data foo::count type i32 from mymod"),
+            "pub import data foo::count type i32 from mymod\n\n"
+        );
+
+        // test grouped items
+        assert_eq!(
+            format("pub import fn foo::{inc, dec}"),
+            "\
+pub import fn foo::inc
+pub import fn foo::dec\n\n"
+        );
+
+        // test glob
+        assert_eq!(
+            format("pub import fn self::utils::*"),
+            "pub import fn self::utils::*\n\n"
+        );
+
+        // the import module can not be re-exported
+        assert!(matches!(
+            parse_from_str(r#"pub import module mymod from "modules/mymod""#),
+            Err(ParserError::MessageWithLocation(_, _, _))
+        ));
+    }
+
+    #[test]
+    fn test_parse_import_module_statement() {
+        assert_eq!(
+            format(r#"import module http_client version "1.2.0""#),
+            "import module http_client version \"1.2.0\"\n\n"
+        );
+
+        assert_eq!(
+            format(r#"import module mymod from "modules/mymod""#),
+            "import module mymod from \"modules/mymod\"\n\n"
+        );
+
+        // test line breaks
+        assert_eq!(
+            format(
+                r#"
+import
+module
+http_client
+version
+"1.2.0""#
+            ),
+            "import module http_client version \"1.2.0\"\n\n"
+        );
+
+        assert_eq!(
+            format(r#"import module http_client remote "https://github.com/hemashushu/http_client.git" "v1.0.0""#),
+            "import module http_client remote \"https://github.com/hemashushu/http_client.git\" \"v1.0.0\"\n\n"
+        );
+
+        assert_eq!(
+            format("import module std runtime"),
+            "import module std runtime\n\n"
+        );
+
+        // 'system' is only available for external library
+        assert!(parse_from_str(r#"import module mymod system "mymod""#).is_err());
+
+        // missing source
+        assert!(parse_from_str("import module mymod").is_err());
+    }
+
+    #[test]
+    fn test_parse_external_library_statement() {
+        assert_eq!(
+            format(r#"external library libfoo from "lib/libfoo.so""#),
+            "external library libfoo from \"lib/libfoo.so\"\n\n"
+        );
+
+        assert_eq!(
+            format(r#"external library libc system "libc.so.6""#),
+            "external library libc system \"libc.so.6\"\n\n"
+        );
+
+        assert_eq!(
+            format(r#"external library libbar version "2.0.1""#),
+            "external library libbar version \"2.0.1\"\n\n"
+        );
+
+        // 'runtime' is only available for import module
+        assert!(parse_from_str("external library libbar runtime").is_err());
+
+        // test with other external items
+        assert_eq!(
+            format(
+                r#"
+external library libfoo from "lib/libfoo.so"
+external fn libfoo::add(i32,i32)->i32"#
+            ),
+            "\
+external library libfoo from \"lib/libfoo.so\"
+external fn libfoo::add(i32, i32) -> i32\n\n"
+        );
     }
 
     #[test]
@@ -2119,6 +3074,14 @@ baz"
         // data type i64
         assert_eq!(format("data bar:i64=17_i64"), "data bar:i64 = 17_i64\n\n");
 
+        // data type i16 and i8
+        assert_eq!(format("data flag:i8=1"), "data flag:i8 = 1\n\n");
+        assert_eq!(
+            format("readonly data count:i16=19_i16"),
+            "readonly data count:i16 = 19_i16\n\n"
+        );
+        assert_eq!(format("uninit data port:i16"), "uninit data port:i16\n\n");
+
         // other data types and values
         assert_eq!(
             format(
@@ -2154,6 +3117,59 @@ pub data foo5:byte[] = [
 "
         );
 
+        // repetition
+        assert_eq!(
+            format("data foo:byte[] = [0_i32; 256]"),
+            "data foo:byte[] = [0; 256]\n\n"
+        );
+
+        // nested repetition
+        assert_eq!(
+            format("data foo:byte[] = [[0xff_i8; 4]\n;\n16]"),
+            "data foo:byte[] = [[255_i8; 4]; 16]\n\n"
+        );
+
+        // repetition of list
+        assert_eq!(
+            format("data foo:byte[align=4] = [[11_i32, 13_i32]; 8]"),
+            "\
+data foo:byte[align=4] = [[
+    11
+    13
+]; 8]
+
+"
+        );
+
+        // the count of repetition must be positive
+        assert!(parse_from_str("data foo:byte[] = [0_i32; 0]").is_err());
+        assert!(parse_from_str("data foo:byte[] = [0_i32; -1]").is_err());
+
+        // fill
+        assert_eq!(
+            format("data foo:byte[4096] = fill(0xcc_i8)"),
+            "data foo:byte[4096] = fill(204_i8)\n\n"
+        );
+
+        // fill is only allowed for the fixed-length byte array
+        assert!(parse_from_str("data foo:byte[] = fill(0_i8)").is_err());
+
+        // include files
+        assert_eq!(
+            format(r#"readonly data foo:byte[] = include_bytes("assets/table.bin")"#),
+            "readonly data foo:byte[] = include_bytes(\"assets/table.bin\")\n\n"
+        );
+
+        assert_eq!(
+            format(
+                r#"readonly data foo:byte[align=4] = include_str("message.txt", offset=16
+length=256)"#
+            ),
+            "readonly data foo:byte[align=4] = include_str(\"message.txt\", offset=16, length=256)\n\n"
+        );
+
+        assert!(parse_from_str(r#"data foo:byte[] = include_bytes("a.bin", size=4)"#).is_err());
+
         // test line breaks
         assert_eq!(
             format(
@@ -2249,6 +3265,16 @@ fn foo() -> ()
 "
         );
 
+        // with i16 and i8 local variables
+        assert_eq!(
+            format("fn foo() [a:i16,b:i8] nop()"),
+            "\
+fn foo() -> ()
+    [a:i16, b:i8]
+    nop()
+"
+        );
+
         // with instruction expressions
         assert_eq!(
             format(
@@ -2302,6 +3328,170 @@ pub fn foo(left:i32, right:i32) -> i32
         );
     }
 
+    #[test]
+    fn test_parse_instruction_with_data_literal_arguments() {
+        // string
+        assert_eq!(
+            format(r#"fn foo() host_addr_data("Hello, World!\n")"#),
+            "\
+fn foo() -> ()
+    host_addr_data(\"Hello, World!\n\")
+"
+        );
+
+        // hex byte data
+        assert_eq!(
+            format(r#"fn foo() data_load_i8_u(h"11 13 17 19", offset=2)"#),
+            "\
+fn foo() -> ()
+    data_load_i8_u(h\"11 13 17 19\", offset=2)
+"
+        );
+
+        // list
+        assert_eq!(
+            format(
+                "\
+fn foo() data_load_i32_u([
+    11
+    13_i32,
+    [17_i8, 19_i8]
+], offset=4)"
+            ),
+            "\
+fn foo() -> ()
+    data_load_i32_u([11, 13, [17_i8, 19_i8]], offset=4)
+"
+        );
+    }
+
+    #[test]
+    fn test_parse_document() {
+        assert_eq!(
+            format(
+                "\
+//! The utilities.
+//!
+//!More details.
+
+/// Copy memory.
+import fn std::memory::copy(i64, i64, i64)
+
+/// The external function.
+external fn libfoo::bar(i32) -> i32
+
+/// The counter.
+///   Starts from 0.
+pub data count:i32 = 0
+
+// ordinary comment
+/// Adds two numbers.
+pub fn add(left:i32, right:i32) -> i32
+    nop()"
+            ),
+            "\
+//! The utilities.
+//!
+//! More details.
+
+/// Copy memory.
+import fn std::memory::copy(i64, i64, i64) -> ()
+
+/// The external function.
+external fn libfoo::bar(i32) -> i32
+
+/// The counter.
+///   Starts from 0.
+pub data count:i32 = 0
+
+/// Adds two numbers.
+pub fn add(left:i32, right:i32) -> i32
+    nop()
+"
+        );
+
+        // the document of a grouped import applies to all items
+        assert_eq!(
+            format("/// Memory functions.\nimport fn std::memory::{copy, fill}"),
+            "\
+/// Memory functions.
+import fn std::memory::copy
+/// Memory functions.
+import fn std::memory::fill\n\n"
+        );
+
+        // the documents which are not followed by a statement are ordinary comments
+        assert_eq!(
+            format(
+                "\
+fn foo(
+    /// the left operand
+    left:i32) -> i32
+{
+    /// the first operand
+    local_load_i32_s(left)
+    add_i32(
+        /// the second operand
+        imm_i32(11))
+    /// the end
+}
+/// dangling"
+            ),
+            "\
+fn foo(left:i32) -> i32
+    {
+        local_load_i32_s(left)
+        add_i32(
+            imm_i32(11))
+    }
+"
+        );
+
+        // the module document after the statements is an ordinary comment
+        assert_eq!(
+            format("fn foo() nop()\n//! module"),
+            "fn foo() -> ()\n    nop()\n"
+        );
+
+        // err: the module document follows the document of a statement
+        assert!(matches!(
+            parse_from_str("/// foo\n//! module\nfn foo() nop()"),
+            Err(ParserError::MessageWithLocation(_, _, _))
+        ));
+    }
+
+    #[test]
+    fn test_parse_char_literal() {
+        // instruction argument
+        assert_eq!(
+            format("fn foo() eq_i32(local_load_i32_u(ch), imm_i32('\\n'))"),
+            "\
+fn foo() -> ()
+    eq_i32(
+        local_load_i32_u(ch),
+        imm_i32('\\n'))
+"
+        );
+
+        // data value
+        assert_eq!(format("data foo:i32 = 'A'"), "data foo:i32 = 'A'\n\n");
+        assert_eq!(
+            format("data foo:byte[] = ['文', '\\'', '\\u{1b}']"),
+            "\
+data foo:byte[] = [
+    '文'
+    '\\''
+    '\\u{1b}'
+]\n\n"
+        );
+
+        // the minus sign can not be applied to chars
+        assert!(matches!(
+            parse_from_str("data foo:i32 = -'A'"),
+            Err(ParserError::MessageWithLocation(_, _, _))
+        ));
+    }
+
     #[test]
     fn test_parse_expression_group() {
         assert_eq!(
@@ -2752,4 +3942,71 @@ fn foo() -> ()
 "
         );
     }
+
+    #[test]
+    fn test_parse_locations() {
+        let source = "\
+import fn std::memory::copy(i64, i64, i64)
+import data foo::{count type i32, msg as message}
+external fn libc::getuid() -> i32 as uid
+pub fn add(left:i32) -> i32 [sum:i32]
+    local_load_i32_s(left)
+";
+        let module_node = parse_from_str(source).unwrap();
+
+        let get_text = |location: &Location| -> String {
+            source
+                .chars()
+                .skip(location.index)
+                .take(location.length)
+                .collect()
+        };
+
+        // the identifier of the imported item is the last segment of
+        // the full name or the alias name.
+        let import_locations: Vec<Location> = module_node
+            .imports
+            .iter()
+            .map(|import_node| match import_node {
+                ImportNode::Function(node) => node.location,
+                ImportNode::Data(node) => node.location,
+                ImportNode::Module(node) => node.location,
+                ImportNode::Glob(node) => node.location,
+            })
+            .collect();
+        assert_eq!(
+            import_locations
+                .iter()
+                .map(get_text)
+                .collect::<Vec<String>>(),
+            vec!["copy", "count", "message"]
+        );
+        assert_eq!(import_locations[0], Location::new_range(23, 0, 23, 4));
+
+        let ExternalNode::Function(external_function_node) = &module_node.externals[0] else {
+            panic!("expect an external function");
+        };
+        assert_eq!(get_text(&external_function_node.location), "uid");
+
+        let function_node = &module_node.functions[0];
+        assert_eq!(function_node.location, Location::new_range(141, 3, 7, 3));
+        assert_eq!(get_text(&function_node.params[0].location), "left");
+        assert_eq!(get_text(&function_node.locals[0].location), "sum");
+        assert_eq!(
+            get_text(&function_node.statement_location),
+            "fn add(left:i32) -> i32 [sum:i32]\n    local_load_i32_s(left)"
+        );
+
+        let ExpressionNode::Instruction(instruction_node) = function_node.body.as_ref() else {
+            panic!("expect an instruction");
+        };
+        assert_eq!(get_text(&instruction_node.location), "local_load_i32_s");
+
+        let ArgumentValue::Identifier(identifier, location) = &instruction_node.positional_args[0]
+        else {
+            panic!("expect an identifier");
+        };
+        assert_eq!(identifier, "left");
+        assert_eq!(get_text(location), "left");
+    }
 }
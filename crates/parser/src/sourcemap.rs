@@ -0,0 +1,365 @@
+// Copyright (c) 2024 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions,
+// more details in file LICENSE, LICENSE.additional and CONTRIBUTING.
+
+// the conversions between the units of source text.
+//
+// the `index` and `column` of `Location` are counted in Unicode chars
+// (by `CharsWithPositionIter`), while the other consumers use different units:
+//
+// - `&str` slicing uses byte offsets (UTF-8 code units).
+// - the editors (e.g. LSP) use UTF-16 code units by default.
+// - the terminal renderers use display columns, i.e. a tab is expanded to the next
+//   tab stop, and an East Asian wide character (e.g. '文') occupies two columns.
+//
+// e.g. the char 'b' in "文\tb":
+//
+// | unit           | value |
+// |----------------|-------|
+// | char index     | 2     |
+// | byte offset    | 4     |
+// | UTF-16 offset  | 2     |
+// | display column | 4     | (tab width 4)
+
+use std::ops::Range;
+
+use unicode_width::UnicodeWidthChar;
+
+use crate::location::Location;
+
+pub const DEFAULT_TAB_WIDTH: usize = 4;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DisplayOptions {
+    // the distance between two tab stops.
+    pub tab_width: usize,
+
+    // treat the characters of ambiguous width (e.g. '°', '×' and the Greek letters)
+    // as wide, it should be enabled when the terminal uses a CJK locale.
+    pub ambiguous_as_wide: bool,
+}
+
+impl Default for DisplayOptions {
+    fn default() -> Self {
+        Self {
+            tab_width: DEFAULT_TAB_WIDTH,
+            ambiguous_as_wide: false,
+        }
+    }
+}
+
+/// get the number of columns of a char on the terminal.
+///
+/// `column` is the display column where the char starts, which is used for
+/// expanding the tab to the next tab stop.
+pub fn get_char_display_width(c: char, column: usize, options: &DisplayOptions) -> usize {
+    match c {
+        '\t' => {
+            let tab_width = options.tab_width.max(1);
+            tab_width - column % tab_width
+        }
+        // the line breaks are printed as a space in the snippets.
+        '\n' | '\r' => 1,
+        _ => {
+            let width = if options.ambiguous_as_wide {
+                c.width_cjk()
+            } else {
+                c.width()
+            };
+            width.unwrap_or(0)
+        }
+    }
+}
+
+/// get the number of columns of a string on the terminal,
+/// the string is assumed to start at column 0.
+pub fn get_display_width(text: &str, options: &DisplayOptions) -> usize {
+    text.chars().fold(0, |column, c| {
+        column + get_char_display_width(c, column, options)
+    })
+}
+
+pub struct SourceMap {
+    text: String,
+
+    // the byte offset and UTF-16 offset of each char, and
+    // the offsets of the end of text are appended, i.e. the
+    // length of these lists is `the number of chars + 1`.
+    byte_offsets: Vec<usize>,
+    utf16_offsets: Vec<usize>,
+
+    // the char index of the first char of each line.
+    line_starts: Vec<usize>,
+}
+
+impl SourceMap {
+    pub fn new(text: &str) -> Self {
+        let mut byte_offsets = vec![];
+        let mut utf16_offsets = vec![];
+        let mut line_starts = vec![0];
+
+        let mut utf16_offset = 0;
+        for (char_index, (byte_offset, c)) in text.char_indices().enumerate() {
+            byte_offsets.push(byte_offset);
+            utf16_offsets.push(utf16_offset);
+            utf16_offset += c.len_utf16();
+
+            if c == '\n' {
+                line_starts.push(char_index + 1);
+            }
+        }
+
+        byte_offsets.push(text.len());
+        utf16_offsets.push(utf16_offset);
+
+        Self {
+            text: text.to_owned(),
+            byte_offsets,
+            utf16_offsets,
+            line_starts,
+        }
+    }
+
+    /// the total number of chars.
+    pub fn len(&self) -> usize {
+        self.byte_offsets.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get_line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// get the text of the specified range of chars.
+    ///
+    /// the index which exceeds the end of text is treated as the end of text.
+    pub fn get_text(&self, char_index_start: usize, char_index_end: usize) -> &str {
+        let end = self.get_byte_offset(char_index_end);
+        let start = self.get_byte_offset(char_index_start).min(end);
+        &self.text[start..end]
+    }
+
+    /// get the text of the specified line, excluding the line break.
+    pub fn get_line_text(&self, line: usize) -> Option<&str> {
+        let start = *self.line_starts.get(line)?;
+        let end = self.get_line_end(line);
+        Some(self.get_text(start, end).trim_end_matches('\r'))
+    }
+
+    // the char index of the '\n' of the line, or the end of text for the last line.
+    fn get_line_end(&self, line: usize) -> usize {
+        match self.line_starts.get(line + 1) {
+            Some(next_line_start) => next_line_start - 1,
+            None => self.len(),
+        }
+    }
+
+    // the char index which exceeds the end of text is treated as the end of text.
+    fn clamp_char_index(&self, char_index: usize) -> usize {
+        char_index.min(self.len())
+    }
+
+    pub fn get_byte_offset(&self, char_index: usize) -> usize {
+        self.byte_offsets[self.clamp_char_index(char_index)]
+    }
+
+    /// returns `None` if the byte offset is not on a char boundary or
+    /// exceeds the end of text.
+    pub fn get_char_index_by_byte_offset(&self, byte_offset: usize) -> Option<usize> {
+        self.byte_offsets.binary_search(&byte_offset).ok()
+    }
+
+    pub fn get_utf16_offset(&self, char_index: usize) -> usize {
+        self.utf16_offsets[self.clamp_char_index(char_index)]
+    }
+
+    /// the offset which is in the middle of a surrogate pair is treated as
+    /// the start of the pair, and the offset which exceeds the end of text
+    /// is treated as the end of text.
+    pub fn get_char_index_by_utf16_offset(&self, utf16_offset: usize) -> usize {
+        self.utf16_offsets
+            .partition_point(|offset| *offset <= utf16_offset)
+            - 1
+    }
+
+    /// get the line index of the char.
+    pub fn get_line(&self, char_index: usize) -> usize {
+        let char_index = self.clamp_char_index(char_index);
+        self.line_starts
+            .partition_point(|start| *start <= char_index)
+            - 1
+    }
+
+    /// get the line index and the column (in chars) of the char,
+    /// i.e. the same as the `line` and `column` of `Location`.
+    pub fn get_line_and_column(&self, char_index: usize) -> (usize, usize) {
+        let char_index = self.clamp_char_index(char_index);
+        let line = self.get_line(char_index);
+        (line, char_index - self.line_starts[line])
+    }
+
+    /// get the line index and the column (in UTF-16 code units) of the char.
+    pub fn get_line_and_utf16_column(&self, char_index: usize) -> (usize, usize) {
+        let char_index = self.clamp_char_index(char_index);
+        let line = self.get_line(char_index);
+        let column = self.utf16_offsets[char_index] - self.utf16_offsets[self.line_starts[line]];
+        (line, column)
+    }
+
+    /// get the line index and the display column of the char.
+    pub fn get_line_and_display_column(
+        &self,
+        char_index: usize,
+        options: &DisplayOptions,
+    ) -> (usize, usize) {
+        let char_index = self.clamp_char_index(char_index);
+        let line = self.get_line(char_index);
+        let column = get_display_width(self.get_text(self.line_starts[line], char_index), options);
+        (line, column)
+    }
+
+    /// convert the line index and the column (in UTF-16 code units) to the char index.
+    ///
+    /// the column which exceeds the end of line is treated as the end of line,
+    /// and the line which exceeds the last line is treated as the end of text.
+    pub fn get_char_index_by_line_and_utf16_column(&self, line: usize, column: usize) -> usize {
+        let Some(line_start) = self.line_starts.get(line) else {
+            return self.len();
+        };
+
+        let utf16_offset = self.utf16_offsets[*line_start] + column;
+        self.get_char_index_by_utf16_offset(utf16_offset)
+            .min(self.get_line_end(line))
+    }
+
+    /// convert the char range of `Location` to the byte range, which
+    /// can be used for slicing the source text.
+    pub fn get_byte_range(&self, location: &Location) -> Range<usize> {
+        self.get_byte_offset(location.index)..self.get_byte_offset(location.index + location.length)
+    }
+
+    /// convert the char range of `Location` to the UTF-16 range.
+    pub fn get_utf16_range(&self, location: &Location) -> Range<usize> {
+        self.get_utf16_offset(location.index)
+            ..self.get_utf16_offset(location.index + location.length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::location::Location;
+
+    use super::{get_char_display_width, get_display_width, DisplayOptions, SourceMap};
+
+    #[test]
+    fn test_convert_offset() {
+        // char:        a  文 b  \n \t 😀 c  (end)
+        // char index:  0  1  2  3  4  5  6  7
+        // byte offset: 0  1  4  5  6  7  11 12
+        // UTF-16:      0  1  2  3  4  5  7  8
+        let source_map = SourceMap::new("a文b\n\t😀c");
+
+        assert_eq!(source_map.len(), 7);
+        assert_eq!(source_map.get_line_count(), 2);
+
+        // byte offset
+        assert_eq!(source_map.get_byte_offset(2), 4);
+        assert_eq!(source_map.get_byte_offset(6), 11);
+        assert_eq!(source_map.get_byte_offset(7), 12);
+        assert_eq!(source_map.get_byte_offset(100), 12);
+
+        assert_eq!(source_map.get_char_index_by_byte_offset(4), Some(2));
+        assert_eq!(source_map.get_char_index_by_byte_offset(12), Some(7));
+        assert_eq!(source_map.get_char_index_by_byte_offset(2), None);
+        assert_eq!(source_map.get_char_index_by_byte_offset(100), None);
+
+        // UTF-16 offset
+        assert_eq!(source_map.get_utf16_offset(5), 5);
+        assert_eq!(source_map.get_utf16_offset(6), 7);
+
+        assert_eq!(source_map.get_char_index_by_utf16_offset(4), 4);
+        assert_eq!(source_map.get_char_index_by_utf16_offset(5), 5);
+        assert_eq!(source_map.get_char_index_by_utf16_offset(6), 5); // in the middle of surrogate pair
+        assert_eq!(source_map.get_char_index_by_utf16_offset(7), 6);
+        assert_eq!(source_map.get_char_index_by_utf16_offset(100), 7);
+
+        // range
+        let location = Location::new_range(1, 0, 1, 2);
+        assert_eq!(source_map.get_byte_range(&location), 1..5);
+        assert_eq!(source_map.get_utf16_range(&location), 1..3);
+        assert_eq!(&"a文b\n\t😀c"[source_map.get_byte_range(&location)], "文b");
+
+        // text
+        assert_eq!(source_map.get_text(1, 3), "文b");
+        assert_eq!(source_map.get_line_text(0), Some("a文b"));
+        assert_eq!(source_map.get_line_text(1), Some("\t😀c"));
+        assert_eq!(source_map.get_line_text(2), None);
+    }
+
+    #[test]
+    fn test_convert_line_and_column() {
+        let source_map = SourceMap::new("a文b\r\n\t😀c");
+        let options = DisplayOptions::default();
+
+        // 'b'
+        assert_eq!(source_map.get_line_and_column(2), (0, 2));
+        assert_eq!(source_map.get_line_and_utf16_column(2), (0, 2));
+        assert_eq!(source_map.get_line_and_display_column(2, &options), (0, 3));
+
+        // 'c'
+        assert_eq!(source_map.get_line_and_column(7), (1, 2));
+        assert_eq!(source_map.get_line_and_utf16_column(7), (1, 3));
+        assert_eq!(source_map.get_line_and_display_column(7, &options), (1, 6));
+
+        // out of range
+        assert_eq!(source_map.get_line_and_column(100), (1, 3));
+
+        // UTF-16 column to char index
+        assert_eq!(source_map.get_char_index_by_line_and_utf16_column(0, 2), 2);
+        assert_eq!(source_map.get_char_index_by_line_and_utf16_column(1, 3), 7);
+        assert_eq!(
+            source_map.get_char_index_by_line_and_utf16_column(0, 100),
+            4
+        );
+        assert_eq!(
+            source_map.get_char_index_by_line_and_utf16_column(100, 0),
+            8
+        );
+    }
+
+    #[test]
+    fn test_display_width() {
+        let options = DisplayOptions::default();
+
+        assert_eq!(get_display_width("abc", &options), 3);
+        assert_eq!(get_display_width("中文", &options), 4);
+        assert_eq!(get_display_width("a\tb", &options), 5);
+        assert_eq!(get_display_width("abcd\tb", &options), 9);
+
+        // tab width
+        let options_with_tab_width_8 = DisplayOptions {
+            tab_width: 8,
+            ..DisplayOptions::default()
+        };
+        assert_eq!(get_display_width("a\tb", &options_with_tab_width_8), 9);
+        assert_eq!(
+            get_char_display_width('\t', 3, &options_with_tab_width_8),
+            5
+        );
+
+        // ambiguous width
+        let options_with_ambiguous_as_wide = DisplayOptions {
+            ambiguous_as_wide: true,
+            ..DisplayOptions::default()
+        };
+        assert_eq!(get_display_width("1°", &options), 2);
+        assert_eq!(get_display_width("1°", &options_with_ambiguous_as_wide), 3);
+    }
+}
@@ -22,6 +22,9 @@ pub enum Token {
     // `=`
     Equal,
 
+    // `;`, for the repetition of list elements, e.g. `[0_i8; 16]`
+    Semicolon,
+
     // "->"
     RightArrow,
 
@@ -75,6 +78,10 @@ pub enum NumberToken {
     I64(u64),
     F32(f32),
     F64(f64),
+
+    // e.g. `'A'`, `'\n'`, `'\u{6587}'`, it is an `i32` number
+    // (the unicode code point) with a different literal form.
+    Char(char),
 }
 
 #[derive(Debug, PartialEq)]
@@ -85,6 +92,15 @@ pub enum Comment {
 
     // `/*...*/`
     Block(String),
+
+    // `///...`, the document of the following statement,
+    // the leading `///` is not included.
+    // note that `////...` is an ordinary line comment.
+    Document(String),
+
+    // `//!...`, the document of the current (sub)module,
+    // the leading `//!` is not included.
+    ModuleDocument(String),
 }
 
 #[derive(Debug, PartialEq)]